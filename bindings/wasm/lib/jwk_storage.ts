@@ -16,6 +16,10 @@ export class JwkMemStore implements JwkStorage {
         return "Ed25519";
     }
 
+    public supportedKeyTypes(): string[] {
+        return [JwkMemStore.ed25519KeyType()];
+    }
+
     public async generate(keyType: string, algorithm: JwsAlgorithm): Promise<JwkGenOutput> {
         if (keyType !== JwkMemStore.ed25519KeyType()) {
             throw new Error(`unsupported key type ${keyType}`);
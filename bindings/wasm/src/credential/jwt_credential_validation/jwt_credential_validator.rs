@@ -121,6 +121,15 @@ impl WasmJwtCredentialValidator {
       .map(WasmDecodedJwtCredential)
   }
 
+  /// Validates the semantic structure of the `Credential`.
+  ///
+  /// ### Warning
+  /// This does not validate against the credential's schema nor the structure of the subject claims.
+  #[wasm_bindgen(js_name = checkStructure)]
+  pub fn check_structure(credential: &WasmCredential) -> Result<()> {
+    JwtCredentialValidator::check_structure(&credential.0).wasm_result()
+  }
+
   /// Validate that the credential expires on or after the specified timestamp.
   #[wasm_bindgen(js_name = checkExpiresOnOrAfter)]
   pub fn check_expires_on_or_after(credential: &WasmCredential, timestamp: &WasmTimestamp) -> Result<()> {
@@ -63,11 +63,32 @@ interface IJwtCredentialValidationOptions {
      * Uses the current datetime during validation if not set. */
     readonly latestIssuanceDate?: Timestamp;
 
+    /** The datetime considered to be the current one during validation, used in place of `earliestExpiryDate` and
+     * `latestIssuanceDate` when those are not set.
+     * Uses the system clock if not set. */
+    readonly now?: Timestamp;
+
     /** Validation behaviour for `credentialStatus`.
      *
      * Default: `StatusCheck.Strict`. */
     readonly status?: StatusCheck;
 
+    /** Declare whether the `refreshService` entries of the credential are checked to be well-formed.
+     *
+     * Default: false. */
+    readonly checkRefreshService?: boolean;
+
+    /** Declare whether the expiration date of the credential is checked before its signature is verified, rejecting
+     * expired credentials without the cost of a signature verification.
+     *
+     * Default: false. */
+    readonly checkExpirationBeforeSignature?: boolean;
+
+    /** Declare the DIDs of the issuers whose credentials are accepted, credentials of any other issuer are rejected.
+     *
+     * Default: empty, i.e. credentials of any issuer are accepted. */
+    readonly trustedIssuers?: string[];
+
     /** Options which affect the verification of the signature on the credential. */
     readonly verifierOptions?: JwsVerificationOptions;
 }"#;
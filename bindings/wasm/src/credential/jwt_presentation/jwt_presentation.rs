@@ -3,6 +3,8 @@
 
 use identity_iota::core::Context;
 use identity_iota::core::Object;
+use identity_iota::core::Url;
+use identity_iota::credential::Jwt;
 use identity_iota::credential::JwtPresentation;
 use identity_iota::credential::JwtPresentationBuilder;
 use wasm_bindgen::prelude::*;
@@ -46,6 +48,18 @@ impl WasmJwtPresentation {
     builder.build().map(Self).wasm_result()
   }
 
+  /// Constructs a new presentation of `holder` presenting the given credential JWTs, with the base context and type.
+  ///
+  /// Fails if one of the `credentials` is not a JWS in compact serialization.
+  #[wasm_bindgen(js_name = fromCredentials)]
+  pub fn from_credentials(holder: &str, credentials: &ArrayJwt) -> Result<WasmJwtPresentation> {
+    let credentials: Vec<Jwt> = credentials.into_serde().wasm_result()?;
+    JwtPresentationBuilder::from_credentials(Url::parse(holder).wasm_result()?, &credentials)
+      .and_then(JwtPresentationBuilder::build)
+      .map(Self)
+      .wasm_result()
+  }
+
   /// Returns a copy of the JSON-LD context(s) applicable to the presentation.
   #[wasm_bindgen]
   pub fn context(&self) -> Result<ArrayContext> {
@@ -124,6 +138,33 @@ impl WasmJwtPresentation {
       .map(|value| value.unchecked_into::<ArrayPolicy>())
   }
 
+  /// Returns the DID URL of the holder's verification method the presentation is bound to, if any.
+  ///
+  /// This is embedded as the `cnf` claim when the presentation is signed as a JWT.
+  #[wasm_bindgen(js_name = "holderBinding")]
+  pub fn holder_binding(&self) -> Option<String> {
+    self
+      .0
+      .holder_binding
+      .as_ref()
+      .map(|confirmation| confirmation.kid.to_string())
+  }
+
+  /// Returns a copy of the intended audience(s) of the presentation.
+  ///
+  /// This is embedded as the `aud` claim when the presentation is signed as a JWT.
+  #[wasm_bindgen]
+  pub fn audience(&self) -> ArrayString {
+    self
+      .0
+      .audience
+      .iter()
+      .map(|url| url.as_str())
+      .map(JsValue::from_str)
+      .collect::<js_sys::Array>()
+      .unchecked_into::<ArrayString>()
+  }
+
   /// Optional proof that can be verified by users in addition to JWS.
   #[wasm_bindgen]
   pub fn proof(&self) -> Result<Option<MapStringAny>> {
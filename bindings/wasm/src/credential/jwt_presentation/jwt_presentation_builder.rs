@@ -26,6 +26,8 @@ impl TryFrom<IJwtPresentation> for JwtPresentationBuilder {
       holder,
       refresh_service,
       terms_of_use,
+      holder_binding,
+      audience,
       properties,
     } = values.into_serde::<IJwtPresentationHelper>().wasm_result()?;
 
@@ -58,6 +60,14 @@ impl TryFrom<IJwtPresentation> for JwtPresentationBuilder {
         builder = builder.terms_of_use(policy);
       }
     }
+    if let Some(holder_binding) = holder_binding {
+      builder = builder.holder_binding(Url::parse(holder_binding).wasm_result()?);
+    }
+    if let Some(audience) = audience {
+      for value in audience.into_vec() {
+        builder = builder.audience(Url::parse(value).wasm_result()?);
+      }
+    }
 
     Ok(builder)
   }
@@ -99,6 +109,14 @@ struct IJwtPresentationHelper {
   /// Terms-of-use specified by the presentation holder.
   #[typescript(name = "termsOfUse", type = "Policy | Array<Policy>")]
   terms_of_use: Option<OneOrMany<Policy>>,
+  /// DID URL of the holder's verification method to bind the presentation to.
+  /// Embedded as the `cnf` claim of the presentation JWT.
+  #[typescript(name = "holderBinding", type = "string | DIDUrl")]
+  holder_binding: Option<String>,
+  /// The intended audience(s) of the presentation, usually the identifiers of the verifiers.
+  /// Embedded as the `aud` claim of the presentation JWT.
+  #[typescript(type = "string | Array<string>")]
+  audience: Option<OneOrMany<String>>,
   /// Miscellaneous properties.
   #[serde(flatten)]
   #[typescript(optional = false, name = "[properties: string]", type = "unknown")]
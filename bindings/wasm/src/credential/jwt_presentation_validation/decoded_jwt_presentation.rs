@@ -57,6 +57,15 @@ impl WasmDecodedJwtPresentation {
     self.0.aud.clone().map(|aud| aud.to_string())
   }
 
+  /// The `nonce` parsed from the protected header, which is checked against the `challenge` of the validation options
+  /// to protect the presentation against replay attacks.
+  ///
+  /// Returns `undefined` if the protected header has no `nonce`.
+  #[wasm_bindgen]
+  pub fn challenge(&self) -> Option<String> {
+    self.0.header.nonce().map(ToOwned::to_owned)
+  }
+
   /// The credentials included in the presentation (decoded).
   #[wasm_bindgen(js_name = "credentials")]
   pub fn credentials(&self) -> ArrayDecodedJwtCredential {
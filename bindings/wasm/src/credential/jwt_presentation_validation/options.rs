@@ -71,6 +71,21 @@ interface IJwtPresentationValidationOptions {
      */
     readonly subjectHolderRelationship?: SubjectHolderRelationship;
 
+    /**
+     * Declare how the holder must match the subjects of credentials with more than one subject.
+     *
+     * Default: `MultipleSubjectsPolicy.NoMatch`
+     */
+    readonly multipleSubjectsPolicy?: MultipleSubjectsPolicy;
+
+    /**
+     * Declare how errors of the credentials in the presentation are reported, e.g. to collect the errors of all
+     * credentials while still failing fast on the presentation itself.
+     *
+     * Default: `CredentialErrorPolicy.FollowFailFast`
+     */
+    readonly credentialErrorPolicy?: CredentialErrorPolicy;
+
     /**
      * Declare that the presentation is **not** considered valid if it expires before this `Timestamp`.
      * Uses the current datetime during validation if not set. 
@@ -82,4 +97,29 @@ interface IJwtPresentationValidationOptions {
      * Uses the current datetime during validation if not set. 
      */
     readonly latestIssuanceDate?: Timestamp;
+
+    /**
+     * The datetime considered to be the current one when validating the presentation, used in place of
+     * `earliestExpiryDate` and `latestIssuanceDate` when those are not set.
+     * Uses the system clock if not set.
+     */
+    readonly now?: Timestamp;
+
+    /**
+     * Declare that the presentation is **not** considered valid if its `aud` claim is missing or does not contain
+     * this audience. The audience is not checked if not set.
+     */
+    readonly expectedAudience?: string;
+
+    /**
+     * Declare that the presentation is **not** considered valid if the `nonce` in its protected header is missing
+     * or does not match this challenge. Takes precedence over the nonce of `presentationVerifierOptions`.
+     */
+    readonly challenge?: string;
+
+    /**
+     * Declare that the presentation is **not** considered valid if it contains more than this number of credentials.
+     * Checked before any signature is verified. The number of credentials is unbounded if not set.
+     */
+    readonly maxCredentials?: number;
 }"#;
@@ -11,7 +11,9 @@ pub use self::jwt::WasmJwt;
 pub use self::jwt_credential_validation::*;
 pub use self::jwt_presentation::*;
 pub use self::jwt_presentation_validation::*;
+pub use self::options::WasmCredentialErrorPolicy;
 pub use self::options::WasmFailFast;
+pub use self::options::WasmMultipleSubjectsPolicy;
 pub use self::options::WasmSubjectHolderRelationship;
 pub use self::types::*;
 
@@ -1,7 +1,9 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_iota::credential::CredentialErrorPolicy;
 use identity_iota::credential::FailFast;
+use identity_iota::credential::MultipleSubjectsPolicy;
 use identity_iota::credential::StatusCheck;
 use identity_iota::credential::SubjectHolderRelationship;
 use serde_repr::Deserialize_repr;
@@ -66,6 +68,58 @@ impl From<WasmSubjectHolderRelationship> for SubjectHolderRelationship {
   }
 }
 
+/// Declares how the holder must match the subjects of a credential with more than one subject
+/// when checking the `SubjectHolderRelationship`.
+#[wasm_bindgen(js_name = MultipleSubjectsPolicy)]
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum WasmMultipleSubjectsPolicy {
+  /// The holder never matches a credential with more than one subject.
+  /// This variant is the default.
+  NoMatch = 0,
+  /// The holder must match at least one of the subjects.
+  Any = 1,
+  /// The holder must match all of the subjects.
+  All = 2,
+}
+
+impl From<WasmMultipleSubjectsPolicy> for MultipleSubjectsPolicy {
+  fn from(policy: WasmMultipleSubjectsPolicy) -> Self {
+    match policy {
+      WasmMultipleSubjectsPolicy::NoMatch => Self::NoMatch,
+      WasmMultipleSubjectsPolicy::Any => Self::Any,
+      WasmMultipleSubjectsPolicy::All => Self::All,
+    }
+  }
+}
+
+/// Declares how errors of the credentials contained in a presentation are reported, independently of the
+/// `FailFast` used for the presentation itself.
+///
+/// Errors concerning the structure of the presentation or the holder's signature always end validation immediately.
+#[wasm_bindgen(js_name = CredentialErrorPolicy)]
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum WasmCredentialErrorPolicy {
+  /// Credential errors are reported according to the `FailFast` passed to the validator.
+  /// This variant is the default.
+  FollowFailFast = 0,
+  /// Every credential is validated and the first error of each invalid credential is reported.
+  AccumulateFirstErrors = 1,
+  /// Every credential is validated and all errors of each invalid credential are reported.
+  AccumulateAllErrors = 2,
+}
+
+impl From<WasmCredentialErrorPolicy> for CredentialErrorPolicy {
+  fn from(policy: WasmCredentialErrorPolicy) -> Self {
+    match policy {
+      WasmCredentialErrorPolicy::FollowFailFast => Self::FollowFailFast,
+      WasmCredentialErrorPolicy::AccumulateFirstErrors => Self::AccumulateFirstErrors,
+      WasmCredentialErrorPolicy::AccumulateAllErrors => Self::AccumulateAllErrors,
+    }
+  }
+}
+
 /// Declares when validation should return if an error occurs.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 #[wasm_bindgen(js_name = FailFast)]
@@ -0,0 +1,60 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_demia_core::DemiaDID;
+use identity_iota::did::CoreDID;
+use wasm_bindgen::prelude::*;
+
+use crate::did::WasmCoreDID;
+use crate::error::Result;
+use crate::error::WasmResult;
+
+/// A DID conforming to the Demia DID method specification.
+///
+/// @typicalname did
+#[wasm_bindgen(js_name = DemiaDID, inspectable)]
+pub struct WasmDemiaDID(pub(crate) DemiaDID);
+
+#[wasm_bindgen(js_class = DemiaDID)]
+impl WasmDemiaDID {
+  /// The Demia DID method name (`"demia"`).
+  #[wasm_bindgen(getter = METHOD)]
+  pub fn static_method() -> String {
+    DemiaDID::METHOD.to_owned()
+  }
+
+  /// Parses a `DemiaDID` from the input string.
+  #[wasm_bindgen]
+  pub fn parse(input: &str) -> Result<WasmDemiaDID> {
+    DemiaDID::parse(input).map(Self).wasm_result()
+  }
+
+  /// Returns whether `did` is a valid DID according to the Demia DID method specification.
+  ///
+  /// Unlike {@link DemiaDID.parse}, this never throws and returns `false` for malformed input.
+  #[wasm_bindgen(js_name = isValid)]
+  pub fn is_valid(did: &str) -> bool {
+    matches!(CoreDID::parse(did), Ok(did) if DemiaDID::is_valid(&did))
+  }
+
+  /// Returns the DID represented as a `CoreDID`.
+  #[wasm_bindgen(js_name = toCoreDid)]
+  pub fn as_core_did(&self) -> WasmCoreDID {
+    WasmCoreDID(self.0.as_ref().clone())
+  }
+
+  /// Returns the `DID` as a string.
+  #[allow(clippy::inherent_to_string)]
+  #[wasm_bindgen(js_name = toString)]
+  pub fn to_string(&self) -> String {
+    self.0.to_string()
+  }
+}
+
+impl_wasm_clone!(WasmDemiaDID, DemiaDID);
+
+impl From<DemiaDID> for WasmDemiaDID {
+  fn from(did: DemiaDID) -> Self {
+    Self(did)
+  }
+}
@@ -0,0 +1,6 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+pub use demia_did::WasmDemiaDID;
+
+mod demia_did;
@@ -64,4 +64,10 @@ interface IJwsVerificationOptions {
 
     /** Verify the signing verification method relationship matches this.*/
     readonly methodScope?: MethodScope;
+
+    /** The algorithms the JWS may be signed with.
+     *
+     * Default: all asymmetric signature algorithms. A JWS with `alg` set to `none` is always rejected.
+     */
+    readonly acceptedAlgorithms?: JwsAlgorithm[];
 }"#;
@@ -103,7 +103,8 @@ impl_wasm_error_from!(
   identity_iota::credential::ValidationError,
   identity_iota::credential::RevocationError,
   identity_iota::verification::Error,
-  identity_iota::credential::DomainLinkageValidationError
+  identity_iota::credential::DomainLinkageValidationError,
+  identity_demia_core::Error
 );
 
 // Similar to `impl_wasm_error_from`, but uses the types name instead of requiring/calling Into &'static str
@@ -23,6 +23,7 @@ mod macros;
 pub mod common;
 pub mod credential;
 pub mod crypto;
+pub mod demia;
 pub mod did;
 pub mod error;
 pub mod iota;
@@ -112,6 +112,10 @@ interface JwkStorage {
   delete: (keyId: string) => Promise<void>;
   /** Returns `true` if the key with the given `keyId` exists in storage, `false` otherwise. */
   exists: (keyId: string) => Promise<boolean>;
+  /** Returns the key types that can be passed to `generate`.
+   *
+   * Implementing this method is optional. */
+  supportedKeyTypes?: () => string[];
 }"#;
 
 fn uint8array_to_bytes(value: JsValue) -> KeyStorageResult<Vec<u8>> {
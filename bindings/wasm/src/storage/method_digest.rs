@@ -37,6 +37,13 @@ impl WasmMethodDigest {
     let bytes: Vec<u8> = bytes.to_vec();
     Ok(Self(MethodDigest::unpack(bytes).wasm_result()?))
   }
+
+  /// Returns the `MethodDigest` as a string of the form `md<version>:<packed bytes in hex>`.
+  #[allow(clippy::inherent_to_string)]
+  #[wasm_bindgen(js_name = toString)]
+  pub fn to_string(&self) -> String {
+    self.0.to_string()
+  }
 }
 
 impl_wasm_clone!(WasmMethodDigest, MethodDigest);
@@ -4,9 +4,14 @@
 use std::rc::Rc;
 
 use identity_iota::storage::storage::Storage;
+use js_sys::Array;
+use js_sys::Function;
+use js_sys::Reflect;
 
 use super::WasmJwkStorage;
 use super::WasmKeyIdStorage;
+use crate::common::ArrayString;
+use crate::error::Result;
 use wasm_bindgen::prelude::*;
 
 pub(crate) type WasmStorageInner = Storage<WasmJwkStorage, WasmKeyIdStorage>;
@@ -36,4 +41,18 @@ impl WasmStorage {
   pub fn key_storage(&self) -> WasmJwkStorage {
     JsValue::from(self.0.key_storage()).unchecked_into()
   }
+
+  /// Returns the key types supported by the wrapped `JwkStorage`.
+  ///
+  /// Returns an empty array if the `JwkStorage` does not implement the optional `supportedKeyTypes` method.
+  #[wasm_bindgen(js_name = supportedKeyTypes)]
+  pub fn supported_key_types(&self) -> Result<ArrayString> {
+    let key_storage: &JsValue = self.0.key_storage().as_ref();
+    let supported_key_types: JsValue = Reflect::get(key_storage, &JsValue::from_str("supportedKeyTypes"))?;
+    if !supported_key_types.is_function() {
+      return Ok(Array::new().unchecked_into());
+    }
+    let key_types: JsValue = supported_key_types.unchecked_into::<Function>().call0(key_storage)?;
+    Ok(key_types.unchecked_into())
+  }
 }
@@ -167,6 +167,16 @@ describe("Presentation", function() {
             properties.set("custom2", 1234);
             assert.deepStrictEqual(presentation.properties(), properties);
             assert.deepStrictEqual(presentation.proof(), undefined);
+            assert.deepStrictEqual(presentation.audience(), []);
+        });
+
+        it("should support multiple audiences", async () => {
+            const audience = ["did:example:verifier", "https://verifier.example.com/"];
+            const presentation = new JwtPresentation({
+                ...presentationFields,
+                audience,
+            });
+            assert.deepStrictEqual(presentation.audience(), audience);
         });
     });
 });
@@ -0,0 +1,28 @@
+export {};
+
+const assert = require("assert");
+const { DemiaDID } = require("../node");
+
+const tag = "0xf29dd16310c2100fd1bf568b345fb1cc14d71caa3bd9b5ad735d2bd6d455ca3b";
+
+describe("DemiaDID", function() {
+    describe("#isValid", function() {
+        it("should accept valid DIDs", () => {
+            assert.strictEqual(DemiaDID.isValid(`did:demia:usa:main:${tag}`), true);
+            assert.strictEqual(DemiaDID.isValid(`did:demia:usa:${tag}`), true);
+        });
+
+        it("should return false for invalid input without throwing", () => {
+            assert.strictEqual(DemiaDID.isValid(""), false);
+            assert.strictEqual(DemiaDID.isValid("not a did"), false);
+            assert.strictEqual(DemiaDID.isValid(`did:iota:smr:${tag}`), false);
+            assert.strictEqual(DemiaDID.isValid("did:demia:usa:main:0x1234"), false);
+            assert.strictEqual(DemiaDID.isValid(`did:demia:usa:NOTVALID:${tag}`), false);
+        });
+
+        it("should agree with parse", () => {
+            const did = DemiaDID.parse(`did:demia:usa:main:${tag}`);
+            assert.strictEqual(DemiaDID.isValid(did.toString()), true);
+        });
+    });
+});
@@ -18,6 +18,8 @@ describe("Method digest", () => {
         let packed = methodDigest.pack();
         // Packed bytes must be consistent between Rust and Wasm, see Rust tests for `MethodDigest`.
         let packedExpected = new Uint8Array([
+            77,
+            68,
             0,
             74,
             60,
@@ -29,6 +31,12 @@ describe("Method digest", () => {
             133,
         ]);
         assert.deepStrictEqual(packed, packedExpected);
+        assert.deepStrictEqual(MethodDigest.unpack(packed).pack(), packedExpected);
+        assert.equal(methodDigest.toString(), "md0:004a3c0ac74ccdb485");
+    });
+
+    it("should throw on invalid packed bytes", () => {
+        assert.throws(() => MethodDigest.unpack(new Uint8Array([1, 2, 3])));
     });
 });
 
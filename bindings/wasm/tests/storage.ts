@@ -64,6 +64,12 @@ describe("#JwkStorageDocument", function() {
         );
     });
 
+    it("should list the supported key types", async () => {
+        const storage = new Storage(new JwkMemStore(), new KeyIdMemStore());
+        assert.ok(storage.supportedKeyTypes().includes(JwkMemStore.ed25519KeyType()));
+        assert.ok(storage.supportedKeyTypes().includes("Ed25519"));
+    });
+
     it("The JwkStorageDocument extension should work: CoreDocument", async () => {
         const keystore = new JwkMemStore();
         const keyIdStore = new KeyIdMemStore();
@@ -177,6 +183,90 @@ describe("#JwkStorageDocument", function() {
         assert.deepStrictEqual((storage.keyStorage() as JwkMemStore).count(), 0);
     });
 
+    it("JwtCredentialValidator should validate a standalone credential", async () => {
+        const storage = new Storage(new JwkMemStore(), new KeyIdMemStore());
+        const issuer = new CoreDocument({ id: "did:example:issuer" });
+        const fragment = "#key-1";
+        await issuer.generateMethod(
+            storage,
+            JwkMemStore.ed25519KeyType(),
+            JwsAlgorithm.EdDSA,
+            fragment,
+            MethodScope.VerificationMethod(),
+        );
+        const otherIssuer = new CoreDocument({ id: "did:example:issuer" });
+        await otherIssuer.generateMethod(
+            storage,
+            JwkMemStore.ed25519KeyType(),
+            JwsAlgorithm.EdDSA,
+            fragment,
+            MethodScope.VerificationMethod(),
+        );
+
+        const expirationDate = Timestamp.nowUTC().checkedAdd(Duration.days(365))!;
+        const credential = new Credential({
+            id: "https://example.edu/credentials/3732",
+            type: "UniversityDegreeCredential",
+            credentialSubject: {
+                id: "did:example:ebfeb1f712ebc6f1c276e12ec21",
+                degree: "Bachelor of Science and Arts",
+            },
+            issuer: issuer.id(),
+            issuanceDate: Timestamp.parse("2010-01-01T00:00:00Z"),
+            expirationDate,
+        });
+        JwtCredentialValidator.checkStructure(credential);
+        const credentialJwt = await issuer.createCredentialJwt(
+            storage,
+            fragment,
+            credential,
+            new JwsSignatureOptions(),
+        );
+
+        const validator = new JwtCredentialValidator();
+        const decoded = validator.validate(
+            credentialJwt,
+            issuer,
+            JwtCredentialValidationOptions.default(),
+            FailFast.AllErrors,
+        );
+        assert.deepStrictEqual(decoded.credential().toJSON(), credential.toJSON());
+
+        // Signature: a document with a different key for the same method fails.
+        assert.throws(() =>
+            validator.validate(
+                credentialJwt,
+                otherIssuer,
+                JwtCredentialValidationOptions.default(),
+                FailFast.AllErrors,
+            )
+        );
+
+        // Expiration date.
+        assert.throws(() =>
+            validator.validate(
+                credentialJwt,
+                issuer,
+                new JwtCredentialValidationOptions({
+                    earliestExpiryDate: expirationDate.checkedAdd(Duration.days(1)),
+                }),
+                FailFast.AllErrors,
+            )
+        );
+
+        // Issuance date.
+        assert.throws(() =>
+            validator.validate(
+                credentialJwt,
+                issuer,
+                new JwtCredentialValidationOptions({
+                    latestIssuanceDate: Timestamp.parse("2009-01-01T00:00:00Z"),
+                }),
+                FailFast.AllErrors,
+            )
+        );
+    });
+
     it("The JwkStorageDocument extension should work: IotaDocument", async () => {
         const keystore = new JwkMemStore();
         const keyIdStore = new KeyIdMemStore();
@@ -331,6 +421,25 @@ describe("#JwkStorageDocument", function() {
             presentation.toJSON(),
         );
         assert.equal(decoded.audience(), audience);
+        assert.deepStrictEqual(decoded.challenge(), undefined);
+
+        // Check that the challenge is read from the nonce in the protected header.
+        const challenge = "475a7984-1bb5-4c4c-a56f-822bccd46440";
+        const presentationJwtWithNonce = await holderDoc.createPresentationJwt(
+            storage,
+            fragment,
+            presentation,
+            new JwsSignatureOptions({ nonce: challenge }),
+            new JwtPresentationOptions(),
+        );
+        decoded = validator.validate(
+            presentationJwtWithNonce,
+            holderDoc,
+            [issuerDoc],
+            new JwtPresentationValidationOptions({ challenge }),
+            FailFast.FirstError,
+        );
+        assert.deepStrictEqual(decoded.challenge(), challenge);
 
         // check issuance date validation.
         let options = new JwtPresentationValidationOptions({
@@ -37,6 +37,20 @@ impl Timestamp {
     Ok(Timestamp(truncate_fractional_seconds(offset_date_time)))
   }
 
+  /// Parses a `Timestamp` like [`Timestamp::parse`], but only accepts inputs that use the UTC designator `Z`
+  /// instead of a numeric offset such as `+00:00`.
+  ///
+  /// # Errors
+  ///
+  /// [`Error::NonUtcTimestamp`] if `input` is a valid RFC 3339 timestamp that does not end in `Z`.
+  pub fn parse_strict(input: &str) -> Result<Self> {
+    let timestamp: Self = Self::parse(input)?;
+    if !input.ends_with('Z') {
+      return Err(Error::NonUtcTimestamp);
+    }
+    Ok(timestamp)
+  }
+
   /// Creates a new `Timestamp` with the current date and time, normalized to UTC+00:00 with
   /// fractional seconds truncated.
   ///
@@ -210,7 +224,9 @@ mod diff {
 /// A span of time.
 ///
 /// This type is typically used to increment or decrement a [`Timestamp`].
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+///
+/// The default is a zero-length [`Duration`].
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
 #[repr(transparent)]
 pub struct Duration(time::Duration);
 
@@ -289,6 +305,39 @@ mod tests {
     assert_eq!(timestamp.to_rfc3339(), expected);
   }
 
+  #[test]
+  fn test_parse_offsets() {
+    let expected = "2023-05-01T10:00:00Z";
+    for original in [
+      "2023-05-01T10:00:00Z",
+      "2023-05-01T10:00:00+00:00",
+      "2023-05-01T12:00:00+02:00",
+    ] {
+      assert_eq!(Timestamp::parse(original).unwrap().to_rfc3339(), expected);
+      assert_eq!(
+        Timestamp::from_json(&format!("\"{original}\"")).unwrap().to_rfc3339(),
+        expected
+      );
+    }
+  }
+
+  #[test]
+  fn test_parse_strict() {
+    let timestamp = Timestamp::parse_strict("2023-05-01T10:00:00Z").unwrap();
+    assert_eq!(timestamp.to_rfc3339(), "2023-05-01T10:00:00Z");
+
+    for original in ["2023-05-01T10:00:00+00:00", "2023-05-01T12:00:00+02:00"] {
+      assert!(matches!(
+        Timestamp::parse_strict(original),
+        Err(crate::Error::NonUtcTimestamp)
+      ));
+    }
+    assert!(matches!(
+      Timestamp::parse_strict("2023-05-01Z"),
+      Err(crate::Error::InvalidTimestamp(_))
+    ));
+  }
+
   #[test]
   fn test_checked_add() {
     let timestamp = Timestamp::parse("1980-01-01T12:34:56Z").unwrap();
@@ -38,6 +38,9 @@ pub enum Error {
   /// Caused by attempting to parse an invalid `Timestamp`.
   #[error("invalid timestamp")]
   InvalidTimestamp(#[from] time::error::Error),
+  /// Caused by a `Timestamp` that does not use the UTC designator `Z` when parsed strictly.
+  #[error("timestamp must use the UTC designator `Z`")]
+  NonUtcTimestamp,
   /// Caused by attempting to create an empty `OneOrSet` instance or remove all its elements.
   #[error("OneOrSet cannot be empty")]
   OneOrSetEmpty,
@@ -150,6 +150,15 @@ impl<T> CredentialBuilder<T> {
     self
   }
 
+  /// Adds the values from the iterator to the `evidence` set.
+  #[must_use]
+  pub fn evidences<I: IntoIterator<Item = Evidence>>(mut self, values: I) -> Self {
+    for value in values {
+      self.evidence.push(value);
+    }
+    self
+  }
+
   /// Sets the value of the `Credential` `nonTransferable` property.
   #[must_use]
   pub fn non_transferable(mut self, value: bool) -> Self {
@@ -205,11 +214,14 @@ mod tests {
   use identity_core::common::Timestamp;
   use identity_core::common::Url;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
   use serde_json::json;
   use serde_json::Value;
 
   use crate::credential::Credential;
   use crate::credential::CredentialBuilder;
+  use crate::credential::Evidence;
+  use crate::credential::RefreshService;
   use crate::credential::Subject;
 
   fn subject() -> Subject {
@@ -267,6 +279,57 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_builder_evidence() {
+    let evidence: Evidence = Evidence::with_id("DocumentVerification".to_owned(), "https://example.edu/evidence/1");
+    let credential: Credential = CredentialBuilder::default()
+      .subject(subject())
+      .issuer(issuer())
+      .evidence(evidence.clone())
+      .evidences([Evidence::new("SupportingActivity".to_owned())])
+      .build()
+      .unwrap();
+
+    assert_eq!(credential.evidence.len(), 2);
+    assert_eq!(credential.evidence.get(0).unwrap(), &evidence);
+    let json: Value = credential.to_json_value().unwrap();
+    assert_eq!(json["evidence"][0]["type"], "DocumentVerification");
+    assert_eq!(json["evidence"][0]["id"], "https://example.edu/evidence/1");
+    assert_eq!(json["evidence"][1]["type"], "SupportingActivity");
+  }
+
+  #[test]
+  #[should_panic = "InvalidEvidence"]
+  fn test_builder_evidence_missing_type() {
+    let _: Credential = CredentialBuilder::default()
+      .subject(subject())
+      .issuer(issuer())
+      .evidence(Evidence::new(Vec::<String>::new()))
+      .build()
+      .unwrap();
+  }
+
+  #[test]
+  fn test_builder_refresh_service() {
+    let refresh_service: RefreshService = RefreshService::new(
+      Url::parse("https://example.edu/refresh/3732").unwrap(),
+      "ManualRefreshService2018".to_owned(),
+    );
+    let credential: Credential = CredentialBuilder::default()
+      .subject(subject())
+      .issuer(issuer())
+      .refresh_service(refresh_service.clone())
+      .build()
+      .unwrap();
+    assert_eq!(credential.refresh_service.as_slice(), [refresh_service]);
+
+    let json: Value = credential.to_json_value().unwrap();
+    assert_eq!(json["refreshService"]["id"], "https://example.edu/refresh/3732");
+    assert_eq!(json["refreshService"]["type"], "ManualRefreshService2018");
+    let roundtrip: Credential = Credential::from_json_value(json).unwrap();
+    assert_eq!(roundtrip, credential);
+  }
+
   #[test]
   #[should_panic = "MissingSubject"]
   fn test_builder_missing_subjects() {
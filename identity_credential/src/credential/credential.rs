@@ -12,11 +12,14 @@ use identity_core::common::Object;
 use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::common::Value;
 use identity_core::convert::FmtJson;
 use identity_core::crypto::GetSignature;
 use identity_core::crypto::GetSignatureMut;
 use identity_core::crypto::Proof;
 use identity_core::crypto::SetSignature;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
 use identity_verification::MethodUriType;
 use identity_verification::TryMethod;
 
@@ -33,6 +36,28 @@ use crate::error::Result;
 
 use super::jwt_serialization::CredentialJwtClaims;
 
+/// Top-level properties of a [`Credential`] that are not part of the Hedera Guardian representation.
+const GUARDIAN_DROPPED_PROPERTIES: &[&str] = &[
+  "credentialSchema",
+  "refreshService",
+  "termsOfUse",
+  "evidence",
+  "nonTransferable",
+];
+
+/// Properties of a [`Proof`] that are retained in the Hedera Guardian representation.
+const GUARDIAN_PROOF_PROPERTIES: &[&str] = &[
+  "type",
+  "created",
+  "verificationMethod",
+  "proofPurpose",
+  "challenge",
+  "domain",
+  "jws",
+  "proofValue",
+  "signatureValue",
+];
+
 lazy_static! {
   static ref BASE_CONTEXT: Context = Context::Url(Url::parse("https://www.w3.org/2018/credentials/v1").unwrap());
 }
@@ -155,6 +180,15 @@ impl<T> Credential<T> {
       }
     }
 
+    // Each evidence entry MUST specify its type
+    if self
+      .evidence
+      .iter()
+      .any(|evidence| evidence.types.is_empty() || evidence.types.iter().any(String::is_empty))
+    {
+      return Err(Error::InvalidEvidence);
+    }
+
     Ok(())
   }
 
@@ -172,6 +206,132 @@ impl<T> Credential<T> {
       .map_err(|err| Error::JwtClaimsSetSerializationError(err.into()))
   }
 
+  /// Reshapes the [`Credential`] into the JSON representation ingested by
+  /// [Hedera Guardian](https://github.com/hashgraph/guardian).
+  ///
+  /// The following changes are applied to the regular JSON representation:
+  /// - `issuer` is reduced to its URL, dropping any additional issuer properties.
+  /// - `credentialSubject` is always an array, even for a single subject.
+  /// - `proof` only retains `type`, `created`, `verificationMethod`, `proofPurpose`, `challenge`, `domain` and the
+  ///   signature value (`jws`, `proofValue` or `signatureValue`). `proofPurpose` defaults to `assertionMethod` if
+  ///   unset, while `expires` is dropped. `challenge` and `domain` are part of the signed proof options and must be
+  ///   kept for the proof to verify.
+  /// - `credentialSchema`, `refreshService`, `termsOfUse`, `evidence` and `nonTransferable` are dropped.
+  ///
+  /// All other properties, including custom ones, are kept as-is. In particular, `credentialStatus` is kept so that
+  /// the revocation status of the exported credential can still be checked.
+  pub fn to_guardian_json(&self) -> Result<serde_json::Value>
+  where
+    T: serde::Serialize,
+  {
+    use serde_json::Value;
+
+    let mut value: Value = serde_json::to_value(self).map_err(|err| Error::CredentialSerializationError(err.into()))?;
+    if let Value::Object(object) = &mut value {
+      for property in GUARDIAN_DROPPED_PROPERTIES {
+        object.remove(*property);
+      }
+
+      object.insert("issuer".to_owned(), Value::String(self.issuer.url().to_string()));
+
+      let subjects: Vec<Value> = self
+        .credential_subject
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<std::result::Result<_, _>>()
+        .map_err(|err| Error::CredentialSerializationError(err.into()))?;
+      object.insert("credentialSubject".to_owned(), Value::Array(subjects));
+
+      if let Some(Value::Object(proof)) = object.get_mut("proof") {
+        proof.retain(|property, _| GUARDIAN_PROOF_PROPERTIES.contains(&property.as_str()));
+        proof
+          .entry("proofPurpose")
+          .or_insert_with(|| Value::String("assertionMethod".to_owned()));
+      }
+    }
+
+    Ok(value)
+  }
+
+  /// Looks up a property of the first credential subject by a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901),
+  /// e.g. `/degree/name`.
+  ///
+  /// See [`Subject::pointer`] for details and [`Credential::subject_value_at`] for credentials with multiple subjects.
+  pub fn subject_value(&self, pointer: &str) -> Option<&Value> {
+    self.subject_value_at(0, pointer)
+  }
+
+  /// Looks up a property of the credential subject at `index` by a
+  /// [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901).
+  ///
+  /// Returns `None` if there is no subject at `index` or the pointer does not resolve to a value.
+  pub fn subject_value_at(&self, index: usize, pointer: &str) -> Option<&Value> {
+    self.credential_subject.get(index)?.pointer(pointer)
+  }
+
+  /// Returns the DIDs referenced by the issuer, the credential subjects, the status and the schemas of the
+  /// `Credential`, e.g. to resolve their DID documents ahead of validation.
+  ///
+  /// URLs that are DID URLs contribute their DID, other URLs are ignored. Every DID is returned once, in the order
+  /// of its first occurrence.
+  pub fn referenced_dids(&self) -> Vec<CoreDID> {
+    let urls = std::iter::once(self.issuer.url())
+      .chain(self.credential_subject.iter().filter_map(|subject| subject.id.as_ref()))
+      .chain(self.credential_status.iter().map(|status| &status.id))
+      .chain(self.credential_schema.iter().map(|schema| &schema.id));
+
+    let mut dids: Vec<CoreDID> = Vec::new();
+    for did_url in urls.filter_map(|url| DIDUrl::parse(url.as_str()).ok()) {
+      if !dids.contains(did_url.did()) {
+        dids.push(did_url.did().clone());
+      }
+    }
+    dids
+  }
+
+  /// Returns whether `self` and `other` make the same claims, i.e. are equal in every field except `proof`.
+  ///
+  /// Useful for deduplicating credentials that were re-issued with a new signature. Properties are compared as maps,
+  /// so the field order of the JSON the credentials were parsed from does not matter.
+  pub fn claims_eq(&self, other: &Self) -> bool
+  where
+    T: PartialEq,
+  {
+    // Destructure to not silently ignore fields added in the future.
+    let Self {
+      context,
+      id,
+      types,
+      credential_subject,
+      issuer,
+      issuance_date,
+      expiration_date,
+      credential_status,
+      credential_schema,
+      refresh_service,
+      terms_of_use,
+      evidence,
+      non_transferable,
+      properties,
+      proof: _,
+    } = self;
+
+    context == &other.context
+      && id == &other.id
+      && types == &other.types
+      && credential_subject == &other.credential_subject
+      && issuer == &other.issuer
+      && issuance_date == &other.issuance_date
+      && expiration_date == &other.expiration_date
+      && credential_status == &other.credential_status
+      && credential_schema == &other.credential_schema
+      && refresh_service == &other.refresh_service
+      && terms_of_use == &other.terms_of_use
+      && evidence == &other.evidence
+      && non_transferable == &other.non_transferable
+      && properties == &other.properties
+  }
+
   /// Returns a reference to the proof.
   pub fn proof(&self) -> Option<&Proof> {
     self.proof.as_ref()
@@ -216,9 +376,17 @@ impl<T> TryMethod for Credential<T> {
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::OneOrMany;
+  use identity_core::common::Url;
   use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use identity_did::CoreDID;
 
   use crate::credential::Credential;
+  use crate::credential::Status;
+  use crate::credential::Subject;
 
   const JSON1: &str = include_str!("../../tests/fixtures/credential-1.json");
   const JSON2: &str = include_str!("../../tests/fixtures/credential-2.json");
@@ -232,6 +400,8 @@ mod tests {
   const JSON10: &str = include_str!("../../tests/fixtures/credential-10.json");
   const JSON11: &str = include_str!("../../tests/fixtures/credential-11.json");
   const JSON12: &str = include_str!("../../tests/fixtures/credential-12.json");
+  const GUARDIAN_INPUT: &str = include_str!("../../tests/fixtures/guardian-credential.json");
+  const GUARDIAN_EXPORT: &str = include_str!("../../tests/fixtures/guardian-credential-export.json");
 
   #[test]
   fn test_from_json() {
@@ -248,4 +418,122 @@ mod tests {
     let _credential: Credential = Credential::from_json(JSON11).unwrap();
     let _credential: Credential = Credential::from_json(JSON12).unwrap();
   }
+
+  #[test]
+  fn test_subject_value() {
+    let subject = |name: &str| -> Subject {
+      Subject::from_json_value(json!({
+        "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+        "name": name,
+        "degree": {
+          "type": "BachelorDegree",
+          "name": "Bachelor of Science and Arts",
+        },
+        "a/b": { "m~n": 1 },
+      }))
+      .unwrap()
+    };
+    let credential: Credential = Credential::builder(Object::new())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .subject(subject("Alice"))
+      .subject(subject("Bob"))
+      .build()
+      .unwrap();
+
+    assert_eq!(
+      credential.subject_value("/degree/name"),
+      Some(&json!("Bachelor of Science and Arts"))
+    );
+    assert_eq!(credential.subject_value("/name"), Some(&json!("Alice")));
+    assert_eq!(credential.subject_value_at(1, "/name"), Some(&json!("Bob")));
+    assert_eq!(credential.subject_value("/a~1b/m~0n"), Some(&json!(1)));
+
+    assert!(credential.subject_value("/degree/grade").is_none());
+    assert!(credential.subject_value("/id").is_none());
+    assert!(credential.subject_value("degree").is_none());
+    assert!(credential.subject_value("").is_none());
+    assert!(credential.subject_value_at(2, "/name").is_none());
+  }
+
+  #[test]
+  fn test_referenced_dids() {
+    let credential: Credential = Credential::builder(Object::new())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:alice").unwrap()))
+      .subject(Subject::with_id(Url::parse("did:example:bob").unwrap()))
+      .subject(Subject::with_id(Url::parse("did:example:alice#key-1").unwrap()))
+      .subject(Subject::with_id(Url::parse("https://example.com/carol").unwrap()))
+      .status(Status::new(
+        Url::parse("did:example:issuer?index=5#revocation").unwrap(),
+        "RevocationBitmap2022".to_owned(),
+      ))
+      .build()
+      .unwrap();
+
+    let expected: Vec<CoreDID> = ["did:example:issuer", "did:example:alice", "did:example:bob"]
+      .into_iter()
+      .map(|did| CoreDID::parse(did).unwrap())
+      .collect();
+    assert_eq!(credential.referenced_dids(), expected);
+  }
+
+  #[test]
+  fn test_claims_eq() {
+    let credential: Credential = Credential::from_json(
+      r#"{
+        "@context": "https://www.w3.org/2018/credentials/v1",
+        "type": "VerifiableCredential",
+        "issuer": "did:example:issuer",
+        "issuanceDate": "2023-05-10T12:00:00Z",
+        "credentialSubject": { "id": "did:example:alice", "name": "Alice", "degree": "BSc" },
+        "proof": {
+          "type": "JcsEd25519Signature2020",
+          "verificationMethod": "did:example:issuer#key-1",
+          "signatureValue": "first"
+        }
+      }"#,
+    )
+    .unwrap();
+    let reissued: Credential = Credential::from_json(
+      r#"{
+        "credentialSubject": { "degree": "BSc", "name": "Alice", "id": "did:example:alice" },
+        "issuanceDate": "2023-05-10T12:00:00Z",
+        "issuer": "did:example:issuer",
+        "type": "VerifiableCredential",
+        "@context": "https://www.w3.org/2018/credentials/v1",
+        "proof": {
+          "type": "JcsEd25519Signature2020",
+          "verificationMethod": "did:example:issuer#key-2",
+          "signatureValue": "second"
+        }
+      }"#,
+    )
+    .unwrap();
+    assert_ne!(credential, reissued);
+    assert!(credential.claims_eq(&reissued));
+
+    let mut unsigned: Credential = reissued.clone();
+    unsigned.proof = None;
+    assert!(credential.claims_eq(&unsigned));
+
+    let mut changed: Credential = reissued;
+    changed.credential_subject = OneOrMany::One(
+      Subject::from_json_value(json!({
+        "id": "did:example:alice",
+        "name": "Alice",
+        "degree": "MSc",
+      }))
+      .unwrap(),
+    );
+    assert!(!credential.claims_eq(&changed));
+  }
+
+  #[test]
+  fn test_to_guardian_json() {
+    // The expected output follows the credential layout documented by Guardian, it is not captured from a running
+    // Guardian instance.
+    let credential: Credential = Credential::from_json(GUARDIAN_INPUT).unwrap();
+    let expected: serde_json::Value = serde_json::from_str(GUARDIAN_EXPORT).unwrap();
+    assert_eq!(credential.to_guardian_json().unwrap(), expected);
+  }
 }
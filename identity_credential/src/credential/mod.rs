@@ -17,8 +17,12 @@ mod policy;
 mod refresh;
 #[cfg(feature = "revocation-bitmap")]
 mod revocation_bitmap_status;
+#[cfg(feature = "revocation-bitmap")]
+mod revocation_list_2020_status;
 mod schema;
 mod status;
+#[cfg(feature = "revocation-bitmap")]
+mod status_list_2021_entry;
 mod subject;
 
 pub use self::builder::CredentialBuilder;
@@ -32,8 +36,14 @@ pub use self::policy::Policy;
 pub use self::refresh::RefreshService;
 #[cfg(feature = "revocation-bitmap")]
 pub use self::revocation_bitmap_status::RevocationBitmapStatus;
+#[cfg(feature = "revocation-bitmap")]
+pub use self::revocation_list_2020_status::RevocationList2020Status;
 pub use self::schema::Schema;
 pub use self::status::Status;
+#[cfg(feature = "revocation-bitmap")]
+pub use self::status_list_2021_entry::StatusList2021Entry;
+#[cfg(feature = "revocation-bitmap")]
+pub use self::status_list_2021_entry::StatusPurpose;
 pub use self::subject::Subject;
 
 #[cfg(feature = "validator")]
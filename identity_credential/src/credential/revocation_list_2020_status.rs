@@ -0,0 +1,162 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+use crate::credential::Status;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Information used to determine the current status of a [`Credential`][crate::credential::Credential]
+/// using the [`RevocationList2020`](https://w3c-ccg.github.io/vc-status-rl-2020/) specification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevocationList2020Status(Status);
+
+impl RevocationList2020Status {
+  const INDEX_PROPERTY: &'static str = "revocationListIndex";
+  const CREDENTIAL_PROPERTY: &'static str = "revocationListCredential";
+  /// Type name of the revocation list status.
+  pub const TYPE: &'static str = "RevocationList2020Status";
+
+  /// Creates a new `RevocationList2020Status` referencing the credential at `index` in the revocation list
+  /// credential located at `revocation_list_credential`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use identity_core::common::Url;
+  /// # use identity_credential::credential::RevocationList2020Status;
+  /// let list: Url = Url::parse("https://example.com/credentials/status/3").unwrap();
+  /// let status_id: Url = Url::parse("https://example.com/credentials/status/3#94567").unwrap();
+  /// let status: RevocationList2020Status = RevocationList2020Status::new(status_id, 94567, list.clone());
+  /// assert_eq!(status.index().unwrap(), 94567);
+  /// assert_eq!(status.revocation_list_credential().unwrap(), list);
+  /// ```
+  pub fn new(id: Url, index: u32, revocation_list_credential: Url) -> Self {
+    let mut object = Object::new();
+    object.insert(Self::INDEX_PROPERTY.to_owned(), Value::String(index.to_string()));
+    object.insert(
+      Self::CREDENTIAL_PROPERTY.to_owned(),
+      Value::String(revocation_list_credential.into_string()),
+    );
+    RevocationList2020Status(Status::new_with_properties(id, Self::TYPE.to_owned(), object))
+  }
+
+  /// Returns the index of the credential in the revocation list if it can be decoded.
+  pub fn index(&self) -> Result<u32> {
+    if let Some(Value::String(index)) = self.0.properties.get(Self::INDEX_PROPERTY) {
+      u32::from_str(index).map_err(|err| {
+        Error::InvalidStatus(format!(
+          "{} cannot be converted to an unsigned, 32-bit integer: {err}",
+          Self::INDEX_PROPERTY
+        ))
+      })
+    } else {
+      Err(Error::InvalidStatus(format!(
+        "expected {} to be an unsigned 32-bit integer expressed as a string",
+        Self::INDEX_PROPERTY
+      )))
+    }
+  }
+
+  /// Returns the [`Url`] of the revocation list credential.
+  pub fn revocation_list_credential(&self) -> Result<Url> {
+    if let Some(Value::String(url)) = self.0.properties.get(Self::CREDENTIAL_PROPERTY) {
+      Url::parse(url).map_err(|err| Error::InvalidStatus(format!("invalid revocation list credential url: {err}")))
+    } else {
+      Err(Error::InvalidStatus(format!(
+        "expected {} to be a url expressed as a string",
+        Self::CREDENTIAL_PROPERTY
+      )))
+    }
+  }
+}
+
+impl TryFrom<Status> for RevocationList2020Status {
+  type Error = Error;
+
+  fn try_from(status: Status) -> Result<Self> {
+    if status.type_ != Self::TYPE {
+      return Err(Error::InvalidStatus(format!(
+        "expected type '{}', got '{}'",
+        Self::TYPE,
+        status.type_
+      )));
+    }
+
+    let status: Self = Self(status);
+    // Ensure both required properties are present and well-formed.
+    status.index()?;
+    status.revocation_list_credential()?;
+
+    Ok(status)
+  }
+}
+
+impl From<RevocationList2020Status> for Status {
+  fn from(status: RevocationList2020Status) -> Self {
+    status.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::RevocationList2020Status;
+  use super::Status;
+
+  #[test]
+  fn test_revocation_list_2020_status_from_json() {
+    let status: Status = Status::from_json_value(serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "RevocationList2020Status",
+      "revocationListIndex": "94567",
+      "revocationListCredential": "https://example.com/credentials/status/3"
+    }))
+    .unwrap();
+
+    let status: RevocationList2020Status = RevocationList2020Status::try_from(status).unwrap();
+    assert_eq!(status.index().unwrap(), 94567);
+    assert_eq!(
+      status.revocation_list_credential().unwrap().as_str(),
+      "https://example.com/credentials/status/3"
+    );
+  }
+
+  #[test]
+  fn test_revocation_list_2020_status_invalid() {
+    // INVALID: missing list credential.
+    let status: Status = Status::from_json_value(serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "RevocationList2020Status",
+      "revocationListIndex": "94567",
+    }))
+    .unwrap();
+    assert!(RevocationList2020Status::try_from(status).is_err());
+
+    // INVALID: index is not a string.
+    let status: Status = Status::from_json_value(serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "RevocationList2020Status",
+      "revocationListIndex": 94567,
+      "revocationListCredential": "https://example.com/credentials/status/3"
+    }))
+    .unwrap();
+    assert!(RevocationList2020Status::try_from(status).is_err());
+
+    // INVALID: wrong type.
+    let status: Status = Status::from_json_value(serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "RevocationBitmap2022",
+      "revocationListIndex": "94567",
+      "revocationListCredential": "https://example.com/credentials/status/3"
+    }))
+    .unwrap();
+    assert!(RevocationList2020Status::try_from(status).is_err());
+  }
+}
@@ -0,0 +1,228 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use std::str::FromStr;
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+use crate::credential::Status;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The purpose of a [`StatusList2021Entry`] and of the status list it references.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatusPurpose {
+  /// A set bit permanently revokes the credential.
+  Revocation,
+  /// A set bit temporarily suspends the credential.
+  Suspension,
+}
+
+impl StatusPurpose {
+  /// Returns the `statusPurpose` value of this purpose.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Revocation => "revocation",
+      Self::Suspension => "suspension",
+    }
+  }
+}
+
+impl FromStr for StatusPurpose {
+  type Err = Error;
+
+  fn from_str(purpose: &str) -> Result<Self> {
+    match purpose {
+      "revocation" => Ok(Self::Revocation),
+      "suspension" => Ok(Self::Suspension),
+      _ => Err(Error::InvalidStatus(format!("unsupported status purpose '{purpose}'"))),
+    }
+  }
+}
+
+impl Display for StatusPurpose {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Information used to determine the current status of a [`Credential`][crate::credential::Credential]
+/// using the [`StatusList2021`](https://www.w3.org/TR/2023/WD-vc-status-list-20230427/) specification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusList2021Entry(Status);
+
+impl StatusList2021Entry {
+  const PURPOSE_PROPERTY: &'static str = "statusPurpose";
+  const INDEX_PROPERTY: &'static str = "statusListIndex";
+  const CREDENTIAL_PROPERTY: &'static str = "statusListCredential";
+  /// Type name of the status list entry.
+  pub const TYPE: &'static str = "StatusList2021Entry";
+
+  /// Creates a new `StatusList2021Entry` referencing the credential at `index` in the status list credential
+  /// located at `status_list_credential`, which must have the same `purpose`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use identity_core::common::Url;
+  /// # use identity_credential::credential::StatusList2021Entry;
+  /// # use identity_credential::credential::StatusPurpose;
+  /// let list: Url = Url::parse("https://example.com/credentials/status/3").unwrap();
+  /// let status_id: Url = Url::parse("https://example.com/credentials/status/3#94567").unwrap();
+  /// let status: StatusList2021Entry =
+  ///   StatusList2021Entry::new(status_id, StatusPurpose::Suspension, 94567, list.clone());
+  /// assert_eq!(status.purpose().unwrap(), StatusPurpose::Suspension);
+  /// assert_eq!(status.index().unwrap(), 94567);
+  /// assert_eq!(status.status_list_credential().unwrap(), list);
+  /// ```
+  pub fn new(id: Url, purpose: StatusPurpose, index: u32, status_list_credential: Url) -> Self {
+    let mut object = Object::new();
+    object.insert(
+      Self::PURPOSE_PROPERTY.to_owned(),
+      Value::String(purpose.as_str().to_owned()),
+    );
+    object.insert(Self::INDEX_PROPERTY.to_owned(), Value::String(index.to_string()));
+    object.insert(
+      Self::CREDENTIAL_PROPERTY.to_owned(),
+      Value::String(status_list_credential.into_string()),
+    );
+    StatusList2021Entry(Status::new_with_properties(id, Self::TYPE.to_owned(), object))
+  }
+
+  /// Returns the purpose of the entry if it can be decoded.
+  pub fn purpose(&self) -> Result<StatusPurpose> {
+    if let Some(Value::String(purpose)) = self.0.properties.get(Self::PURPOSE_PROPERTY) {
+      StatusPurpose::from_str(purpose)
+    } else {
+      Err(Error::InvalidStatus(format!(
+        "expected {} to be a string",
+        Self::PURPOSE_PROPERTY
+      )))
+    }
+  }
+
+  /// Returns the index of the credential in the status list if it can be decoded.
+  pub fn index(&self) -> Result<u32> {
+    if let Some(Value::String(index)) = self.0.properties.get(Self::INDEX_PROPERTY) {
+      u32::from_str(index).map_err(|err| {
+        Error::InvalidStatus(format!(
+          "{} cannot be converted to an unsigned, 32-bit integer: {err}",
+          Self::INDEX_PROPERTY
+        ))
+      })
+    } else {
+      Err(Error::InvalidStatus(format!(
+        "expected {} to be an unsigned 32-bit integer expressed as a string",
+        Self::INDEX_PROPERTY
+      )))
+    }
+  }
+
+  /// Returns the [`Url`] of the status list credential.
+  pub fn status_list_credential(&self) -> Result<Url> {
+    if let Some(Value::String(url)) = self.0.properties.get(Self::CREDENTIAL_PROPERTY) {
+      Url::parse(url).map_err(|err| Error::InvalidStatus(format!("invalid status list credential url: {err}")))
+    } else {
+      Err(Error::InvalidStatus(format!(
+        "expected {} to be a url expressed as a string",
+        Self::CREDENTIAL_PROPERTY
+      )))
+    }
+  }
+}
+
+impl TryFrom<Status> for StatusList2021Entry {
+  type Error = Error;
+
+  fn try_from(status: Status) -> Result<Self> {
+    if status.type_ != Self::TYPE {
+      return Err(Error::InvalidStatus(format!(
+        "expected type '{}', got '{}'",
+        Self::TYPE,
+        status.type_
+      )));
+    }
+
+    let status: Self = Self(status);
+    // Ensure all required properties are present and well-formed.
+    status.purpose()?;
+    status.index()?;
+    status.status_list_credential()?;
+
+    Ok(status)
+  }
+}
+
+impl From<StatusList2021Entry> for Status {
+  fn from(status: StatusList2021Entry) -> Self {
+    status.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::Status;
+  use super::StatusList2021Entry;
+  use super::StatusPurpose;
+
+  #[test]
+  fn test_status_list_2021_entry_from_json() {
+    let status: Status = Status::from_json_value(serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "StatusList2021Entry",
+      "statusPurpose": "revocation",
+      "statusListIndex": "94567",
+      "statusListCredential": "https://example.com/credentials/status/3"
+    }))
+    .unwrap();
+
+    let status: StatusList2021Entry = StatusList2021Entry::try_from(status).unwrap();
+    assert_eq!(status.purpose().unwrap(), StatusPurpose::Revocation);
+    assert_eq!(status.index().unwrap(), 94567);
+    assert_eq!(
+      status.status_list_credential().unwrap().as_str(),
+      "https://example.com/credentials/status/3"
+    );
+  }
+
+  #[test]
+  fn test_status_list_2021_entry_invalid() {
+    // INVALID: unknown purpose.
+    let status: Status = Status::from_json_value(serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "StatusList2021Entry",
+      "statusPurpose": "expiration",
+      "statusListIndex": "94567",
+      "statusListCredential": "https://example.com/credentials/status/3"
+    }))
+    .unwrap();
+    assert!(StatusList2021Entry::try_from(status).is_err());
+
+    // INVALID: missing purpose.
+    let status: Status = Status::from_json_value(serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "StatusList2021Entry",
+      "statusListIndex": "94567",
+      "statusListCredential": "https://example.com/credentials/status/3"
+    }))
+    .unwrap();
+    assert!(StatusList2021Entry::try_from(status).is_err());
+
+    // INVALID: wrong type.
+    let status: Status = Status::from_json_value(serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "RevocationList2020Status",
+      "statusPurpose": "revocation",
+      "statusListIndex": "94567",
+      "statusListCredential": "https://example.com/credentials/status/3"
+    }))
+    .unwrap();
+    assert!(StatusList2021Entry::try_from(status).is_err());
+  }
+}
@@ -3,6 +3,7 @@
 
 use identity_core::common::Object;
 use identity_core::common::Url;
+use identity_core::common::Value;
 
 /// An entity who is the target of a set of claims.
 ///
@@ -40,6 +41,17 @@ impl Subject {
       properties,
     }
   }
+
+  /// Looks up a nested property by a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901), e.g. `/degree/name`.
+  ///
+  /// The pointer is resolved against the [`properties`](Subject::properties), so the `id` of the subject cannot be
+  /// accessed this way. Returns `None` if the pointer is malformed or does not resolve to a value.
+  pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+    let pointer: &str = pointer.strip_prefix('/')?;
+    let (property, rest): (&str, &str) = pointer.split_at(pointer.find('/').unwrap_or(pointer.len()));
+    let property: String = property.replace("~1", "/").replace("~0", "~");
+    self.properties.get(&property)?.pointer(rest)
+  }
 }
 
 #[cfg(test)]
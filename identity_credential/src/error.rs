@@ -30,6 +30,9 @@ pub enum Error {
   /// Caused when constructing a credential with a malformed subject.
   #[error("invalid credential subject")]
   InvalidSubject,
+  /// Caused when constructing a credential with an evidence entry without a type.
+  #[error("invalid credential evidence: missing type")]
+  InvalidEvidence,
   /// Caused when trying to construct an invalid status.
   #[error("invalid credential status: {0}")]
   InvalidStatus(String),
@@ -48,6 +51,9 @@ pub enum Error {
   /// between the registered claims and those in the `vp` object.
   #[error("could not convert JWT to the VP data model: {0}")]
   InconsistentPresentationJwtClaims(&'static str),
+  /// Caused when the holder binding of a `JwtPresentation` does not reference a verification method of its holder.
+  #[error("invalid holder binding: {0}")]
+  InvalidHolderBinding(&'static str),
   /// Caused when attempting to parse a timestamp value that is outside the
   /// valid range defined in [RFC 3339](https://tools.ietf.org/html/rfc3339).  
   #[error("timestamp conversion failed")]
@@ -62,4 +68,12 @@ pub enum Error {
   /// JSON.
   #[error("could not deserialize JWT claims set")]
   JwtClaimsSetDeserializationError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+  /// Caused by a failure to serialize a `Credential` to JSON.
+  #[error("could not serialize credential")]
+  CredentialSerializationError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+
+  /// Caused by a credential JWT that is not a well-formed JWS in compact serialization.
+  #[error("malformed credential JWT")]
+  MalformedCredentialJwt(#[source] identity_verification::jose::error::Error),
 }
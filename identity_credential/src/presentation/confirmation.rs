@@ -0,0 +1,30 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+
+/// A proof-of-possession key confirmation in accordance with [RFC 7800](https://www.rfc-editor.org/rfc/rfc7800),
+/// binding a [`JwtPresentation`](crate::presentation::JwtPresentation) to a key of its holder.
+///
+/// Embedded as the `cnf` claim when the presentation is serialized as a JWT.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Confirmation {
+  /// The DID URL of the holder's verification method the presentation is bound to.
+  pub kid: Url,
+}
+
+impl Confirmation {
+  /// Creates a new [`Confirmation`] referencing the verification method identified by `kid`.
+  pub fn new(kid: Url) -> Self {
+    Self { kid }
+  }
+
+  /// Checks that `kid` references a verification method of `holder`, i.e. that it consists of the `holder` DID
+  /// followed by a fragment.
+  pub(crate) fn check_holder(&self, holder: &Url) -> bool {
+    let mut did: Url = self.kid.clone();
+    let has_fragment: bool = did.fragment().map(|fragment| !fragment.is_empty()).unwrap_or(false);
+    did.set_fragment(None);
+    has_fragment && &did == holder
+  }
+}
@@ -23,6 +23,7 @@ use crate::error::Error;
 use crate::error::Result;
 
 use super::jwt_serialization::PresentationJwtClaims;
+use super::Confirmation;
 use super::JwtPresentationBuilder;
 use super::JwtPresentationOptions;
 
@@ -55,6 +56,16 @@ pub struct JwtPresentation<T = Object> {
   /// Optional proof that can be verified by users in addition to JWS.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub proof: Option<Object>,
+  /// Optional key of the holder the `Presentation` is bound to.
+  ///
+  /// This is not part of the VP data model, it is only embedded as the `cnf` claim of the JWT representation.
+  #[serde(skip)]
+  pub holder_binding: Option<Confirmation>,
+  /// The intended audience(s) of the `Presentation`, usually the identifiers of the verifiers.
+  ///
+  /// This is not part of the VP data model, it is only embedded as the `aud` claim of the JWT representation.
+  #[serde(skip)]
+  pub audience: OneOrMany<Url>,
 }
 
 impl<T> JwtPresentation<T> {
@@ -87,6 +98,8 @@ impl<T> JwtPresentation<T> {
       terms_of_use: builder.terms_of_use.into(),
       properties: builder.properties,
       proof: None,
+      holder_binding: builder.holder_binding,
+      audience: builder.audience.into(),
     };
     this.check_structure()?;
 
@@ -110,6 +123,15 @@ impl<T> JwtPresentation<T> {
     if !self.types.iter().any(|type_| type_ == Self::base_type()) {
       return Err(Error::MissingBaseType);
     }
+
+    // The holder binding MUST reference a method of the holder
+    if let Some(holder_binding) = &self.holder_binding {
+      if !holder_binding.check_holder(&self.holder) {
+        return Err(Error::InvalidHolderBinding(
+          "the `kid` must be a DID URL of the holder with a fragment",
+        ));
+      }
+    }
     Ok(())
   }
 
@@ -5,12 +5,15 @@ use identity_core::common::Context;
 use identity_core::common::Object;
 use identity_core::common::Url;
 use identity_core::common::Value;
+use identity_verification::jws::Decoder;
 
 use crate::credential::Jwt;
 use crate::credential::Policy;
 use crate::credential::RefreshService;
+use crate::error::Error;
 use crate::error::Result;
 
+use super::Confirmation;
 use super::JwtPresentation;
 
 /// A `JwtPresentationBuilder` is used to create a customized [JwtPresentation].
@@ -24,6 +27,8 @@ pub struct JwtPresentationBuilder<T = Object> {
   pub(crate) refresh_service: Vec<RefreshService>,
   pub(crate) terms_of_use: Vec<Policy>,
   pub(crate) properties: T,
+  pub(crate) holder_binding: Option<Confirmation>,
+  pub(crate) audience: Vec<Url>,
 }
 
 impl<T> JwtPresentationBuilder<T> {
@@ -38,6 +43,8 @@ impl<T> JwtPresentationBuilder<T> {
       refresh_service: Vec::new(),
       terms_of_use: Vec::new(),
       properties,
+      holder_binding: None,
+      audience: Vec::new(),
     }
   }
 
@@ -83,6 +90,22 @@ impl<T> JwtPresentationBuilder<T> {
     self
   }
 
+  /// Binds the presentation to the holder's verification method identified by the DID URL `kid`,
+  /// which is embedded as the `cnf` claim of the presentation JWT.
+  #[must_use]
+  pub fn holder_binding(mut self, kid: Url) -> Self {
+    self.holder_binding = Some(Confirmation::new(kid));
+    self
+  }
+
+  /// Adds a value to the intended audience of the presentation, which is embedded as the `aud` claim of the
+  /// presentation JWT.
+  #[must_use]
+  pub fn audience(mut self, value: Url) -> Self {
+    self.audience.push(value);
+    self
+  }
+
   /// Returns a new `Presentation` based on the `PresentationBuilder` configuration.
   pub fn build(self) -> Result<JwtPresentation<T>> {
     JwtPresentation::from_builder(self)
@@ -90,6 +113,25 @@ impl<T> JwtPresentationBuilder<T> {
 }
 
 impl JwtPresentationBuilder<Object> {
+  /// Creates a new `JwtPresentationBuilder` for `holder` presenting the given credential JWTs, with the base context
+  /// and type of a presentation and no custom properties.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`Error::MalformedCredentialJwt`](crate::Error::MalformedCredentialJwt) if one of the `credentials` is
+  /// not a JWS in compact serialization. Signatures are not verified.
+  pub fn from_credentials(holder: Url, credentials: &[Jwt]) -> Result<Self> {
+    for credential in credentials {
+      Decoder::new()
+        .decode_compact_serialization(credential.as_str().as_bytes(), None)
+        .map_err(Error::MalformedCredentialJwt)?;
+    }
+
+    let mut builder: Self = Self::new(holder, Object::new());
+    builder.credentials.extend_from_slice(credentials);
+    Ok(builder)
+  }
+
   /// Adds a new custom property.
   #[must_use]
   pub fn property<K, V>(mut self, key: K, value: V) -> Self
@@ -124,11 +166,13 @@ mod tests {
   use identity_core::common::Object;
   use identity_core::common::Url;
   use identity_core::convert::FromJson;
+  use identity_verification::jwu::encode_b64;
 
   use crate::credential::Credential;
   use crate::credential::CredentialBuilder;
   use crate::credential::Jwt;
   use crate::credential::Subject;
+  use crate::error::Error;
   use crate::presentation::JwtPresentation;
   use crate::presentation::JwtPresentationBuilder;
 
@@ -179,4 +223,89 @@ mod tests {
     assert_eq!(presentation.types.get(1).unwrap(), "ExamplePresentation");
     assert_eq!(presentation.verifiable_credential.len(), 1);
   }
+
+  #[test]
+  fn test_presentation_builder_from_credentials() {
+    let credential_jwt = |type_: &str| -> Jwt {
+      let credential: Credential = CredentialBuilder::default()
+        .type_(type_)
+        .subject(subject())
+        .issuer(issuer())
+        .build()
+        .unwrap();
+      let header: String = encode_b64(r#"{"alg":"EdDSA","kid":"did:example:issuer#key-1"}"#);
+      let payload: String = encode_b64(credential.serialize_jwt().unwrap());
+      Jwt::new(format!("{header}.{payload}.{}", encode_b64([0_u8; 64])))
+    };
+    let holder: Url = Url::parse("did:test:abc1").unwrap();
+    let credentials: [Jwt; 2] = [credential_jwt("ExampleCredential"), credential_jwt("OtherCredential")];
+
+    let presentation: JwtPresentation = JwtPresentationBuilder::from_credentials(holder.clone(), &credentials)
+      .unwrap()
+      .build()
+      .unwrap();
+    assert_eq!(presentation.holder, holder);
+    assert_eq!(
+      presentation.context.as_slice(),
+      &[JwtPresentation::<Object>::base_context().clone()]
+    );
+    assert_eq!(
+      presentation.types.as_slice(),
+      &[JwtPresentation::<Object>::base_type().to_owned()]
+    );
+    assert_eq!(presentation.verifiable_credential.as_slice(), &credentials);
+
+    // INVALID: a credential that is not a JWS in compact serialization.
+    let credential: Credential = CredentialBuilder::default()
+      .subject(subject())
+      .issuer(issuer())
+      .build()
+      .unwrap();
+    let malformed: Jwt = Jwt::new(credential.serialize_jwt().unwrap());
+    assert!(matches!(
+      JwtPresentationBuilder::from_credentials(holder, &[credentials[0].clone(), malformed]),
+      Err(Error::MalformedCredentialJwt(_))
+    ));
+  }
+
+  #[test]
+  fn test_presentation_builder_holder_binding() {
+    let holder: Url = Url::parse("did:test:abc1").unwrap();
+    let presentation: JwtPresentation = JwtPresentationBuilder::new(holder.clone(), Object::new())
+      .holder_binding(Url::parse("did:test:abc1#key-1").unwrap())
+      .build()
+      .unwrap();
+    assert_eq!(presentation.holder_binding.unwrap().kid.as_str(), "did:test:abc1#key-1");
+
+    // INVALID: the key does not belong to the holder.
+    let result = JwtPresentationBuilder::new(holder.clone(), Object::new())
+      .holder_binding(Url::parse("did:test:other#key-1").unwrap())
+      .build();
+    assert!(matches!(result, Err(Error::InvalidHolderBinding(_))));
+
+    // INVALID: the key is not identified by a fragment.
+    let result = JwtPresentationBuilder::new(holder.clone(), Object::new())
+      .holder_binding(holder)
+      .build();
+    assert!(matches!(result, Err(Error::InvalidHolderBinding(_))));
+  }
+
+  #[test]
+  fn test_presentation_builder_audience() {
+    let holder: Url = Url::parse("did:test:abc1").unwrap();
+    let verifier_1: Url = Url::parse("did:test:verifier1").unwrap();
+    let verifier_2: Url = Url::parse("https://verifier.example.com").unwrap();
+
+    let presentation: JwtPresentation = JwtPresentationBuilder::new(holder.clone(), Object::new())
+      .build()
+      .unwrap();
+    assert!(presentation.audience.is_empty());
+
+    let presentation: JwtPresentation = JwtPresentationBuilder::new(holder, Object::new())
+      .audience(verifier_1.clone())
+      .audience(verifier_2.clone())
+      .build()
+      .unwrap();
+    assert_eq!(presentation.audience.as_slice(), &[verifier_1, verifier_2]);
+  }
 }
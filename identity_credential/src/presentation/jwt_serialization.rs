@@ -16,6 +16,7 @@ use crate::credential::IssuanceDateClaims;
 use crate::credential::Jwt;
 use crate::credential::Policy;
 use crate::credential::RefreshService;
+use crate::presentation::Confirmation;
 use crate::presentation::JwtPresentation;
 #[cfg(feature = "validator")]
 use crate::Error;
@@ -44,8 +45,13 @@ where
   #[serde(skip_serializing_if = "Option::is_none")]
   jti: Option<Cow<'presentation, Url>>,
 
+  /// Represents the audience of the presentation, either a single value or an array.
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub(crate) aud: Option<Url>,
+  pub(crate) aud: Option<OneOrMany<Url>>,
+
+  /// Represents the holder binding of the presentation.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cnf: Option<Cow<'presentation, Confirmation>>,
 
   pub(crate) vp: InnerPresentation<'presentation, T>,
 }
@@ -65,8 +71,16 @@ where
       terms_of_use,
       properties,
       proof,
+      holder_binding,
+      audience,
     } = presentation;
 
+    // The audience of the options is added to the audience of the presentation.
+    let mut aud: Vec<Url> = audience.iter().cloned().collect();
+    if let Some(option_audience) = options.audience.as_ref().filter(|url| !aud.contains(url)) {
+      aud.push(option_audience.clone());
+    }
+
     Ok(Self {
       iss: Cow::Borrowed(holder),
       jti: id.as_ref().map(Cow::Borrowed),
@@ -82,7 +96,8 @@ where
       },
       exp: options.expiration_date.map(|expiration_date| expiration_date.to_unix()),
       issuance_date: options.issuance_date.map(IssuanceDateClaims::new),
-      aud: options.audience.clone(),
+      aud: (!aud.is_empty()).then(|| aud.into()),
+      cnf: holder_binding.as_ref().map(Cow::Borrowed),
     })
   }
 }
@@ -131,7 +146,8 @@ where
       iss,
       issuance_date: _,
       jti,
-      aud: _,
+      aud,
+      cnf,
       vp,
     } = self;
     let InnerPresentation {
@@ -155,6 +171,8 @@ where
       terms_of_use: terms_of_use.into_owned(),
       properties: properties.into_owned(),
       proof: proof.map(Cow::into_owned),
+      holder_binding: cnf.map(Cow::into_owned),
+      audience: aud.unwrap_or_default(),
     };
 
     Ok(presentation)
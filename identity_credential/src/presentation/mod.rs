@@ -5,11 +5,13 @@
 
 #![allow(clippy::module_inception)]
 
+mod confirmation;
 mod jwt_presentation;
 mod jwt_presentation_builder;
 mod jwt_presentation_options;
 mod jwt_serialization;
 
+pub use self::confirmation::Confirmation;
 pub use self::jwt_presentation::JwtPresentation;
 pub use self::jwt_presentation_builder::JwtPresentationBuilder;
 pub use self::jwt_presentation_options::JwtPresentationOptions;
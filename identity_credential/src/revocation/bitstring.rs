@@ -0,0 +1,354 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::Read;
+use std::io::Write;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::utils::Base;
+use identity_core::utils::BaseEncoding;
+
+use super::error::RevocationError;
+use crate::credential::Credential;
+use crate::credential::Subject;
+
+/// An uncompressed bitstring for managing credential revocation in accordance with
+/// [Revocation List 2020](https://w3c-ccg.github.io/vc-status-rl-2020/).
+///
+/// The bit at position `index` is `1` if the credential with that index is revoked. Bits are ordered from the most
+/// significant bit of the first byte onwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevocationBitstring(Vec<u8>);
+
+impl RevocationBitstring {
+  /// The type of the `credentialSubject` of a revocation list credential.
+  pub const TYPE: &'static str = "RevocationList2020";
+  /// The type of a revocation list credential.
+  pub const CREDENTIAL_TYPE: &'static str = "RevocationList2020Credential";
+  /// The name of the `credentialSubject` property holding the encoded bitstring.
+  pub const ENCODED_LIST_PROPERTY: &'static str = "encodedList";
+  /// The minimum length of the bitstring in bytes (16KB), as mandated by the specification to provide adequate group
+  /// privacy. The bitstring always grows in multiples of this length.
+  pub const MIN_LENGTH: usize = 16 * 1024;
+  /// The maximum length of the bitstring in bytes (16MiB). The bitstring never grows beyond this length, and longer
+  /// lists are rejected when decoded, since they are fetched from untrusted sources and a small compressed list can
+  /// expand to an arbitrary size.
+  pub const MAX_LENGTH: usize = 1024 * Self::MIN_LENGTH;
+
+  /// Constructs a new [`RevocationBitstring`] of [`Self::MIN_LENGTH`] in which no credential is revoked.
+  pub fn new() -> Self {
+    Self(vec![0; Self::MIN_LENGTH])
+  }
+
+  /// Mark the given `index` as revoked, growing the bitstring if `index` exceeds its capacity.
+  ///
+  /// Returns true if the `index` was not revoked before.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`RevocationError::IndexOutOfBounds`] if the bitstring would have to grow beyond
+  /// [`Self::MAX_LENGTH`] to hold `index`.
+  pub fn set(&mut self, index: u32) -> Result<bool, RevocationError> {
+    let (byte, mask) = Self::position(index);
+    if byte >= Self::MAX_LENGTH {
+      return Err(RevocationError::IndexOutOfBounds(index));
+    }
+    if byte >= self.0.len() {
+      let length: usize = (byte / Self::MIN_LENGTH + 1) * Self::MIN_LENGTH;
+      self.0.resize(length, 0);
+    }
+    let was_set: bool = self.0[byte] & mask != 0;
+    self.0[byte] |= mask;
+    Ok(!was_set)
+  }
+
+  /// Mark the given `index` as not revoked.
+  ///
+  /// Returns true if the `index` was revoked before.
+  pub fn unset(&mut self, index: u32) -> bool {
+    let (byte, mask) = Self::position(index);
+    match self.0.get_mut(byte) {
+      Some(value) if *value & mask != 0 => {
+        *value &= !mask;
+        true
+      }
+      _ => false,
+    }
+  }
+
+  /// Returns `true` if the credential at the given `index` is revoked.
+  ///
+  /// Indices beyond the length of the bitstring are never revoked.
+  pub fn is_revoked(&self, index: u32) -> bool {
+    let (byte, mask) = Self::position(index);
+    self.0.get(byte).map(|value| value & mask != 0).unwrap_or(false)
+  }
+
+  /// Returns the number of indices that can be represented by this bitstring.
+  pub fn capacity(&self) -> u64 {
+    self.0.len() as u64 * 8
+  }
+
+  /// Decodes a GZIP-compressed, base64url-encoded bitstring as found in the `encodedList`
+  /// property of a revocation list credential.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the list cannot be decoded or the decompressed list exceeds [`Self::MAX_LENGTH`].
+  pub fn from_encoded_list(encoded_list: &str) -> Result<Self, RevocationError> {
+    let compressed: Vec<u8> = BaseEncoding::decode(encoded_list, Base::Base64Url)
+      .map_err(|e| RevocationError::Base64DecodingError(encoded_list.to_owned(), e))?;
+
+    let mut decompressed: Vec<u8> = Vec::new();
+    // Read at most one byte more than allowed, to detect oversized lists without decompressing them entirely.
+    GzDecoder::new(compressed.as_slice())
+      .take(Self::MAX_LENGTH as u64 + 1)
+      .read_to_end(&mut decompressed)
+      .map_err(RevocationError::BitmapDecodingError)?;
+    if decompressed.len() > Self::MAX_LENGTH {
+      return Err(RevocationError::InvalidRevocationList(
+        "the decoded list exceeds the maximum length",
+      ));
+    }
+    Ok(Self(decompressed))
+  }
+
+  /// Compresses the bitstring using GZIP and encodes it as base64url, as expected in the `encodedList`
+  /// property of a revocation list credential.
+  pub fn to_encoded_list(&self) -> Result<String, RevocationError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+      .write_all(&self.0)
+      .map_err(RevocationError::BitmapEncodingError)?;
+    let compressed: Vec<u8> = encoder.finish().map_err(RevocationError::BitmapEncodingError)?;
+    Ok(BaseEncoding::encode(&compressed, Base::Base64Url))
+  }
+
+  /// Returns a `credentialSubject` of type `RevocationList2020` identified by `id` embedding the encoded bitstring.
+  ///
+  /// The subject is intended to be used with a credential of type [`Self::CREDENTIAL_TYPE`].
+  pub fn to_subject(&self, id: Url) -> Result<Subject, RevocationError> {
+    let mut properties: Object = Object::new();
+    properties.insert("type".to_owned(), Value::String(Self::TYPE.to_owned()));
+    properties.insert(
+      Self::ENCODED_LIST_PROPERTY.to_owned(),
+      Value::String(self.to_encoded_list()?),
+    );
+    Ok(Subject::with_id_and_properties(id, properties))
+  }
+
+  /// Replaces the `encodedList` of the revocation list `credential` with this bitstring.
+  ///
+  /// Note that this invalidates any existing proof on the `credential`, it must be signed again afterwards.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the credential does not have exactly one subject of type `RevocationList2020`.
+  pub fn embed_into<T>(&self, credential: &mut Credential<T>) -> Result<(), RevocationError> {
+    let encoded_list: String = self.to_encoded_list()?;
+    if credential.credential_subject.len() != 1 {
+      return Err(RevocationError::InvalidRevocationList(
+        "expected exactly one credential subject",
+      ));
+    }
+    let subject: &mut Subject = credential
+      .credential_subject
+      .get_mut(0)
+      .expect("the credential should have exactly one subject");
+
+    if subject.properties.get("type") != Some(&Value::String(Self::TYPE.to_owned())) {
+      return Err(RevocationError::InvalidRevocationList(
+        "invalid subject type - expected `RevocationList2020`",
+      ));
+    }
+
+    subject
+      .properties
+      .insert(Self::ENCODED_LIST_PROPERTY.to_owned(), Value::String(encoded_list));
+    Ok(())
+  }
+
+  /// Extracts the bitstring from the `credentialSubject` of a revocation list credential.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the credential does not have exactly one subject of type `RevocationList2020`
+  /// or its `encodedList` cannot be decoded.
+  pub fn from_credential<T>(credential: &Credential<T>) -> Result<Self, RevocationError> {
+    let subject: &Subject = match credential.credential_subject.as_slice() {
+      [subject] => subject,
+      _ => {
+        return Err(RevocationError::InvalidRevocationList(
+          "expected exactly one credential subject",
+        ))
+      }
+    };
+
+    if subject.properties.get("type") != Some(&Value::String(Self::TYPE.to_owned())) {
+      return Err(RevocationError::InvalidRevocationList(
+        "invalid subject type - expected `RevocationList2020`",
+      ));
+    }
+
+    match subject.properties.get(Self::ENCODED_LIST_PROPERTY) {
+      Some(Value::String(encoded_list)) => Self::from_encoded_list(encoded_list),
+      _ => Err(RevocationError::InvalidRevocationList(
+        "missing or invalid `encodedList` property",
+      )),
+    }
+  }
+
+  /// Returns the byte offset and bit mask of `index`.
+  fn position(index: u32) -> (usize, u8) {
+    ((index / 8) as usize, 0x80 >> (index % 8))
+  }
+}
+
+impl Default for RevocationBitstring {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Write;
+
+  use flate2::write::GzEncoder;
+  use flate2::Compression;
+  use identity_core::common::Url;
+  use identity_core::utils::Base;
+  use identity_core::utils::BaseEncoding;
+
+  use super::RevocationBitstring;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::revocation::RevocationError;
+
+  #[test]
+  fn test_revocation_bitstring_test_vector_empty() {
+    // Example from https://w3c-ccg.github.io/vc-status-rl-2020/#revocationlist2020credential.
+    const ENCODED_LIST: &str = "H4sIAAAAAAAAA-3BMQEAAADCoPVPbQsvoAAAAAAAAAAAAAAAAP4GcwM92tQwAAA";
+
+    let bitstring: RevocationBitstring = RevocationBitstring::from_encoded_list(ENCODED_LIST).unwrap();
+
+    assert_eq!(bitstring.capacity(), 100_000);
+    for index in [0, 1, 94567, 99_999, 100_000] {
+      assert!(!bitstring.is_revoked(index));
+    }
+  }
+
+  #[test]
+  fn test_revocation_bitstring_invalid_encoding() {
+    assert!(RevocationBitstring::from_encoded_list("not base64!").is_err());
+    // Valid base64url but not GZIP compressed.
+    assert!(RevocationBitstring::from_encoded_list("AAAA").is_err());
+  }
+
+  #[test]
+  fn test_revocation_bitstring_exceeds_max_length() {
+    let compress = |length: usize| -> String {
+      let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+      encoder.write_all(&vec![0; length]).unwrap();
+      BaseEncoding::encode(&encoder.finish().unwrap(), Base::Base64Url)
+    };
+
+    let bitstring: RevocationBitstring =
+      RevocationBitstring::from_encoded_list(&compress(RevocationBitstring::MAX_LENGTH)).unwrap();
+    assert_eq!(bitstring.capacity(), RevocationBitstring::MAX_LENGTH as u64 * 8);
+
+    // A few KB of compressed data must not be expanded beyond the maximum length.
+    let encoded_list: String = compress(RevocationBitstring::MAX_LENGTH + 1);
+    assert!(encoded_list.len() < 64 * 1024);
+    assert!(matches!(
+      RevocationBitstring::from_encoded_list(&encoded_list),
+      Err(RevocationError::InvalidRevocationList(_))
+    ));
+  }
+
+  #[test]
+  fn test_revocation_bitstring_set_unset() {
+    let mut bitstring: RevocationBitstring = RevocationBitstring::new();
+    assert_eq!(bitstring.capacity(), RevocationBitstring::MIN_LENGTH as u64 * 8);
+
+    for index in [0, 7, 8, 1337] {
+      assert!(!bitstring.is_revoked(index));
+      assert!(bitstring.set(index).unwrap());
+      assert!(!bitstring.set(index).unwrap());
+      assert!(bitstring.is_revoked(index));
+    }
+    // Neighbouring indices are unaffected.
+    assert!(!bitstring.is_revoked(1));
+    assert!(!bitstring.is_revoked(1336));
+
+    assert!(bitstring.unset(7));
+    assert!(!bitstring.unset(7));
+    assert!(!bitstring.is_revoked(7));
+    assert!(bitstring.is_revoked(0));
+    assert!(bitstring.is_revoked(8));
+
+    // Indices beyond the capacity cannot be unset.
+    assert!(!bitstring.unset(u32::MAX));
+  }
+
+  #[test]
+  fn test_revocation_bitstring_growth() {
+    let mut bitstring: RevocationBitstring = RevocationBitstring::new();
+    let index: u32 = RevocationBitstring::MIN_LENGTH as u32 * 8 + 3;
+    assert!(!bitstring.is_revoked(index));
+
+    assert!(bitstring.set(index).unwrap());
+    assert!(bitstring.is_revoked(index));
+    assert_eq!(bitstring.capacity(), RevocationBitstring::MIN_LENGTH as u64 * 8 * 2);
+
+    // The bitstring grows up to its maximum length, but not beyond.
+    let max_index: u32 = RevocationBitstring::MAX_LENGTH as u32 * 8 - 1;
+    assert!(bitstring.set(max_index).unwrap());
+    assert_eq!(bitstring.capacity(), RevocationBitstring::MAX_LENGTH as u64 * 8);
+    for index in [max_index + 1, u32::MAX] {
+      assert!(matches!(
+        bitstring.set(index),
+        Err(RevocationError::IndexOutOfBounds(i)) if i == index
+      ));
+      assert!(!bitstring.is_revoked(index));
+    }
+    assert_eq!(bitstring.capacity(), RevocationBitstring::MAX_LENGTH as u64 * 8);
+  }
+
+  #[test]
+  fn test_revocation_bitstring_credential_round_trip() {
+    let mut bitstring: RevocationBitstring = RevocationBitstring::new();
+    for index in [5, 398, 67000] {
+      bitstring.set(index).unwrap();
+    }
+
+    let mut credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse("did:example:1234").unwrap())
+      .type_(RevocationBitstring::CREDENTIAL_TYPE)
+      .subject(
+        bitstring
+          .to_subject(Url::parse("https://example.com/status/3#list").unwrap())
+          .unwrap(),
+      )
+      .build()
+      .unwrap();
+    assert_eq!(RevocationBitstring::from_credential(&credential).unwrap(), bitstring);
+
+    // Update the embedded bitstring.
+    bitstring.unset(398);
+    bitstring.set(200_000).unwrap();
+    bitstring.embed_into(&mut credential).unwrap();
+
+    let decoded: RevocationBitstring = RevocationBitstring::from_credential(&credential).unwrap();
+    assert_eq!(decoded, bitstring);
+    assert!(decoded.is_revoked(5));
+    assert!(!decoded.is_revoked(398));
+    assert!(decoded.is_revoked(67000));
+    assert!(decoded.is_revoked(200_000));
+  }
+}
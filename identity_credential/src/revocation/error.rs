@@ -17,6 +17,15 @@ pub enum RevocationError {
   /// `RevocationBitmaps`.
   #[error("{0}")]
   InvalidService(&'static str),
+  /// Indicates that a credential could not be interpreted as a `RevocationList2020` credential.
+  #[error("invalid revocation list: {0}")]
+  InvalidRevocationList(&'static str),
+  /// Indicates that an index exceeds the maximum capacity of a revocation list.
+  #[error("index {0} exceeds the maximum capacity of the revocation list")]
+  IndexOutOfBounds(u32),
+  /// Indicates that a credential could not be interpreted as a `StatusList2021` credential.
+  #[error("invalid status list: {0}")]
+  InvalidStatusList(&'static str),
   /// Indicates a failure to decode a bitmap from a base64 string representation.
   #[error("unable to decode base64 string: `{0}`")]
   Base64DecodingError(String, #[source] identity_core::error::Error),
@@ -1,11 +1,19 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-//! Contains a bitmap for managing credential revocation.
+//! Contains a bitmap, a bitstring and a status list for managing credential revocation.
 mod bitmap;
+#[cfg(feature = "credential")]
+mod bitstring;
 mod document_ext;
 mod error;
+#[cfg(feature = "credential")]
+mod status_list_2021;
 pub use self::bitmap::RevocationBitmap;
+#[cfg(feature = "credential")]
+pub use self::bitstring::RevocationBitstring;
 pub use self::document_ext::RevocationDocumentExt;
 pub use self::error::RevocationError;
 pub use self::error::RevocationResult;
+#[cfg(feature = "credential")]
+pub use self::status_list_2021::StatusList2021;
@@ -0,0 +1,242 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+use super::error::RevocationError;
+use super::RevocationBitstring;
+use crate::credential::Credential;
+use crate::credential::StatusPurpose;
+use crate::credential::Subject;
+
+/// A status list in accordance with [Status List 2021](https://www.w3.org/TR/2023/WD-vc-status-list-20230427/),
+/// revoking or suspending credentials depending on its [`StatusPurpose`].
+///
+/// The bit at position `index` is `1` if the status of the credential with that index is set. The bits are encoded
+/// like a [`RevocationBitstring`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatusList2021 {
+  purpose: StatusPurpose,
+  bitstring: RevocationBitstring,
+}
+
+impl StatusList2021 {
+  /// The type of the `credentialSubject` of a status list credential.
+  pub const TYPE: &'static str = "StatusList2021";
+  /// The type of a status list credential.
+  pub const CREDENTIAL_TYPE: &'static str = "StatusList2021Credential";
+  /// The name of the `credentialSubject` property holding the purpose of the list.
+  pub const PURPOSE_PROPERTY: &'static str = "statusPurpose";
+
+  /// Constructs a new [`StatusList2021`] for `purpose` of [`RevocationBitstring::MIN_LENGTH`] in which no status
+  /// is set.
+  pub fn new(purpose: StatusPurpose) -> Self {
+    Self {
+      purpose,
+      bitstring: RevocationBitstring::new(),
+    }
+  }
+
+  /// Returns the purpose of the list.
+  pub fn purpose(&self) -> StatusPurpose {
+    self.purpose
+  }
+
+  /// Sets the status of the given `index`, growing the list if `index` exceeds its capacity.
+  ///
+  /// Returns true if the status of `index` was not set before.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`RevocationError::IndexOutOfBounds`] if the list would have to grow beyond
+  /// [`RevocationBitstring::MAX_LENGTH`] to hold `index`.
+  pub fn set(&mut self, index: u32) -> Result<bool, RevocationError> {
+    self.bitstring.set(index)
+  }
+
+  /// Clears the status of the given `index`, e.g. to lift a suspension.
+  ///
+  /// Returns true if the status of `index` was set before.
+  pub fn unset(&mut self, index: u32) -> bool {
+    self.bitstring.unset(index)
+  }
+
+  /// Returns `true` if the status of the credential at the given `index` is set, i.e. the credential is revoked or
+  /// suspended according to the purpose of the list.
+  ///
+  /// Indices beyond the length of the list are never set.
+  pub fn is_set(&self, index: u32) -> bool {
+    self.bitstring.is_revoked(index)
+  }
+
+  /// Returns a `credentialSubject` of type `StatusList2021` identified by `id` embedding the purpose and the encoded
+  /// list.
+  ///
+  /// The subject is intended to be used with a credential of type [`Self::CREDENTIAL_TYPE`].
+  pub fn to_subject(&self, id: Url) -> Result<Subject, RevocationError> {
+    let mut properties: Object = Object::new();
+    properties.insert("type".to_owned(), Value::String(Self::TYPE.to_owned()));
+    properties.insert(
+      Self::PURPOSE_PROPERTY.to_owned(),
+      Value::String(self.purpose.as_str().to_owned()),
+    );
+    properties.insert(
+      RevocationBitstring::ENCODED_LIST_PROPERTY.to_owned(),
+      Value::String(self.bitstring.to_encoded_list()?),
+    );
+    Ok(Subject::with_id_and_properties(id, properties))
+  }
+
+  /// Replaces the `encodedList` of the status list `credential` with this list.
+  ///
+  /// Note that this invalidates any existing proof on the `credential`, it must be signed again afterwards.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the credential does not have exactly one subject of type `StatusList2021` with the purpose of this list.
+  pub fn embed_into<T>(&self, credential: &mut Credential<T>) -> Result<(), RevocationError> {
+    let encoded_list: String = self.bitstring.to_encoded_list()?;
+    if credential.credential_subject.len() != 1 {
+      return Err(RevocationError::InvalidStatusList(
+        "expected exactly one credential subject",
+      ));
+    }
+    let subject: &mut Subject = credential
+      .credential_subject
+      .get_mut(0)
+      .expect("the credential should have exactly one subject");
+    if Self::subject_purpose(subject)? != self.purpose {
+      return Err(RevocationError::InvalidStatusList("mismatching status purpose"));
+    }
+
+    subject.properties.insert(
+      RevocationBitstring::ENCODED_LIST_PROPERTY.to_owned(),
+      Value::String(encoded_list),
+    );
+    Ok(())
+  }
+
+  /// Extracts the list from the `credentialSubject` of a status list credential.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the credential does not have exactly one subject of type `StatusList2021`, its `statusPurpose` is
+  /// not supported or its `encodedList` cannot be decoded.
+  pub fn from_credential<T>(credential: &Credential<T>) -> Result<Self, RevocationError> {
+    let subject: &Subject = match credential.credential_subject.as_slice() {
+      [subject] => subject,
+      _ => {
+        return Err(RevocationError::InvalidStatusList(
+          "expected exactly one credential subject",
+        ))
+      }
+    };
+    let purpose: StatusPurpose = Self::subject_purpose(subject)?;
+
+    match subject.properties.get(RevocationBitstring::ENCODED_LIST_PROPERTY) {
+      Some(Value::String(encoded_list)) => Ok(Self {
+        purpose,
+        bitstring: RevocationBitstring::from_encoded_list(encoded_list)?,
+      }),
+      _ => Err(RevocationError::InvalidStatusList(
+        "missing or invalid `encodedList` property",
+      )),
+    }
+  }
+
+  /// Returns the purpose of a subject of type `StatusList2021`.
+  fn subject_purpose(subject: &Subject) -> Result<StatusPurpose, RevocationError> {
+    if subject.properties.get("type") != Some(&Value::String(Self::TYPE.to_owned())) {
+      return Err(RevocationError::InvalidStatusList(
+        "invalid subject type - expected `StatusList2021`",
+      ));
+    }
+
+    match subject.properties.get(Self::PURPOSE_PROPERTY) {
+      Some(Value::String(purpose)) => StatusPurpose::from_str(purpose)
+        .map_err(|_| RevocationError::InvalidStatusList("unsupported `statusPurpose` property")),
+      _ => Err(RevocationError::InvalidStatusList(
+        "missing or invalid `statusPurpose` property",
+      )),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+
+  use super::StatusList2021;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::StatusPurpose;
+
+  fn status_list_credential(list: &StatusList2021) -> Credential {
+    CredentialBuilder::default()
+      .issuer(Url::parse("did:example:1234").unwrap())
+      .type_(StatusList2021::CREDENTIAL_TYPE)
+      .subject(
+        list
+          .to_subject(Url::parse("https://example.com/status/3#list").unwrap())
+          .unwrap(),
+      )
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn test_status_list_2021_credential_round_trip() {
+    for purpose in [StatusPurpose::Revocation, StatusPurpose::Suspension] {
+      let mut list: StatusList2021 = StatusList2021::new(purpose);
+      for index in [5, 398, 67000] {
+        assert!(list.set(index).unwrap());
+      }
+
+      let mut credential: Credential = status_list_credential(&list);
+      assert_eq!(StatusList2021::from_credential(&credential).unwrap(), list);
+
+      // Update the embedded list.
+      assert!(list.unset(398));
+      assert!(list.set(200_000).unwrap());
+      list.embed_into(&mut credential).unwrap();
+
+      let decoded: StatusList2021 = StatusList2021::from_credential(&credential).unwrap();
+      assert_eq!(decoded.purpose(), purpose);
+      assert!(decoded.is_set(5));
+      assert!(!decoded.is_set(398));
+      assert!(decoded.is_set(67000));
+      assert!(decoded.is_set(200_000));
+    }
+  }
+
+  #[test]
+  fn test_status_list_2021_invalid_credential() {
+    let list: StatusList2021 = StatusList2021::new(StatusPurpose::Revocation);
+    let mut credential: Credential = status_list_credential(&list);
+
+    // A list cannot be embedded into a credential of another purpose.
+    assert!(StatusList2021::new(StatusPurpose::Suspension)
+      .embed_into(&mut credential)
+      .is_err());
+
+    credential
+      .credential_subject
+      .get_mut(0)
+      .unwrap()
+      .properties
+      .insert("statusPurpose".to_owned(), "expiration".into());
+    assert!(StatusList2021::from_credential(&credential).is_err());
+
+    credential
+      .credential_subject
+      .get_mut(0)
+      .unwrap()
+      .properties
+      .insert("type".to_owned(), "RevocationList2020".into());
+    assert!(StatusList2021::from_credential(&credential).is_err());
+  }
+}
@@ -0,0 +1,27 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use identity_core::common::Value;
+
+/// Fetches the JSON Schemas referenced by a credential's
+/// [`credentialSchema`](https://www.w3.org/TR/vc-data-model/#data-schemas) property.
+///
+/// The validators only evaluate the credential subjects against the returned schema, implementors are responsible
+/// for establishing trust in it (e.g. by pinning known schemas) before returning it.
+///
+/// This trait is implemented for closures of the form `Fn(&Url) -> Result<Value, E>`.
+pub trait CredentialSchemaResolver {
+  /// Fetches the JSON Schema located at `url`.
+  fn fetch_schema(&self, url: &Url) -> Result<Value, Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+impl<F, E> CredentialSchemaResolver for F
+where
+  F: Fn(&Url) -> Result<Value, E>,
+  E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+{
+  fn fetch_schema(&self, url: &Url) -> Result<Value, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    self(url).map_err(Into::into)
+  }
+}
@@ -2,13 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Verifiable Credential and Presentation validators.
+pub use self::credential_schema_resolver::CredentialSchemaResolver;
+pub use self::options::CredentialErrorPolicy;
 pub use self::options::FailFast;
+pub use self::options::MultipleSubjectsPolicy;
 pub use self::options::StatusCheck;
 pub use self::options::SubjectHolderRelationship;
+pub use self::status_list_resolver::StatusListResolver;
 pub use self::vc_jwt_validation::*;
 pub use self::vp_jwt_validation::*;
 
+mod credential_schema_resolver;
 mod options;
+mod status_list_resolver;
 #[cfg(test)]
 pub(crate) mod test_utils;
 mod vc_jwt_validation;
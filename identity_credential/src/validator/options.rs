@@ -12,7 +12,8 @@ pub enum StatusCheck {
   /// Validate the status if supported, reject any unsupported
   /// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status) types.
   ///
-  /// Only `RevocationBitmap2022` is currently supported.
+  /// Only `RevocationBitmap2022` is currently supported, as well as `RevocationList2020Status` and
+  /// `StatusList2021Entry` when validating with a [`StatusListResolver`](crate::validator::StatusListResolver).
   ///
   /// This is the default.
   Strict = 0,
@@ -53,6 +54,52 @@ impl Default for SubjectHolderRelationship {
   }
 }
 
+/// Declares how the holder must match the subjects of a credential with more than one
+/// [`credentialSubject`](https://www.w3.org/TR/vc-data-model/#credential-subject) when checking the
+/// [`SubjectHolderRelationship`].
+// Need to use serde_repr to make this work with duck typed interfaces in the Wasm bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[repr(u8)]
+pub enum MultipleSubjectsPolicy {
+  /// The holder never matches a credential with more than one subject.
+  /// This is the variant returned by [Self::default](Self::default()).
+  NoMatch = 0,
+  /// The holder must match at least one of the subjects.
+  Any = 1,
+  /// The holder must match all of the subjects.
+  All = 2,
+}
+
+impl Default for MultipleSubjectsPolicy {
+  fn default() -> Self {
+    Self::NoMatch
+  }
+}
+
+/// Declares how errors of the credentials contained in a presentation are reported, independently of the
+/// [`FailFast`] used for the presentation itself.
+///
+/// Errors concerning the structure of the presentation or the holder's signature always end validation immediately,
+/// since the credentials cannot be trusted without them.
+// Need to use serde_repr to make this work with duck typed interfaces in the Wasm bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr)]
+#[repr(u8)]
+pub enum CredentialErrorPolicy {
+  /// Credential errors are reported according to the [`FailFast`] passed to the validator.
+  /// This is the variant returned by [Self::default](Self::default()).
+  FollowFailFast = 0,
+  /// Every credential is validated and the first error of each invalid credential is reported.
+  AccumulateFirstErrors = 1,
+  /// Every credential is validated and all errors of each invalid credential are reported.
+  AccumulateAllErrors = 2,
+}
+
+impl Default for CredentialErrorPolicy {
+  fn default() -> Self {
+    Self::FollowFailFast
+  }
+}
+
 /// Declares when validation should return if an error occurs.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum FailFast {
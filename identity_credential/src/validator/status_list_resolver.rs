@@ -0,0 +1,30 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+
+use crate::credential::Credential;
+
+/// Fetches status list credentials referenced by a credential's
+/// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status), such as the `revocationListCredential`
+/// of a [`RevocationList2020Status`](crate::credential::RevocationList2020Status) or the `statusListCredential` of a
+/// [`StatusList2021Entry`](crate::credential::StatusList2021Entry).
+///
+/// The validators only evaluate the bitstring of the returned credential, implementors are responsible for
+/// establishing trust in it (e.g. by verifying its proof) before returning it.
+///
+/// This trait is implemented for closures of the form `Fn(&Url) -> Result<Credential, E>`.
+pub trait StatusListResolver {
+  /// Fetches the status list credential located at `url`.
+  fn fetch_status_list(&self, url: &Url) -> Result<Credential, Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+impl<F, E> StatusListResolver for F
+where
+  F: Fn(&Url) -> Result<Credential, E>,
+  E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+{
+  fn fetch_status_list(&self, url: &Url) -> Result<Credential, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    self(url).map_err(Into::into)
+  }
+}
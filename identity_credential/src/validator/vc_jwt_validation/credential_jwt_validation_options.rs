@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 // TODO: Replace or update the equivalent types in the parent module.
+use identity_core::common::Duration;
 use identity_core::common::Timestamp;
+use identity_did::CoreDID;
 use identity_document::verifiable::JwsVerificationOptions;
 use serde::Deserialize;
 use serde::Serialize;
@@ -22,6 +24,17 @@ pub struct CredentialValidationOptions {
   /// Uses the current datetime during validation if not set.
   #[serde(default)]
   pub latest_issuance_date: Option<Timestamp>,
+  /// Tolerance for clock skew between the issuer and the verifier, applied to the issuance and expiration date
+  /// checks: a credential is still considered valid if it expired, or was issued in the future, by at most this
+  /// [`Duration`].
+  /// Default: zero.
+  #[serde(default)]
+  pub leeway: Duration,
+  /// The datetime considered to be the current one during validation, used in place of
+  /// [`Self::earliest_expiry_date`] and [`Self::latest_issuance_date`] when those are not set.
+  /// Uses the system clock if not set.
+  #[serde(default)]
+  pub now: Option<Timestamp>,
 
   /// Validation behaviour for [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
   ///
@@ -29,6 +42,38 @@ pub struct CredentialValidationOptions {
   #[serde(default)]
   pub status: crate::validator::StatusCheck,
 
+  /// Declares whether the credential subjects are validated against the JSON Schemas referenced in
+  /// [`credentialSchema`](https://www.w3.org/TR/vc-data-model/#data-schemas).
+  ///
+  /// Requires a [`CredentialSchemaResolver`](crate::validator::CredentialSchemaResolver) during validation.
+  /// Default: `false`.
+  #[cfg(feature = "credential-schema")]
+  #[serde(default)]
+  pub check_credential_schema: bool,
+
+  /// Declares whether the [`refreshService`](https://www.w3.org/TR/vc-data-model/#refreshing) entries of the
+  /// credential are checked to be well-formed, see
+  /// [`CredentialValidator::check_refresh_service`](crate::validator::CredentialValidator::check_refresh_service).
+  /// Default: `false`.
+  #[serde(default)]
+  pub check_refresh_service: bool,
+
+  /// Declares whether the expiration date of the credential is checked on the unverified claims before its signature
+  /// is verified, so that expired credentials are rejected without the cost of a signature verification.
+  ///
+  /// Note that an expired credential is then reported as such even if its signature is invalid.
+  /// Default: `false`.
+  #[serde(default)]
+  pub check_expiration_before_signature: bool,
+
+  /// Declares the DIDs of the issuers whose credentials are accepted, credentials of any other issuer are rejected
+  /// with [`ValidationError::UntrustedIssuer`](crate::validator::ValidationError::UntrustedIssuer).
+  ///
+  /// Credentials of any issuer are accepted if empty.
+  /// Default: empty.
+  #[serde(default)]
+  pub trusted_issuers: Vec<CoreDID>,
+
   /// Options which affect the verification of the signature on the credential.
   #[serde(default)]
   pub verification_options: JwsVerificationOptions,
@@ -54,12 +99,51 @@ impl CredentialValidationOptions {
     self
   }
 
+  /// Set the tolerance for clock skew applied to the issuance and expiration date checks.
+  pub fn leeway(mut self, leeway: Duration) -> Self {
+    self.leeway = leeway;
+    self
+  }
+
+  /// Pin the datetime considered to be the current one during validation, e.g. to make tests deterministic.
+  pub fn now(mut self, timestamp: Timestamp) -> Self {
+    self.now = Some(timestamp);
+    self
+  }
+
   /// Sets the validation behaviour for [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
   pub fn status_check(mut self, status_check: crate::validator::StatusCheck) -> Self {
     self.status = status_check;
     self
   }
 
+  /// Declare whether the credential subjects are validated against the JSON Schemas referenced in
+  /// [`credentialSchema`](https://www.w3.org/TR/vc-data-model/#data-schemas).
+  #[cfg(feature = "credential-schema")]
+  pub fn check_credential_schema(mut self, value: bool) -> Self {
+    self.check_credential_schema = value;
+    self
+  }
+
+  /// Declare whether the [`refreshService`](https://www.w3.org/TR/vc-data-model/#refreshing) entries of the
+  /// credential are checked to be well-formed.
+  pub fn check_refresh_service(mut self, value: bool) -> Self {
+    self.check_refresh_service = value;
+    self
+  }
+
+  /// Declare whether the expiration date of the credential is checked before its signature is verified.
+  pub fn check_expiration_before_signature(mut self, value: bool) -> Self {
+    self.check_expiration_before_signature = value;
+    self
+  }
+
+  /// Declare the DIDs of the issuers whose credentials are accepted, an empty list accepts credentials of any issuer.
+  pub fn trusted_issuers(mut self, issuers: Vec<CoreDID>) -> Self {
+    self.trusted_issuers = issuers;
+    self
+  }
+
   /// Set options which affect the verification of the JWS signature.
   pub fn verification_options(mut self, options: JwsVerificationOptions) -> Self {
     self.verification_options = options;
@@ -19,6 +19,7 @@ use identity_verification::jws::Decoder;
 use identity_verification::jws::EdDSAJwsVerifier;
 use identity_verification::jws::JwsValidationItem;
 use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::SignatureVerificationErrorKind;
 
 use super::CompoundCredentialValidationError;
 use super::CredentialValidationOptions;
@@ -28,7 +29,11 @@ use super::ValidationError;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::Jwt;
+use crate::credential::Subject;
+use crate::validator::CredentialSchemaResolver;
 use crate::validator::FailFast;
+use crate::validator::MultipleSubjectsPolicy;
+use crate::validator::StatusListResolver;
 use crate::validator::SubjectHolderRelationship;
 
 /// A type for decoding and validating [`Credential`]s.
@@ -67,7 +72,9 @@ where
   ///
   /// ## Properties that are not validated
   ///  There are many properties defined in [The Verifiable Credentials Data Model](https://www.w3.org/TR/vc-data-model/) that are **not** validated, such as:
-  /// `proof`, `credentialStatus`, `type`, `credentialSchema`, `refreshService` **and more**.
+  /// `proof`, `type`, `credentialSchema` (unless the `credential-schema` feature is enabled), `refreshService` **and more**.
+  /// Of the `credentialStatus` types only `RevocationBitmap2022` is checked, see
+  /// [`Self::validate_with_status_resolver`] for `RevocationList2020Status` and `StatusList2021Entry` support.
   /// These should be manually checked after validation, according to your requirements.
   ///
   /// # Errors
@@ -89,6 +96,92 @@ where
       std::slice::from_ref(issuer.as_ref()),
       options,
       None,
+      None,
+      None,
+      fail_fast,
+    )
+  }
+
+  /// Decodes and validates a batch of [`Credential`]s issued as JWTs by the same `issuer`, in the same way as
+  /// [`Self::validate`].
+  ///
+  /// The `issuer` DID Document only has to be resolved once for the whole batch. A result is returned for every
+  /// credential, in the order of `credentials`, the failure of one credential does not affect the others.
+  pub fn validate_batch<DOC, T>(
+    &self,
+    credentials: &[Jwt],
+    issuer: &DOC,
+    options: &CredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Vec<Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    credentials
+      .iter()
+      .map(|credential_jwt| self.validate(credential_jwt, issuer, options, fail_fast))
+      .collect()
+  }
+
+  /// Decodes and validates a [`Credential`] issued as a JWT in the same way as [`Self::validate`], additionally
+  /// using `status_resolver` to fetch the status list of credentials with a
+  /// [`RevocationList2020Status`](crate::credential::RevocationList2020Status) or a
+  /// [`StatusList2021Entry`](crate::credential::StatusList2021Entry).
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  pub fn validate_with_status_resolver<DOC, T>(
+    &self,
+    credential_jwt: &Jwt,
+    issuer: &DOC,
+    options: &CredentialValidationOptions,
+    status_resolver: &dyn StatusListResolver,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    Self::validate_extended::<CoreDocument, V, T>(
+      &self.0,
+      credential_jwt,
+      std::slice::from_ref(issuer.as_ref()),
+      options,
+      None,
+      Some(status_resolver),
+      None,
+      fail_fast,
+    )
+  }
+
+  /// Decodes and validates a [`Credential`] issued as a JWT in the same way as [`Self::validate`], additionally
+  /// using `schema_resolver` to fetch the JSON Schemas referenced in `credentialSchema` when
+  /// [`CredentialValidationOptions::check_credential_schema`] is set.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  #[cfg(feature = "credential-schema")]
+  pub fn validate_with_schema_resolver<DOC, T>(
+    &self,
+    credential_jwt: &Jwt,
+    issuer: &DOC,
+    options: &CredentialValidationOptions,
+    schema_resolver: &dyn CredentialSchemaResolver,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    Self::validate_extended::<CoreDocument, V, T>(
+      &self.0,
+      credential_jwt,
+      std::slice::from_ref(issuer.as_ref()),
+      options,
+      None,
+      None,
+      Some(schema_resolver),
       fail_fast,
     )
   }
@@ -124,13 +217,27 @@ where
 
   // This method takes a slice of issuer's instead of a single issuer in order to better accommodate presentation
   // validation. It also validates the relationship between a holder and the credential subjects when
-  // `relationship_criterion` is Some.
+  // `relationship_criterion` is Some, fetches external revocation lists when `status_resolver` is Some and
+  // fetches credential schemas using `schema_resolver` if requested by `options`.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      level = "debug",
+      name = "validate_credential",
+      skip_all,
+      fields(issuer = tracing::field::Empty),
+      err(Display)
+    )
+  )]
+  #[allow(clippy::too_many_arguments)]
   pub(crate) fn validate_extended<DOC, S, T>(
     signature_verifier: &S,
     credential: &Jwt,
     issuers: &[DOC],
     options: &CredentialValidationOptions,
-    relationship_criterion: Option<(&Url, SubjectHolderRelationship)>,
+    relationship_criterion: Option<(&Url, SubjectHolderRelationship, MultipleSubjectsPolicy)>,
+    status_resolver: Option<&dyn StatusListResolver>,
+    schema_resolver: Option<&dyn CredentialSchemaResolver>,
     fail_fast: FailFast,
   ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
   where
@@ -138,6 +245,25 @@ where
     S: JwsVerifier,
     DOC: AsRef<CoreDocument>,
   {
+    let earliest_expiry_date: Timestamp = {
+      let earliest_expiry_date: Timestamp = options.earliest_expiry_date.or(options.now).unwrap_or_default();
+      earliest_expiry_date
+        .checked_sub(options.leeway)
+        .unwrap_or(earliest_expiry_date)
+    };
+
+    // Reject expired credentials before carrying out the comparatively expensive signature verification if requested.
+    // Credentials that cannot be decoded are left to the signature verification below to report.
+    if options.check_expiration_before_signature {
+      if let Ok(credential) = Self::decode_unverified(credential.as_str()) {
+        CredentialValidator::check_expires_on_or_after(&credential, earliest_expiry_date).map_err(|err| {
+          CompoundCredentialValidationError {
+            validation_errors: [err].into(),
+          }
+        })?;
+      }
+    }
+
     // First verify the JWS signature and decode the result into a credential token, then apply all other validations.
     // If this errors we have to return early regardless of the `fail_fast` flag as all other validations require a
     // `&Credential`.
@@ -148,40 +274,81 @@ where
         })?;
 
     let credential: &Credential<T> = &credential_token.credential;
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("issuer", credential.issuer.url().as_str());
     // Run all single concern Credential validations in turn and fail immediately if `fail_fast` is true.
 
     let expiry_date_validation = std::iter::once_with(|| {
-      CredentialValidator::check_expires_on_or_after(
-        &credential_token.credential,
-        options.earliest_expiry_date.unwrap_or_default(),
-      )
+      CredentialValidator::check_expires_on_or_after(&credential_token.credential, earliest_expiry_date)
     });
 
     let issuance_date_validation = std::iter::once_with(|| {
-      CredentialValidator::check_issued_on_or_before(credential, options.latest_issuance_date.unwrap_or_default())
+      let latest_issuance_date: Timestamp = options.latest_issuance_date.or(options.now).unwrap_or_default();
+      CredentialValidator::check_issued_on_or_before(
+        credential,
+        latest_issuance_date
+          .checked_add(options.leeway)
+          .unwrap_or(latest_issuance_date),
+      )
     });
 
     let structure_validation = std::iter::once_with(|| CredentialValidator::check_structure(credential));
 
+    let trusted_issuer_validation = std::iter::once_with(|| {
+      if options.trusted_issuers.is_empty() {
+        Ok(())
+      } else {
+        CredentialValidator::check_trusted_issuer(credential, &options.trusted_issuers)
+      }
+    });
+
     let subject_holder_validation = std::iter::once_with(|| {
       relationship_criterion
-        .map(|(holder, relationship)| {
-          CredentialValidator::check_subject_holder_relationship(credential, holder, relationship)
+        .map(|(holder, relationship, policy)| {
+          CredentialValidator::check_subject_holder_relationship_with_policy(credential, holder, relationship, policy)
         })
         .unwrap_or(Ok(()))
     });
 
+    let refresh_service_validation = std::iter::once_with(|| {
+      if options.check_refresh_service {
+        CredentialValidator::check_refresh_service(credential)
+      } else {
+        Ok(())
+      }
+    });
+
     let validation_units_iter = issuance_date_validation
       .chain(expiry_date_validation)
       .chain(structure_validation)
-      .chain(subject_holder_validation);
+      .chain(trusted_issuer_validation)
+      .chain(subject_holder_validation)
+      .chain(refresh_service_validation);
 
     #[cfg(feature = "revocation-bitmap")]
     let validation_units_iter = {
-      let revocation_validation =
-        std::iter::once_with(|| CredentialValidator::check_status(credential, issuers, options.status));
+      let revocation_validation = std::iter::once_with(|| {
+        CredentialValidator::check_status_extended(credential, issuers, status_resolver, options.status)
+      });
       validation_units_iter.chain(revocation_validation)
     };
+    #[cfg(not(feature = "revocation-bitmap"))]
+    let _ = status_resolver;
+
+    #[cfg(feature = "credential-schema")]
+    let validation_units_iter = {
+      let schema_validation = std::iter::once_with(|| {
+        if !options.check_credential_schema {
+          return Ok(());
+        }
+        schema_resolver
+          .ok_or_else(|| ValidationError::SchemaResolution("no credential schema resolver was provided".into()))
+          .and_then(|schema_resolver| CredentialValidator::check_credential_schema(credential, schema_resolver))
+      });
+      validation_units_iter.chain(schema_validation)
+    };
+    #[cfg(not(feature = "credential-schema"))]
+    let _ = schema_resolver;
 
     let validation_units_error_iter = validation_units_iter.filter_map(|result| result.err());
     let validation_errors: Vec<ValidationError> = match fail_fast {
@@ -223,6 +390,20 @@ where
       ));
     }
 
+    // Reject algorithms the verifier does not accept before looking up the key.
+    if !decoded
+      .alg()
+      .map(|alg| options.is_accepted_algorithm(alg))
+      .unwrap_or(false)
+    {
+      return Err(ValidationError::Signature {
+        source: identity_verification::jose::error::Error::SignatureVerificationError(
+          SignatureVerificationErrorKind::UnsupportedAlg.into(),
+        ),
+        signer_ctx: SignerContext::Issuer,
+      });
+    }
+
     // Parse the `kid` to a DID Url which should be the identifier of a verification method in a trusted issuer's DID
     // document TODO: Consider factoring this section into a private method that the (future) PresentationValidator
     // can also use. In that case The `SignerContext` used in the error would have to be passed as an additional
@@ -285,6 +466,15 @@ where
       .map_err(ValidationError::JwsDecodingError)
   }
 
+  /// Decode the claims of the credential into a [`Credential`] without verifying its signature.
+  fn decode_unverified(credential_jws: &str) -> Result<Credential<Object>, ValidationError> {
+    let decoded: JwsValidationItem<'_> = Self::decode(credential_jws)?;
+    CredentialJwtClaims::<'_, Object>::from_json_slice(&decoded.claims())
+      .map_err(|err| ValidationError::CredentialStructure(crate::Error::JwtClaimsSetDeserializationError(err.into())))?
+      .try_into_credential()
+      .map_err(ValidationError::CredentialStructure)
+  }
+
   /// Verify the signature using the given `public_key` and `signature_verifier`.
   fn verify_decoded_signature<S: JwsVerifier, T>(
     decoded: JwsValidationItem<'_>,
@@ -341,6 +531,64 @@ impl CredentialValidator {
       .map_err(ValidationError::CredentialStructure)
   }
 
+  /// Validates the credential subjects against every JSON Schema referenced in the
+  /// [`credentialSchema`](https://www.w3.org/TR/vc-data-model/#data-schemas) property of the [`Credential`],
+  /// fetching the schemas using `schema_resolver`.
+  ///
+  /// A credential without a `credentialSchema` is considered valid.
+  #[cfg(feature = "credential-schema")]
+  pub fn check_credential_schema<T>(
+    credential: &Credential<T>,
+    schema_resolver: &dyn CredentialSchemaResolver,
+  ) -> ValidationUnitResult {
+    use identity_core::common::Value;
+    use identity_core::convert::ToJson;
+
+    let subjects: Vec<Value> = credential
+      .credential_subject
+      .iter()
+      .map(|subject| subject.to_json_value())
+      .collect::<Result<_, _>>()
+      .map_err(|_| ValidationError::CredentialStructure(crate::Error::InvalidSubject))?;
+
+    for schema in credential.credential_schema.iter() {
+      let schema_json: Value = schema_resolver
+        .fetch_schema(&schema.id)
+        .map_err(ValidationError::SchemaResolution)?;
+      let compiled: jsonschema::JSONSchema = jsonschema::JSONSchema::compile(&schema_json)
+        .map_err(|err| ValidationError::SchemaResolution(err.to_string().into()))?;
+
+      for subject in subjects.iter() {
+        if let Err(errors) = compiled.validate(subject) {
+          return Err(ValidationError::SubjectSchema {
+            schema: schema.id.clone(),
+            errors: errors.map(|error| error.to_string()).collect(),
+          });
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Validate that every [`refreshService`](https://www.w3.org/TR/vc-data-model/#refreshing) of the [`Credential`]
+  /// is well-formed, i.e. declares a type and is identified by an `http` or `https` URL with a host.
+  ///
+  /// A credential without a `refreshService` is considered valid.
+  pub fn check_refresh_service<T>(credential: &Credential<T>) -> ValidationUnitResult {
+    for service in credential.refresh_service.iter() {
+      if !matches!(service.id.scheme(), "http" | "https") || service.id.host_str().is_none() {
+        return Err(ValidationError::InvalidRefreshService(
+          "the id must be an http or https URL with a host",
+        ));
+      }
+      if service.types.is_empty() {
+        return Err(ValidationError::InvalidRefreshService("missing type"));
+      }
+    }
+    Ok(())
+  }
+
   /// Validate that the [`Credential`] expires on or after the specified [`Timestamp`].
   pub fn check_expires_on_or_after<T>(credential: &Credential<T>, timestamp: Timestamp) -> ValidationUnitResult {
     let expiration_date: Option<Timestamp> = credential.expiration_date;
@@ -356,24 +604,54 @@ impl CredentialValidator {
       .ok_or(ValidationError::IssuanceDate)
   }
 
+  /// Validate that the issuer of the [`Credential`] is one of the `trusted_issuers`.
+  pub fn check_trusted_issuer<T>(credential: &Credential<T>, trusted_issuers: &[CoreDID]) -> ValidationUnitResult {
+    CoreDID::parse(credential.issuer.url().as_str())
+      .ok()
+      .filter(|issuer| trusted_issuers.contains(issuer))
+      .map(|_| ())
+      .ok_or(ValidationError::UntrustedIssuer)
+  }
+
   /// Validate that the relationship between the `holder` and the credential subjects is in accordance with
   /// `relationship`.
+  ///
+  /// A credential with more than one subject is never considered to be held by its subject, see
+  /// [`Self::check_subject_holder_relationship_with_policy`] to configure this.
   pub fn check_subject_holder_relationship<T>(
     credential: &Credential<T>,
     holder: &Url,
     relationship: SubjectHolderRelationship,
   ) -> ValidationUnitResult {
+    Self::check_subject_holder_relationship_with_policy(
+      credential,
+      holder,
+      relationship,
+      MultipleSubjectsPolicy::NoMatch,
+    )
+  }
+
+  /// Validate that the relationship between the `holder` and the credential subjects is in accordance with
+  /// `relationship`, where `policy` declares whether the `holder` must match any or all subjects of a credential
+  /// with more than one subject.
+  pub fn check_subject_holder_relationship_with_policy<T>(
+    credential: &Credential<T>,
+    holder: &Url,
+    relationship: SubjectHolderRelationship,
+    policy: MultipleSubjectsPolicy,
+  ) -> ValidationUnitResult {
+    let is_holder = |subject: &Subject| subject.id.as_ref() == Some(holder);
     let url_matches: bool = match &credential.credential_subject {
-      OneOrMany::One(ref credential_subject) => credential_subject.id.as_ref() == Some(holder),
-      OneOrMany::Many(subjects) => {
+      OneOrMany::One(ref credential_subject) => is_holder(credential_subject),
+      OneOrMany::Many(subjects) => match (subjects.as_slice(), policy) {
         // need to check the case where the Many variant holds a vector of exactly one subject
-        if let [credential_subject] = subjects.as_slice() {
-          credential_subject.id.as_ref() == Some(holder)
-        } else {
-          // zero or > 1 subjects is interpreted to mean that the holder is not the subject
-          false
-        }
-      }
+        ([credential_subject], _) => is_holder(credential_subject),
+        // zero subjects is interpreted to mean that the holder is not the subject
+        ([], _) => false,
+        (_, MultipleSubjectsPolicy::NoMatch) => false,
+        (subjects, MultipleSubjectsPolicy::Any) => subjects.iter().any(is_holder),
+        (subjects, MultipleSubjectsPolicy::All) => subjects.iter().all(is_holder),
+      },
     };
 
     Some(relationship)
@@ -390,12 +668,37 @@ impl CredentialValidator {
 
   /// Checks whether the credential status has been revoked.
   ///
-  /// Only supports `RevocationBitmap2022`.
+  /// Only supports `RevocationBitmap2022`. See [`Self::check_status_with_resolver`] for
+  /// `RevocationList2020Status` and `StatusList2021Entry` support.
   #[cfg(feature = "revocation-bitmap")]
   pub fn check_status<DOC: AsRef<CoreDocument>, T>(
     credential: &Credential<T>,
     trusted_issuers: &[DOC],
     status_check: crate::validator::StatusCheck,
+  ) -> ValidationUnitResult {
+    Self::check_status_extended(credential, trusted_issuers, None, status_check)
+  }
+
+  /// Checks whether the credential status has been revoked.
+  ///
+  /// Supports `RevocationBitmap2022`, `RevocationList2020Status` and `StatusList2021Entry`. The status list credential
+  /// of the latter two is fetched using `status_resolver` and must be issued by the issuer of `credential`.
+  #[cfg(feature = "revocation-bitmap")]
+  pub fn check_status_with_resolver<DOC: AsRef<CoreDocument>, T>(
+    credential: &Credential<T>,
+    trusted_issuers: &[DOC],
+    status_resolver: &dyn StatusListResolver,
+    status_check: crate::validator::StatusCheck,
+  ) -> ValidationUnitResult {
+    Self::check_status_extended(credential, trusted_issuers, Some(status_resolver), status_check)
+  }
+
+  #[cfg(feature = "revocation-bitmap")]
+  fn check_status_extended<DOC: AsRef<CoreDocument>, T>(
+    credential: &Credential<T>,
+    trusted_issuers: &[DOC],
+    status_resolver: Option<&dyn StatusListResolver>,
+    status_check: crate::validator::StatusCheck,
   ) -> ValidationUnitResult {
     if status_check == crate::validator::StatusCheck::SkipAll {
       return Ok(());
@@ -403,17 +706,7 @@ impl CredentialValidator {
 
     match &credential.credential_status {
       None => Ok(()),
-      Some(status) => {
-        // Check status is supported.
-        if status.type_ != crate::revocation::RevocationBitmap::TYPE {
-          if status_check == crate::validator::StatusCheck::SkipUnsupported {
-            return Ok(());
-          }
-          return Err(ValidationError::InvalidStatus(crate::Error::InvalidStatus(format!(
-            "unsupported type '{}'",
-            status.type_
-          ))));
-        }
+      Some(status) if status.type_ == crate::revocation::RevocationBitmap::TYPE => {
         let status: crate::credential::RevocationBitmapStatus =
           crate::credential::RevocationBitmapStatus::try_from(status.clone())
             .map_err(ValidationError::InvalidStatus)?;
@@ -426,6 +719,29 @@ impl CredentialValidator {
           .ok_or(ValidationError::DocumentMismatch(SignerContext::Issuer))
           .and_then(|issuer| Self::check_revocation_bitmap_status(issuer, status))
       }
+      Some(status) => match status_resolver {
+        Some(status_resolver) if status.type_ == crate::credential::RevocationList2020Status::TYPE => {
+          let status: crate::credential::RevocationList2020Status =
+            crate::credential::RevocationList2020Status::try_from(status.clone())
+              .map_err(ValidationError::InvalidStatus)?;
+          Self::check_revocation_list_2020_status(credential, status, status_resolver)
+        }
+        Some(status_resolver) if status.type_ == crate::credential::StatusList2021Entry::TYPE => {
+          let status: crate::credential::StatusList2021Entry =
+            crate::credential::StatusList2021Entry::try_from(status.clone()).map_err(ValidationError::InvalidStatus)?;
+          Self::check_status_list_2021_entry(credential, status, status_resolver)
+        }
+        // Check status is supported.
+        _ => {
+          if status_check == crate::validator::StatusCheck::SkipUnsupported {
+            return Ok(());
+          }
+          Err(ValidationError::InvalidStatus(crate::Error::InvalidStatus(format!(
+            "unsupported type '{}'",
+            status.type_
+          ))))
+        }
+      },
     }
   }
 
@@ -453,6 +769,80 @@ impl CredentialValidator {
     }
   }
 
+  /// Check the given `status` against the revocation list credential fetched by `status_resolver`.
+  #[cfg(feature = "revocation-bitmap")]
+  fn check_revocation_list_2020_status<T>(
+    credential: &Credential<T>,
+    status: crate::credential::RevocationList2020Status,
+    status_resolver: &dyn StatusListResolver,
+  ) -> ValidationUnitResult {
+    let list_url: Url = status
+      .revocation_list_credential()
+      .map_err(ValidationError::InvalidStatus)?;
+    let list_credential: Credential = status_resolver
+      .fetch_status_list(&list_url)
+      .map_err(ValidationError::StatusListResolution)?;
+
+    // Only the issuer of a credential may revoke it.
+    if list_credential.issuer.url() != credential.issuer.url() {
+      return Err(ValidationError::InvalidStatus(crate::Error::InvalidStatus(
+        "revocation list credential was not issued by the credential issuer".to_owned(),
+      )));
+    }
+
+    // Check whether index is revoked.
+    let bitstring: crate::revocation::RevocationBitstring =
+      crate::revocation::RevocationBitstring::from_credential(&list_credential)
+        .map_err(|err| ValidationError::InvalidStatus(crate::Error::InvalidStatus(err.to_string())))?;
+    let index: u32 = status.index().map_err(ValidationError::InvalidStatus)?;
+    if bitstring.is_revoked(index) {
+      Err(ValidationError::Revoked)
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Check the given `status` against the status list credential fetched by `status_resolver`.
+  #[cfg(feature = "revocation-bitmap")]
+  fn check_status_list_2021_entry<T>(
+    credential: &Credential<T>,
+    status: crate::credential::StatusList2021Entry,
+    status_resolver: &dyn StatusListResolver,
+  ) -> ValidationUnitResult {
+    let list_url: Url = status
+      .status_list_credential()
+      .map_err(ValidationError::InvalidStatus)?;
+    let list_credential: Credential = status_resolver
+      .fetch_status_list(&list_url)
+      .map_err(ValidationError::StatusListResolution)?;
+
+    // Only the issuer of a credential may revoke or suspend it.
+    if list_credential.issuer.url() != credential.issuer.url() {
+      return Err(ValidationError::InvalidStatus(crate::Error::InvalidStatus(
+        "status list credential was not issued by the credential issuer".to_owned(),
+      )));
+    }
+
+    let status_list: crate::revocation::StatusList2021 =
+      crate::revocation::StatusList2021::from_credential(&list_credential)
+        .map_err(|err| ValidationError::InvalidStatus(crate::Error::InvalidStatus(err.to_string())))?;
+    let purpose: crate::credential::StatusPurpose = status.purpose().map_err(ValidationError::InvalidStatus)?;
+    if status_list.purpose() != purpose {
+      return Err(ValidationError::InvalidStatus(crate::Error::InvalidStatus(format!(
+        "expected a status list with purpose '{purpose}', got '{}'",
+        status_list.purpose()
+      ))));
+    }
+
+    // Check whether index is set.
+    let index: u32 = status.index().map_err(ValidationError::InvalidStatus)?;
+    match (status_list.is_set(index), purpose) {
+      (false, _) => Ok(()),
+      (true, crate::credential::StatusPurpose::Revocation) => Err(ValidationError::Revoked),
+      (true, crate::credential::StatusPurpose::Suspension) => Err(ValidationError::Suspended),
+    }
+  }
+
   /// Utility for extracting the issuer field of a [`Credential`] as a DID.
   ///
   /// # Errors
@@ -506,8 +896,10 @@ mod tests {
   use identity_core::common::Duration;
   // All tests here are essentially adaptations of the old CredentialValidator tests.
   use super::*;
+  use crate::credential::RefreshService;
   use identity_core::common::Object;
   use identity_core::common::Timestamp;
+  use identity_core::common::Url;
   use proptest::proptest;
   const LAST_RFC3339_COMPATIBLE_UNIX_TIMESTAMP: i64 = 253402300799; // 9999-12-31T23:59:59Z
   const FIRST_RFC3999_COMPATIBLE_UNIX_TIMESTAMP: i64 = -62167219200; // 0000-01-01T00:00:00Z
@@ -549,6 +941,35 @@ mod tests {
     assert!(CredentialValidator::check_expires_on_or_after(&SIMPLE_CREDENTIAL, earlier_date).is_ok());
   }
 
+  #[test]
+  fn check_refresh_service() {
+    let mut credential: Credential = SIMPLE_CREDENTIAL.clone();
+    assert!(CredentialValidator::check_refresh_service(&credential).is_ok());
+
+    credential.refresh_service = RefreshService::new(
+      Url::parse("https://example.edu/refresh/3732").unwrap(),
+      "ManualRefreshService2018".to_owned(),
+    )
+    .into();
+    assert!(CredentialValidator::check_refresh_service(&credential).is_ok());
+
+    for url in ["did:example:refresh", "ftp://example.edu/refresh/3732"] {
+      credential.refresh_service =
+        RefreshService::new(Url::parse(url).unwrap(), "ManualRefreshService2018".to_owned()).into();
+      assert!(matches!(
+        CredentialValidator::check_refresh_service(&credential),
+        Err(ValidationError::InvalidRefreshService(_))
+      ));
+    }
+
+    credential.refresh_service =
+      RefreshService::new(Url::parse("https://example.edu/refresh/3732").unwrap(), Vec::new()).into();
+    assert!(matches!(
+      CredentialValidator::check_refresh_service(&credential),
+      Err(ValidationError::InvalidRefreshService(_))
+    ));
+  }
+
   // test with a few timestamps that should be RFC3339 compatible
   proptest! {
     #[test]
@@ -570,6 +991,142 @@ mod tests {
     }
   }
 
+  #[test]
+  fn check_subject_holder_relationship_multiple_subjects() {
+    let mut credential = SIMPLE_CREDENTIAL.clone();
+    let holder: Url = credential.credential_subject.first().unwrap().id.clone().unwrap();
+    credential
+      .credential_subject
+      .push(Subject::with_id(Url::parse("did:example:other").unwrap()));
+    assert_eq!(credential.credential_subject.len(), 2);
+
+    let check = |credential: &Credential, policy: MultipleSubjectsPolicy| {
+      CredentialValidator::check_subject_holder_relationship_with_policy(
+        credential,
+        &holder,
+        SubjectHolderRelationship::AlwaysSubject,
+        policy,
+      )
+    };
+
+    // the holder only matches one of the two subjects.
+    assert!(check(&credential, MultipleSubjectsPolicy::NoMatch).is_err());
+    assert!(check(&credential, MultipleSubjectsPolicy::Any).is_ok());
+    assert!(check(&credential, MultipleSubjectsPolicy::All).is_err());
+    // the default policy preserves the behaviour of `check_subject_holder_relationship`.
+    assert!(check(&credential, MultipleSubjectsPolicy::default()).is_err());
+    assert!(CredentialValidator::check_subject_holder_relationship(
+      &credential,
+      &holder,
+      SubjectHolderRelationship::AlwaysSubject
+    )
+    .is_err());
+
+    // the holder matches every subject.
+    credential.credential_subject = vec![Subject::with_id(holder.clone()), Subject::with_id(holder.clone())].into();
+    assert!(check(&credential, MultipleSubjectsPolicy::Any).is_ok());
+    assert!(check(&credential, MultipleSubjectsPolicy::All).is_ok());
+  }
+
+  #[test]
+  fn check_holder_among_multiple_subjects() {
+    // the policy applied by `JwtPresentationValidator::check_all_subjects_are_holder`.
+    let check = |credential: &Credential, holder: &Url| {
+      CredentialValidator::check_subject_holder_relationship_with_policy(
+        credential,
+        holder,
+        SubjectHolderRelationship::AlwaysSubject,
+        MultipleSubjectsPolicy::Any,
+      )
+    };
+    let holder: Url = Url::parse("did:example:holder").unwrap();
+    let mut credential: Credential = SIMPLE_CREDENTIAL.clone();
+    credential.credential_subject = vec![
+      Subject::with_id(Url::parse("did:example:first").unwrap()),
+      Subject::with_id(Url::parse("did:example:second").unwrap()),
+      Subject::with_id(holder.clone()),
+    ]
+    .into();
+    assert!(check(&credential, &holder).is_ok());
+
+    // the holder is none of the subjects, even though the credential is transferable.
+    credential.non_transferable = Some(false);
+    assert!(matches!(
+      check(&credential, &Url::parse("did:example:other").unwrap()),
+      Err(ValidationError::SubjectHolderRelationship)
+    ));
+
+    // a subject without an id never matches the holder.
+    credential.credential_subject = vec![
+      Subject::new(),
+      Subject::with_id(Url::parse("did:example:first").unwrap()),
+    ]
+    .into();
+    assert!(matches!(
+      check(&credential, &holder),
+      Err(ValidationError::SubjectHolderRelationship)
+    ));
+  }
+
+  #[cfg(feature = "credential-schema")]
+  #[test]
+  fn check_credential_schema() {
+    use crate::credential::Schema;
+    use identity_core::common::Value;
+
+    let schema_url: Url = Url::parse("https://example.edu/schemas/degree.json").unwrap();
+    let resolver = |url: &Url| -> Result<Value, std::io::Error> {
+      assert_eq!(url.as_str(), "https://example.edu/schemas/degree.json");
+      Ok(serde_json::json!({
+        "type": "object",
+        "required": ["id", "degree"],
+        "properties": {
+          "degree": {
+            "type": "object",
+            "required": ["type", "name"],
+            "properties": {
+              "type": { "type": "string" },
+              "name": { "type": "string" }
+            }
+          }
+        }
+      }))
+    };
+
+    // a credential without a schema is not checked.
+    assert!(CredentialValidator::check_credential_schema(&*SIMPLE_CREDENTIAL, &resolver).is_ok());
+
+    // a conforming subject.
+    let mut credential: Credential = SIMPLE_CREDENTIAL.clone();
+    credential.credential_schema = Schema::new(schema_url.clone(), "JsonSchemaValidator2018".to_owned()).into();
+    assert!(CredentialValidator::check_credential_schema(&credential, &resolver).is_ok());
+
+    // a non-conforming subject.
+    credential.credential_subject = Subject::from_json_value(serde_json::json!({
+      "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+      "degree": {
+        "type": "BachelorDegree",
+        "name": 42
+      }
+    }))
+    .unwrap()
+    .into();
+    match CredentialValidator::check_credential_schema(&credential, &resolver) {
+      Err(ValidationError::SubjectSchema { schema, errors }) => {
+        assert_eq!(schema, schema_url);
+        assert_eq!(errors.len(), 1);
+      }
+      other => panic!("expected a subject schema error, got {other:?}"),
+    }
+
+    // a schema that cannot be fetched.
+    let failing_resolver = |_: &Url| -> Result<Value, std::io::Error> { Err(std::io::ErrorKind::NotFound.into()) };
+    assert!(matches!(
+      CredentialValidator::check_credential_schema(&credential, &failing_resolver),
+      Err(ValidationError::SchemaResolution(_))
+    ));
+  }
+
   proptest! {
     #[test]
     fn property_based_issued_before(seconds in 0 ..1_000_000_000_u32) {
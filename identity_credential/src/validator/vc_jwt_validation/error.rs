@@ -98,9 +98,43 @@ pub enum ValidationError {
   #[error("service lookup error")]
   #[non_exhaustive]
   ServiceLookupError,
+  /// Indicates that the status list credential referenced by the credential's status could not be fetched.
+  #[error("could not fetch status list credential")]
+  StatusListResolution(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Indicates that a JSON Schema referenced by the credential's `credentialSchema` could not be fetched or
+  /// compiled.
+  #[error("could not fetch credential schema")]
+  SchemaResolution(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Indicates that a credential subject does not conform to a JSON Schema referenced by the credential.
+  #[error("the credential subject does not conform to the schema {schema}")]
+  #[non_exhaustive]
+  SubjectSchema {
+    /// The URL of the schema the subject was validated against.
+    schema: identity_core::common::Url,
+    /// Descriptions of the individual schema violations.
+    errors: Vec<String>,
+  },
+  /// Indicates that a refresh service of the credential is malformed.
+  #[error("invalid credential refresh service: {0}")]
+  InvalidRefreshService(&'static str),
+  /// Indicates that the issuer of the credential is not one of the trusted issuers.
+  #[error("the credential issuer is not trusted")]
+  UntrustedIssuer,
   /// Indicates that the credential has been revoked.
   #[error("credential has been revoked")]
   Revoked,
+  /// Indicates that the credential has been suspended.
+  #[error("credential has been suspended")]
+  Suspended,
+  /// Indicates that the audience of the presentation is missing or does not contain the expected audience.
+  #[error("the presentation is not intended for the expected audience")]
+  Audience,
+  /// Indicates that the nonce of the presentation is missing or does not match the expected challenge.
+  #[error("the presentation nonce does not match the expected challenge")]
+  Challenge,
+  /// Indicates that the presentation contains more credentials than allowed.
+  #[error("the presentation contains more than {0} credentials")]
+  TooManyCredentials(usize),
 }
 
 /// Specifies whether an error is related to a credential issuer or the presentation holder.
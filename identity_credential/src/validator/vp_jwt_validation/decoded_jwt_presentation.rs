@@ -25,6 +25,8 @@ pub struct DecodedJwtPresentation<T = Object, U = Object> {
   /// The issuance date parsed from the JWT claims.
   pub issuance_date: Option<Timestamp>,
   /// The `aud` property parsed from the JWT claims.
+  ///
+  /// If the claim is an array, this is its first element.
   pub aud: Option<Url>,
   /// The credentials included in the presentation (decoded).
   pub credentials: Vec<DecodedJwtCredential<U>>,
@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Timestamp;
+use identity_core::common::Url;
 use identity_document::verifiable::JwsVerificationOptions;
 
 use crate::validator::vc_jwt_validation::CredentialValidationOptions;
+use crate::validator::CredentialErrorPolicy;
+use crate::validator::MultipleSubjectsPolicy;
 use crate::validator::SubjectHolderRelationship;
 
 /// Criteria for validating a [`JwtPresentation`](crate::presentation::JwtPresentation).
@@ -22,6 +25,14 @@ pub struct JwtPresentationValidationOptions {
   /// Default: [`SubjectHolderRelationship::AlwaysSubject`].
   #[serde(default)]
   pub subject_holder_relationship: SubjectHolderRelationship,
+  /// Declares how the holder must match the subjects of credentials with more than one subject.
+  /// Default: [`MultipleSubjectsPolicy::NoMatch`].
+  #[serde(default)]
+  pub multiple_subjects_policy: MultipleSubjectsPolicy,
+  /// Declares how errors of the credentials in the presentation are reported.
+  /// Default: [`CredentialErrorPolicy::FollowFailFast`].
+  #[serde(default)]
+  pub credential_error_policy: CredentialErrorPolicy,
 
   /// Declares that the presentation is **not** considered valid if it expires before this
   /// [`Timestamp`].
@@ -34,6 +45,34 @@ pub struct JwtPresentationValidationOptions {
   /// Uses the current datetime during validation if not set.
   #[serde(default)]
   pub latest_issuance_date: Option<Timestamp>,
+
+  /// The datetime considered to be the current one when validating the presentation, used in place of
+  /// [`Self::earliest_expiry_date`] and [`Self::latest_issuance_date`] when those are not set.
+  /// Credentials in the presentation are validated against [`CredentialValidationOptions::now`] of
+  /// [`Self::shared_validation_options`] instead.
+  /// Uses the system clock if not set.
+  #[serde(default)]
+  pub now: Option<Timestamp>,
+
+  /// Declares that the presentation is **not** considered valid if its `aud` claim is missing or does not contain
+  /// this audience, which is usually the identifier of the verifier.
+  /// The audience is not checked if not set.
+  #[serde(default)]
+  pub expected_audience: Option<Url>,
+
+  /// Declares that the presentation is **not** considered valid if the `nonce` in its protected header is missing or
+  /// does not match this challenge, which the verifier issued to the holder to prevent replay.
+  /// Takes precedence over the nonce of [`Self::presentation_verifier_options`].
+  /// The nonce is not checked against a challenge if not set.
+  #[serde(default)]
+  pub challenge: Option<String>,
+
+  /// Declares that the presentation is **not** considered valid if it contains more than this number of
+  /// credentials. The number is checked before any signature is verified, bounding the work spent on
+  /// oversized presentations.
+  /// The number of credentials is unbounded if not set.
+  #[serde(default)]
+  pub max_credentials: Option<usize>,
 }
 
 impl JwtPresentationValidationOptions {
@@ -58,6 +97,19 @@ impl JwtPresentationValidationOptions {
     self.subject_holder_relationship = options;
     self
   }
+
+  /// Declares how the holder must match the subjects of credentials with more than one subject.
+  pub fn multiple_subjects_policy(mut self, policy: MultipleSubjectsPolicy) -> Self {
+    self.multiple_subjects_policy = policy;
+    self
+  }
+
+  /// Declares how errors of the credentials in the presentation are reported, e.g. to collect the errors of all
+  /// credentials while still failing fast on the presentation itself.
+  pub fn credential_error_policy(mut self, policy: CredentialErrorPolicy) -> Self {
+    self.credential_error_policy = policy;
+    self
+  }
   /// Declare that the presentation is **not** considered valid if it expires before this [`Timestamp`].
   /// Uses the current datetime during validation if not set.
   pub fn earliest_expiry_date(mut self, timestamp: Timestamp) -> Self {
@@ -71,4 +123,31 @@ impl JwtPresentationValidationOptions {
     self.latest_issuance_date = Some(timestamp);
     self
   }
+
+  /// Pin the datetime considered to be the current one when validating the presentation.
+  pub fn now(mut self, timestamp: Timestamp) -> Self {
+    self.now = Some(timestamp);
+    self
+  }
+
+  /// Declare that the presentation is **not** considered valid if its `aud` claim is missing or does not contain
+  /// `audience`.
+  pub fn expected_audience(mut self, audience: Url) -> Self {
+    self.expected_audience = Some(audience);
+    self
+  }
+
+  /// Declare that the presentation is **not** considered valid if the `nonce` in its protected header is missing or
+  /// does not match `challenge`.
+  pub fn challenge(mut self, challenge: impl Into<String>) -> Self {
+    self.challenge = Some(challenge.into());
+    self
+  }
+
+  /// Declare that the presentation is **not** considered valid if it contains more than `max_credentials`
+  /// credentials.
+  pub fn max_credentials(mut self, max_credentials: usize) -> Self {
+    self.max_credentials = Some(max_credentials);
+    self
+  }
 }
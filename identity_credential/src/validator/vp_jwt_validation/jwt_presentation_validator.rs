@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
@@ -9,13 +10,20 @@ use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
+use identity_did::DIDUrl;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
+use identity_document::verifiable::verify_jws_with_any_method;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_document::verifiable::VerifiedJws;
 use identity_verification::jws::DecodedJws;
 use identity_verification::jws::Decoder;
 use identity_verification::jws::EdDSAJwsVerifier;
 use identity_verification::jws::JwsVerifier;
+use identity_verification::MethodScope;
 
+use crate::credential::Credential;
+use crate::credential::CredentialJwtClaims;
 use crate::credential::Jwt;
 use crate::presentation::JwtPresentation;
 use crate::presentation::PresentationJwtClaims;
@@ -24,7 +32,12 @@ use crate::validator::vc_jwt_validation::CredentialValidator;
 use crate::validator::vc_jwt_validation::DecodedJwtCredential;
 use crate::validator::vc_jwt_validation::SignerContext;
 use crate::validator::vc_jwt_validation::ValidationError;
+use crate::validator::CredentialErrorPolicy;
+use crate::validator::CredentialSchemaResolver;
 use crate::validator::FailFast;
+use crate::validator::MultipleSubjectsPolicy;
+use crate::validator::StatusListResolver;
+use crate::validator::SubjectHolderRelationship;
 
 use super::CompoundJwtPresentationValidationError;
 use super::DecodedJwtPresentation;
@@ -60,7 +73,9 @@ where
   ///
   /// The following properties are validated according to `options`:
   /// - the JWT can be decoded into semantically valid presentation.
+  /// - the nonce in the protected header, if a challenge is set.
   /// - the expiration and issuance date contained in the JWT claims.
+  /// - the audience contained in the JWT claims, if an expected audience is set.
   /// - the holder's signature.
   /// - the relationship between the holder and the credential subjects.
   /// - the signatures and some properties of the constituent credentials (see [`CredentialValidator`]).
@@ -77,7 +92,10 @@ where
   ///
   /// ## Properties that are not validated
   ///  There are many properties defined in [The Verifiable Credentials Data Model](https://www.w3.org/TR/vc-data-model/) that are **not** validated, such as:
-  /// `credentialStatus`, `type`, `credentialSchema`, `refreshService`, **and more**.
+  /// `type`, `credentialSchema`, `refreshService`, **and more**. See `validate_with_schema_resolver` for validating
+  /// the `credentialSchema` of the constituent credentials with the `credential-schema` feature.
+  /// Of the `credentialStatus` types only `RevocationBitmap2022` is checked, see
+  /// [`Self::validate_with_status_resolver`] for `RevocationList2020Status` and `StatusList2021Entry` support.
   /// These should be manually checked after validation, according to your requirements.
   ///
   /// # Errors
@@ -96,15 +114,126 @@ where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     U: ToOwned<Owned = U> + serde::Serialize + serde::de::DeserializeOwned,
   {
+    self.validate_extended(presentation, holder, issuers, options, None, None, fail_fast)
+  }
+
+  /// Validates a [`JwtPresentation`] in the same way as [`Self::validate`], additionally using `status_resolver`
+  /// to fetch the status list of credentials with a
+  /// [`RevocationList2020Status`](crate::credential::RevocationList2020Status) or a
+  /// [`StatusList2021Entry`](crate::credential::StatusList2021Entry).
+  ///
+  /// A credential is considered revoked, or suspended, if its bit is set in the fetched status list, subject to
+  /// [`CredentialValidationOptions::status`](crate::validator::CredentialValidationOptions::status).
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied or when decoding fails.
+  pub fn validate_with_status_resolver<HDOC, IDOC, T, U>(
+    &self,
+    presentation: &Jwt,
+    holder: &HDOC,
+    issuers: &[IDOC],
+    options: &JwtPresentationValidationOptions,
+    status_resolver: &dyn StatusListResolver,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtPresentation<T, U>, CompoundJwtPresentationValidationError>
+  where
+    HDOC: AsRef<CoreDocument> + ?Sized,
+    IDOC: AsRef<CoreDocument>,
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    U: ToOwned<Owned = U> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    self.validate_extended(
+      presentation,
+      holder,
+      issuers,
+      options,
+      Some(status_resolver),
+      None,
+      fail_fast,
+    )
+  }
+
+  /// Validates a [`JwtPresentation`] in the same way as [`Self::validate`], additionally using `schema_resolver`
+  /// to fetch the JSON Schemas referenced in the `credentialSchema` of the constituent credentials when
+  /// [`CredentialValidationOptions::check_credential_schema`](crate::validator::CredentialValidationOptions::check_credential_schema)
+  /// is set.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied or when decoding fails.
+  #[cfg(feature = "credential-schema")]
+  pub fn validate_with_schema_resolver<HDOC, IDOC, T, U>(
+    &self,
+    presentation: &Jwt,
+    holder: &HDOC,
+    issuers: &[IDOC],
+    options: &JwtPresentationValidationOptions,
+    schema_resolver: &dyn CredentialSchemaResolver,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtPresentation<T, U>, CompoundJwtPresentationValidationError>
+  where
+    HDOC: AsRef<CoreDocument> + ?Sized,
+    IDOC: AsRef<CoreDocument>,
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    U: ToOwned<Owned = U> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    self.validate_extended(
+      presentation,
+      holder,
+      issuers,
+      options,
+      None,
+      Some(schema_resolver),
+      fail_fast,
+    )
+  }
+
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      level = "debug",
+      name = "validate_presentation",
+      skip_all,
+      fields(holder = holder.as_ref().id().as_str()),
+      err(Display)
+    )
+  )]
+  #[allow(clippy::too_many_arguments)]
+  fn validate_extended<HDOC, IDOC, T, U>(
+    &self,
+    presentation: &Jwt,
+    holder: &HDOC,
+    issuers: &[IDOC],
+    options: &JwtPresentationValidationOptions,
+    status_resolver: Option<&dyn StatusListResolver>,
+    schema_resolver: Option<&dyn CredentialSchemaResolver>,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtPresentation<T, U>, CompoundJwtPresentationValidationError>
+  where
+    HDOC: AsRef<CoreDocument> + ?Sized,
+    IDOC: AsRef<CoreDocument>,
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    U: ToOwned<Owned = U> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    // Bound the work spent on the presentation before verifying any signature.
+    if let Some(max_credentials) = options.max_credentials {
+      JwtPresentationValidator::check_credential_count(presentation, max_credentials)
+        .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+    }
+
+    // Check the challenge, the signature over it is verified along with the JWS.
+    let verifier_options: Cow<'_, JwsVerificationOptions> = match options.challenge.as_deref() {
+      Some(challenge) => {
+        JwtPresentationValidator::check_challenge(presentation, challenge)
+          .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+        Cow::Owned(options.presentation_verifier_options.clone().nonce(challenge))
+      }
+      None => Cow::Borrowed(&options.presentation_verifier_options),
+    };
+
     // Verify JWS.
     let decoded_jws: DecodedJws<'_> = holder
       .as_ref()
-      .verify_jws(
-        presentation.as_str(),
-        None,
-        &self.0,
-        &options.presentation_verifier_options,
-      )
+      .verify_jws(presentation.as_str(), None, &self.0, &verifier_options)
       .map_err(|err| {
         CompoundJwtPresentationValidationError::one_presentation_error(ValidationError::PresentationJwsError(err))
       })?;
@@ -142,11 +271,12 @@ where
       })
       .transpose()?;
 
-    (expiration_date.is_none() || expiration_date >= Some(options.earliest_expiry_date.unwrap_or_default()))
-      .then_some(())
-      .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
-        ValidationError::ExpirationDate,
-      ))?;
+    (expiration_date.is_none()
+      || expiration_date >= Some(options.earliest_expiry_date.or(options.now).unwrap_or_default()))
+    .then_some(())
+    .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
+      ValidationError::ExpirationDate,
+    ))?;
 
     // Check issuance date.
     let issuance_date: Option<Timestamp> = match claims.issuance_date {
@@ -164,21 +294,43 @@ where
       None => None,
     };
 
-    (issuance_date.is_none() || issuance_date <= Some(options.latest_issuance_date.unwrap_or_default()))
-      .then_some(())
-      .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
-        ValidationError::IssuanceDate,
-      ))?;
+    (issuance_date.is_none()
+      || issuance_date <= Some(options.latest_issuance_date.or(options.now).unwrap_or_default()))
+    .then_some(())
+    .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
+      ValidationError::IssuanceDate,
+    ))?;
 
-    let aud: Option<Url> = claims.aud.clone();
+    // Check the audience.
+    if let Some(expected_audience) = options.expected_audience.as_ref() {
+      claims
+        .aud
+        .as_ref()
+        .filter(|aud| aud.contains(expected_audience))
+        .ok_or(CompoundJwtPresentationValidationError::one_presentation_error(
+          ValidationError::Audience,
+        ))?;
+    }
+
+    let aud: Option<Url> = claims.aud.as_ref().and_then(|aud| aud.iter().next()).cloned();
 
     let presentation: JwtPresentation<T> = claims.try_into_presentation().map_err(|err| {
       CompoundJwtPresentationValidationError::one_presentation_error(ValidationError::PresentationStructure(err))
     })?;
 
+    JwtPresentationValidator::check_holder_binding(&presentation, holder.as_ref())
+      .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+
     // Validate credentials.
     let credentials: Vec<DecodedJwtCredential<U>> = self
-      .validate_credentials::<IDOC, T, U>(&presentation, issuers, options, fail_fast)
+      .validate_credentials::<IDOC, T, U>(
+        &presentation,
+        issuers,
+        options,
+        status_resolver,
+        schema_resolver,
+        fail_fast,
+      )
       .map_err(|err| CompoundJwtPresentationValidationError {
         credential_errors: err,
         presentation_validation_errors: vec![],
@@ -196,11 +348,95 @@ where
     Ok(decoded_jwt_presentation)
   }
 
+  /// Decodes a [`JwtPresentation`] issued as a JWT and verifies the holder's JWS signature using the `holder`
+  /// DID Document.
+  ///
+  /// Unlike [`Self::validate`] no other properties of the presentation are validated and the included
+  /// credentials are neither decoded nor validated.
+  ///
+  /// # Errors
+  /// An error is returned if the JWS cannot be verified, the claims cannot be decoded into a semantically valid
+  /// presentation or the holder of the presentation does not match the `holder` DID Document.
+  pub fn verify_signature<HDOC, T>(
+    &self,
+    presentation: &Jwt,
+    holder: &HDOC,
+    options: &JwsVerificationOptions,
+  ) -> Result<JwtPresentation<T>, ValidationError>
+  where
+    HDOC: AsRef<CoreDocument> + ?Sized,
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let decoded_jws: DecodedJws<'_> = holder
+      .as_ref()
+      .verify_jws(presentation.as_str(), None, &self.0, options)
+      .map_err(ValidationError::PresentationJwsError)?;
+
+    Self::presentation_of_holder(&decoded_jws.claims, holder.as_ref())
+  }
+
+  /// Decodes a [`JwtPresentation`] issued as a JWT and verifies the holder's JWS signature against every
+  /// authentication method of the `holder` DID Document, returning the presentation together with the id of the
+  /// method that verified the signature.
+  ///
+  /// Unlike [`Self::verify_signature`] the signature is not only checked against the method referenced by the `kid`
+  /// of the JWS, which accommodates holders with several authentication methods. The
+  /// [`method_scope`](JwsVerificationOptions::method_scope) of `options` is ignored, all other options apply as
+  /// usual. As with [`Self::verify_signature`] no other properties of the presentation are validated.
+  ///
+  /// # Errors
+  /// An error is returned if none of the authentication methods verifies the JWS, the claims cannot be decoded into a
+  /// semantically valid presentation or the holder of the presentation does not match the `holder` DID Document.
+  pub fn verify_presentation_signature<HDOC, T>(
+    &self,
+    presentation: &Jwt,
+    holder: &HDOC,
+    options: &JwsVerificationOptions,
+  ) -> Result<(JwtPresentation<T>, DIDUrl), ValidationError>
+  where
+    HDOC: AsRef<CoreDocument> + ?Sized,
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let options: JwsVerificationOptions = options.clone().method_scope(MethodScope::authentication());
+    let verified: VerifiedJws<'_, '_> =
+      verify_jws_with_any_method(presentation.as_str(), None, holder.as_ref(), &self.0, &options)
+        .map_err(ValidationError::PresentationJwsError)?;
+
+    let presentation: JwtPresentation<T> = Self::presentation_of_holder(&verified.decoded.claims, holder.as_ref())?;
+    Ok((presentation, verified.method.id().clone()))
+  }
+
+  /// Decodes the verified `claims` of a presentation and checks that they were issued by `holder`.
+  fn presentation_of_holder<T>(claims: &[u8], holder: &CoreDocument) -> Result<JwtPresentation<T>, ValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let claims: PresentationJwtClaims<'_, T> = PresentationJwtClaims::from_json_slice(claims).map_err(|err| {
+      ValidationError::PresentationStructure(crate::Error::JwtClaimsSetDeserializationError(err.into()))
+    })?;
+
+    let holder_did: CoreDID = CoreDID::from_str(claims.iss.as_str()).map_err(|err| ValidationError::SignerUrl {
+      signer_ctx: SignerContext::Holder,
+      source: err.into(),
+    })?;
+    if &holder_did != holder.id() {
+      return Err(ValidationError::DocumentMismatch(SignerContext::Holder));
+    }
+
+    let presentation: JwtPresentation<T> = claims
+      .try_into_presentation()
+      .map_err(ValidationError::PresentationStructure)?;
+    JwtPresentationValidator::check_holder_binding(&presentation, holder)?;
+    Ok(presentation)
+  }
+
   fn validate_credentials<DOC, T, U>(
     &self,
     presentation: &JwtPresentation<T>,
     issuers: &[DOC],
     options: &JwtPresentationValidationOptions,
+    status_resolver: Option<&dyn StatusListResolver>,
+    schema_resolver: Option<&dyn CredentialSchemaResolver>,
     fail_fast: FailFast,
   ) -> Result<Vec<DecodedJwtCredential<U>>, BTreeMap<usize, CompoundCredentialValidationError>>
   where
@@ -208,6 +444,13 @@ where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     U: ToOwned<Owned = U> + serde::Serialize + serde::de::DeserializeOwned,
   {
+    // Whether each credential fails fast and whether all credentials are validated.
+    let (credential_fail_fast, validate_all): (FailFast, bool) = match options.credential_error_policy {
+      CredentialErrorPolicy::FollowFailFast => (fail_fast, matches!(fail_fast, FailFast::AllErrors)),
+      CredentialErrorPolicy::AccumulateFirstErrors => (FailFast::FirstError, true),
+      CredentialErrorPolicy::AccumulateAllErrors => (FailFast::AllErrors, true),
+    };
+
     let number_of_credentials = presentation.verifiable_credential.len();
     let mut decoded_credentials: Vec<DecodedJwtCredential<U>> = vec![];
     let credential_errors_iter = presentation
@@ -219,8 +462,14 @@ where
           credential,
           issuers,
           &options.shared_validation_options,
-          Some((&presentation.holder, options.subject_holder_relationship)),
-          fail_fast,
+          Some((
+            &presentation.holder,
+            options.subject_holder_relationship,
+            options.multiple_subjects_policy,
+          )),
+          status_resolver,
+          schema_resolver,
+          credential_fail_fast,
         )
       })
       .enumerate()
@@ -234,10 +483,7 @@ where
       });
 
     let credential_errors: BTreeMap<usize, CompoundCredentialValidationError> = credential_errors_iter
-      .take(match fail_fast {
-        FailFast::FirstError => 1,
-        FailFast::AllErrors => number_of_credentials,
-      })
+      .take(if validate_all { number_of_credentials } else { 1 })
       .collect();
 
     if credential_errors.is_empty() {
@@ -272,10 +518,7 @@ impl JwtPresentationValidator {
       ValidationError::PresentationStructure(crate::Error::JwtClaimsSetDeserializationError(err.into()))
     })?;
 
-    let holder: H = H::from_str(claims.iss.as_str()).map_err(|err| ValidationError::SignerUrl {
-      signer_ctx: SignerContext::Holder,
-      source: err.into(),
-    })?;
+    let holder: H = Self::holder_from_claims(&claims)?;
 
     let mut issuers: Vec<I> = vec![];
     for vc in claims.vp.verifiable_credential.iter() {
@@ -284,6 +527,130 @@ impl JwtPresentationValidator {
     Ok((holder, issuers))
   }
 
+  /// Attempt to extract the holder of the presentation.
+  ///
+  /// The signature of the presentation is **not** verified.
+  ///
+  /// # Errors:
+  /// * If deserialization/decoding of the presentation fails.
+  /// * If the holder can't be parsed as a DID of type `H`.
+  pub fn extract_holder<H: DID>(presentation: &Jwt) -> std::result::Result<H, ValidationError>
+  where
+    <H as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+  {
+    let validation_item = Decoder::new()
+      .decode_compact_serialization(presentation.as_str().as_bytes(), None)
+      .map_err(ValidationError::JwsDecodingError)?;
+
+    let claims: PresentationJwtClaims<'_, Object> = PresentationJwtClaims::from_json_slice(&validation_item.claims())
+      .map_err(|err| {
+      ValidationError::PresentationStructure(crate::Error::JwtClaimsSetDeserializationError(err.into()))
+    })?;
+
+    Self::holder_from_claims(&claims)
+  }
+
+  fn holder_from_claims<H: DID>(claims: &PresentationJwtClaims<'_, Object>) -> std::result::Result<H, ValidationError>
+  where
+    <H as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+  {
+    H::from_str(claims.iss.as_str()).map_err(|err| ValidationError::SignerUrl {
+      signer_ctx: SignerContext::Holder,
+      source: err.into(),
+    })
+  }
+
+  /// Checks that the `nonce` in the protected header of the `presentation` matches the `challenge` issued by the
+  /// verifier.
+  ///
+  /// The signature of the presentation is **not** verified.
+  ///
+  /// # Errors
+  /// Returns [`ValidationError::Challenge`] if the nonce is missing or does not match the `challenge`.
+  pub fn check_challenge(presentation: &Jwt, challenge: &str) -> Result<(), ValidationError> {
+    let validation_item = Decoder::new()
+      .decode_compact_serialization(presentation.as_str().as_bytes(), None)
+      .map_err(ValidationError::JwsDecodingError)?;
+
+    (validation_item.nonce() == Some(challenge))
+      .then_some(())
+      .ok_or(ValidationError::Challenge)
+  }
+
+  /// Checks that the `presentation` contains at most `max_credentials` credentials.
+  ///
+  /// The signature of the presentation is **not** verified.
+  ///
+  /// # Errors
+  /// Returns [`ValidationError::TooManyCredentials`] if the presentation contains more credentials, or an error if
+  /// decoding the presentation fails.
+  pub fn check_credential_count(presentation: &Jwt, max_credentials: usize) -> Result<(), ValidationError> {
+    let validation_item = Decoder::new()
+      .decode_compact_serialization(presentation.as_str().as_bytes(), None)
+      .map_err(ValidationError::JwsDecodingError)?;
+
+    let claims: PresentationJwtClaims<'_, Object> = PresentationJwtClaims::from_json_slice(&validation_item.claims())
+      .map_err(|err| {
+      ValidationError::PresentationStructure(crate::Error::JwtClaimsSetDeserializationError(err.into()))
+    })?;
+
+    (claims.vp.verifiable_credential.len() <= max_credentials)
+      .then_some(())
+      .ok_or(ValidationError::TooManyCredentials(max_credentials))
+  }
+
+  /// Checks that the holder of the `presentation` is a subject of every credential it contains, regardless of the
+  /// credentials' `nonTransferable` property. For a credential with several subjects the holder must be among them.
+  ///
+  /// This can be used independently of [`Self::validate`], the signatures of the credentials are **not** verified.
+  ///
+  /// # Errors
+  /// Returns [`ValidationError::SubjectHolderRelationship`] for the first credential the holder is not a subject of,
+  /// or an error if decoding a credential fails.
+  pub fn check_all_subjects_are_holder<T>(presentation: &JwtPresentation<T>) -> Result<(), ValidationError> {
+    for credential_jwt in presentation.verifiable_credential.iter() {
+      let validation_item = Decoder::new()
+        .decode_compact_serialization(credential_jwt.as_str().as_bytes(), None)
+        .map_err(ValidationError::JwsDecodingError)?;
+      let credential: Credential = CredentialJwtClaims::<'_, Object>::from_json_slice(&validation_item.claims())
+        .map_err(|err| {
+          ValidationError::CredentialStructure(crate::Error::JwtClaimsSetDeserializationError(err.into()))
+        })?
+        .try_into_credential()
+        .map_err(ValidationError::CredentialStructure)?;
+
+      CredentialValidator::check_subject_holder_relationship_with_policy(
+        &credential,
+        &presentation.holder,
+        SubjectHolderRelationship::AlwaysSubject,
+        MultipleSubjectsPolicy::Any,
+      )?;
+    }
+    Ok(())
+  }
+
+  /// Checks that the [`holder_binding`](JwtPresentation::holder_binding) of the `presentation`, if present, references
+  /// a verification method of the `holder` DID Document.
+  ///
+  /// This is part of [`Self::validate`] and [`Self::verify_signature`].
+  ///
+  /// # Errors
+  /// Returns [`ValidationError::PresentationStructure`] if the `holder` has no method identified by the `kid` of the
+  /// holder binding.
+  pub fn check_holder_binding<T>(
+    presentation: &JwtPresentation<T>,
+    holder: &CoreDocument,
+  ) -> Result<(), ValidationError> {
+    match presentation.holder_binding.as_ref() {
+      Some(holder_binding) if holder.resolve_method(holder_binding.kid.as_str(), None).is_none() => {
+        Err(ValidationError::PresentationStructure(
+          crate::Error::InvalidHolderBinding("the `kid` does not reference a verification method of the holder"),
+        ))
+      }
+      _ => Ok(()),
+    }
+  }
+
   /// Validates the semantic structure of the `JwtPresentation`.
   pub fn check_structure<U>(presentation: &JwtPresentation<U>) -> Result<(), ValidationError> {
     presentation
@@ -0,0 +1,21 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Feeds arbitrary UTF-8 input into [`DemiaDID::parse`], which must never panic.
+//!
+//! Run with `cargo +nightly fuzz run parse` from the `identity_demia_core` directory.
+
+#![no_main]
+
+use identity_demia_core::DemiaDID;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+  if let Ok(did) = DemiaDID::parse(input) {
+    // Accessors slice the method id and must not panic on anything `parse` accepts.
+    let _ = (did.country_str(), did.network_str(), did.tag());
+    // The normalized representation must be stable.
+    assert_eq!(DemiaDID::parse(did.as_str()).ok(), Some(did));
+  }
+  let _ = DemiaDID::parse_strict(input);
+});
@@ -0,0 +1,348 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use crate::block::output::AliasId;
+use crate::block::output::AliasOutput;
+use crate::block::output::OutputId;
+use crate::block::protocol::ProtocolParameters;
+use crate::IotaIdentityClient;
+use crate::NetworkName;
+use crate::Result;
+
+/// An [`IotaIdentityClient`] that wraps another client and keeps the most recently resolved Alias Outputs
+/// in a least-recently-used cache keyed by [`AliasId`].
+///
+/// Resolving the same DID repeatedly through the [`IotaIdentityClientExt`](crate::IotaIdentityClientExt) functions
+/// is answered from the cache instead of the node, as long as the entry has not been evicted.
+///
+/// A cached entry is replaced whenever an Alias Output with a higher state index is observed for the same
+/// [`AliasId`], see [`CachedIdentityClient::observe_alias_output`]. Updates published by other parties are not
+/// observed automatically, use [`CachedIdentityClient::invalidate`] to force the next resolution to hit the node.
+#[derive(Debug)]
+pub struct CachedIdentityClient<C> {
+  client: C,
+  cache: Mutex<AliasOutputCache>,
+}
+
+impl<C> CachedIdentityClient<C> {
+  /// Wraps `client` in a cache holding at most `capacity` Alias Outputs.
+  pub fn new(client: C, capacity: NonZeroUsize) -> Self {
+    Self {
+      client,
+      cache: Mutex::new(AliasOutputCache::new(capacity)),
+    }
+  }
+
+  /// Returns a reference to the wrapped client.
+  pub fn client(&self) -> &C {
+    &self.client
+  }
+
+  /// Returns the wrapped client, discarding the cache.
+  pub fn into_inner(self) -> C {
+    self.client
+  }
+
+  /// Returns the maximum number of Alias Outputs held by the cache.
+  pub fn capacity(&self) -> NonZeroUsize {
+    self.cache().capacity
+  }
+
+  /// Returns the number of Alias Outputs currently held by the cache.
+  pub fn len(&self) -> usize {
+    self.cache().entries.len()
+  }
+
+  /// Returns whether the cache is empty.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Notifies the cache of an Alias Output identified by `output_id`, e.g. after publishing it.
+  ///
+  /// The output replaces the cached entry for its [`AliasId`] if it has a higher state index. Outputs of Alias
+  /// identifiers that are not currently cached are ignored.
+  pub fn observe_alias_output(&self, output_id: OutputId, alias_output: AliasOutput) {
+    let alias_id: AliasId = alias_output.alias_id_non_null(&output_id);
+    self.cache().observe(alias_id, output_id, alias_output);
+  }
+
+  /// Removes the Alias Output of `alias_id` from the cache, if present.
+  pub fn invalidate(&self, alias_id: &AliasId) {
+    self.cache().remove(alias_id);
+  }
+
+  /// Removes all Alias Outputs from the cache.
+  pub fn clear(&self) {
+    let mut cache = self.cache();
+    cache.entries.clear();
+    cache.order.clear();
+  }
+
+  fn cache(&self) -> MutexGuard<'_, AliasOutputCache> {
+    // The cache is left in a consistent state even if a thread panicked while holding the lock.
+    self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+impl<C> CachedIdentityClient<C>
+where
+  C: IotaIdentityClient,
+{
+  async fn get_alias_output_cached(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+    // Bind the lookup first so the lock is released before awaiting the node.
+    let cached: Option<(OutputId, AliasOutput)> = self.cache().get(&alias_id);
+    if let Some(entry) = cached {
+      return Ok(entry);
+    }
+
+    let (output_id, alias_output) = self.client.get_alias_output(alias_id).await?;
+    self.cache().insert(alias_id, output_id, alias_output.clone());
+    Ok((output_id, alias_output))
+  }
+}
+
+#[cfg(feature = "send-sync-client-ext")]
+#[async_trait::async_trait]
+impl<C> IotaIdentityClient for CachedIdentityClient<C>
+where
+  C: IotaIdentityClient + Send + Sync,
+{
+  async fn get_alias_output(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+    self.get_alias_output_cached(alias_id).await
+  }
+
+  async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+    self.client.get_protocol_parameters().await
+  }
+
+  async fn available_networks(&self) -> Result<Vec<NetworkName>> {
+    self.client.available_networks().await
+  }
+
+  async fn get_previous_alias_output(&self, alias_id: AliasId, output_id: OutputId) -> Result<(OutputId, AliasOutput)> {
+    self.client.get_previous_alias_output(alias_id, output_id).await
+  }
+}
+
+#[cfg(not(feature = "send-sync-client-ext"))]
+#[async_trait::async_trait(?Send)]
+impl<C> IotaIdentityClient for CachedIdentityClient<C>
+where
+  C: IotaIdentityClient,
+{
+  async fn get_alias_output(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+    self.get_alias_output_cached(alias_id).await
+  }
+
+  async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+    self.client.get_protocol_parameters().await
+  }
+
+  async fn available_networks(&self) -> Result<Vec<NetworkName>> {
+    self.client.available_networks().await
+  }
+
+  async fn get_previous_alias_output(&self, alias_id: AliasId, output_id: OutputId) -> Result<(OutputId, AliasOutput)> {
+    self.client.get_previous_alias_output(alias_id, output_id).await
+  }
+}
+
+/// A least-recently-used cache of Alias Outputs.
+#[derive(Debug)]
+struct AliasOutputCache {
+  capacity: NonZeroUsize,
+  entries: HashMap<AliasId, (OutputId, AliasOutput)>,
+  /// Cached identifiers ordered from least to most recently used.
+  order: VecDeque<AliasId>,
+}
+
+impl AliasOutputCache {
+  fn new(capacity: NonZeroUsize) -> Self {
+    Self {
+      capacity,
+      entries: HashMap::new(),
+      order: VecDeque::new(),
+    }
+  }
+
+  fn get(&mut self, alias_id: &AliasId) -> Option<(OutputId, AliasOutput)> {
+    let entry: (OutputId, AliasOutput) = self.entries.get(alias_id).cloned()?;
+    self.touch(alias_id);
+    Some(entry)
+  }
+
+  fn insert(&mut self, alias_id: AliasId, output_id: OutputId, alias_output: AliasOutput) {
+    if self.entries.insert(alias_id, (output_id, alias_output)).is_some() {
+      self.touch(&alias_id);
+      return;
+    }
+
+    self.order.push_back(alias_id);
+    if self.order.len() > self.capacity.get() {
+      if let Some(evicted) = self.order.pop_front() {
+        self.entries.remove(&evicted);
+      }
+    }
+  }
+
+  fn observe(&mut self, alias_id: AliasId, output_id: OutputId, alias_output: AliasOutput) {
+    let is_newer: bool = self
+      .entries
+      .get(&alias_id)
+      .map(|(_, cached)| alias_output.state_index() > cached.state_index())
+      .unwrap_or(false);
+    if is_newer {
+      self.insert(alias_id, output_id, alias_output);
+    }
+  }
+
+  fn remove(&mut self, alias_id: &AliasId) {
+    if self.entries.remove(alias_id).is_some() {
+      self.order.retain(|id| id != alias_id);
+    }
+  }
+
+  /// Marks `alias_id` as the most recently used entry.
+  fn touch(&mut self, alias_id: &AliasId) {
+    if let Some(position) = self.order.iter().position(|id| id == alias_id) {
+      if let Some(id) = self.order.remove(position) {
+        self.order.push_back(id);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+
+  use isocountry::CountryCode;
+
+  use super::*;
+  use crate::block::address::Address;
+  use crate::block::address::Ed25519Address;
+  use crate::block::output::unlock_condition::GovernorAddressUnlockCondition;
+  use crate::block::output::unlock_condition::StateControllerAddressUnlockCondition;
+  use crate::block::output::AliasOutputBuilder;
+  use crate::block::output::UnlockCondition;
+  use crate::block::payload::transaction::TransactionId;
+  use crate::DemiaDID;
+  use crate::IotaIdentityClientExt;
+  use crate::NetworkName;
+
+  /// A node that answers every Alias Output request with an output at `state_index`.
+  struct MockNode {
+    state_index: u32,
+    alias_output_requests: AtomicUsize,
+  }
+
+  impl MockNode {
+    fn new(state_index: u32) -> Self {
+      Self {
+        state_index,
+        alias_output_requests: AtomicUsize::new(0),
+      }
+    }
+
+    fn requests(&self) -> usize {
+      self.alias_output_requests.load(Ordering::SeqCst)
+    }
+  }
+
+  #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+  #[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+  impl IotaIdentityClient for MockNode {
+    async fn get_alias_output(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+      self.alias_output_requests.fetch_add(1, Ordering::SeqCst);
+      Ok(alias_output(alias_id, self.state_index))
+    }
+
+    async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+      Ok(ProtocolParameters::default())
+    }
+  }
+
+  fn alias_output(alias_id: AliasId, state_index: u32) -> (OutputId, AliasOutput) {
+    let address: Address = Address::Ed25519(Ed25519Address::new([1; 32]));
+    let alias_output: AliasOutput = AliasOutputBuilder::new_with_amount(1, alias_id)
+      .with_state_index(state_index)
+      .add_unlock_condition(UnlockCondition::StateControllerAddress(
+        StateControllerAddressUnlockCondition::new(address),
+      ))
+      .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+        address,
+      )))
+      .finish(ProtocolParameters::default().token_supply())
+      .unwrap();
+    let output_id: OutputId = OutputId::new(TransactionId::new([state_index as u8; 32]), 0).unwrap();
+    (output_id, alias_output)
+  }
+
+  fn did(byte: u8) -> DemiaDID {
+    let network: NetworkName = NetworkName::try_from(ProtocolParameters::default().bech32_hrp().to_owned()).unwrap();
+    DemiaDID::new(&[byte; 32], &CountryCode::USA, &network)
+  }
+
+  #[tokio::test]
+  async fn resolution_within_capacity_is_cached() {
+    let client = CachedIdentityClient::new(MockNode::new(0), NonZeroUsize::new(2).unwrap());
+    let did: DemiaDID = did(1);
+
+    let first = client.resolve_did(&did).await.unwrap();
+    let second = client.resolve_did(&did).await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(client.client().requests(), 1);
+    assert_eq!(client.len(), 1);
+
+    // Invalidating the entry forces the next resolution to hit the node.
+    client.invalidate(&AliasId::from(&did));
+    client.resolve_did(&did).await.unwrap();
+    assert_eq!(client.client().requests(), 2);
+  }
+
+  #[tokio::test]
+  async fn least_recently_used_entry_is_evicted() {
+    let client = CachedIdentityClient::new(MockNode::new(0), NonZeroUsize::new(2).unwrap());
+    let (did1, did2, did3) = (did(1), did(2), did(3));
+
+    client.resolve_did(&did1).await.unwrap();
+    client.resolve_did(&did2).await.unwrap();
+    // Mark did1 as the most recently used entry, so resolving did3 evicts did2.
+    client.resolve_did(&did1).await.unwrap();
+    client.resolve_did(&did3).await.unwrap();
+    assert_eq!(client.client().requests(), 3);
+    assert_eq!(client.len(), 2);
+
+    client.resolve_did(&did1).await.unwrap();
+    assert_eq!(client.client().requests(), 3);
+    client.resolve_did(&did2).await.unwrap();
+    assert_eq!(client.client().requests(), 4);
+  }
+
+  #[tokio::test]
+  async fn newer_state_index_replaces_entry() {
+    let client = CachedIdentityClient::new(MockNode::new(1), NonZeroUsize::new(1).unwrap());
+    let did: DemiaDID = did(1);
+    let alias_id: AliasId = AliasId::from(&did);
+    assert_eq!(client.resolve_did_output(&did).await.unwrap().state_index(), 1);
+
+    // An older output is ignored.
+    let (output_id, output) = alias_output(alias_id, 0);
+    client.observe_alias_output(output_id, output);
+    assert_eq!(client.resolve_did_output(&did).await.unwrap().state_index(), 1);
+
+    // A newer output replaces the cached one.
+    let (output_id, output) = alias_output(alias_id, 2);
+    client.observe_alias_output(output_id, output);
+    assert_eq!(client.resolve_did_output(&did).await.unwrap().state_index(), 2);
+    assert_eq!(client.client().requests(), 1);
+  }
+}
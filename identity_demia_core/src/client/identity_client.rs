@@ -28,6 +28,32 @@ pub trait IotaIdentityClient {
   async fn get_alias_output(&self, alias_id: AliasId) -> Result<(OutputId, AliasOutput)>;
   /// Get the protocol parameters of the node we are trying to connect to.
   async fn get_protocol_parameters(&self) -> Result<ProtocolParameters>;
+  /// Returns the networks for which this client can construct and resolve DIDs.
+  ///
+  /// Defaults to the single network named by the Bech32 human-readable part (HRP) of the protocol parameters.
+  /// Clients serving several networks should override this.
+  async fn available_networks(&self) -> Result<Vec<NetworkName>> {
+    self
+      .get_protocol_parameters()
+      .await
+      .map(|parameters| parameters.bech32_hrp().to_owned())
+      .and_then(NetworkName::try_from)
+      .map(|network| vec![network])
+  }
+  /// Returns the previous state of the Alias Output `alias_id`, i.e. the Alias Output consumed by the transaction
+  /// that created the output with `output_id`.
+  ///
+  /// Defaults to an [`Error::AliasOutputHistoryError`] for clients without access to the output history.
+  /// NOTE: a permanode may be required to fetch older outputs.
+  async fn get_previous_alias_output(
+    &self,
+    _alias_id: AliasId,
+    _output_id: OutputId,
+  ) -> Result<(OutputId, AliasOutput)> {
+    Err(Error::AliasOutputHistoryError(
+      "the client does not provide access to the output history",
+    ))
+  }
 }
 
 /// An extension trait that provides helper functions for publication
@@ -149,6 +175,39 @@ pub trait IotaIdentityClientExt: IotaIdentityClient {
     IotaDocument::unpack_from_output(did, &alias_output, true)
   }
 
+  /// Resolve the [`IotaDocument`] as it was published at the given `state_index` of its Alias Output,
+  /// by walking back the output history starting from the latest output.
+  ///
+  /// # Errors
+  ///
+  /// - [`NetworkMismatch`](Error::NetworkMismatch) if the network of the DID and client differ.
+  /// - [`StateIndexOutOfRange`](Error::StateIndexOutOfRange) if `state_index` is beyond the current state index.
+  /// - [`AliasOutputHistoryError`](Error::AliasOutputHistoryError) if a previous state could not be fetched.
+  async fn resolve_did_at(&self, did: &DemiaDID, state_index: u32) -> Result<IotaDocument> {
+    validate_network(self, did).await?;
+
+    let id: AliasId = AliasId::from(did);
+    let (mut output_id, mut alias_output) = self.get_alias_output(id).await?;
+    if state_index > alias_output.state_index() {
+      return Err(Error::StateIndexOutOfRange {
+        requested: state_index,
+        current: alias_output.state_index(),
+      });
+    }
+
+    while alias_output.state_index() > state_index {
+      let (previous_id, previous_output) = self.get_previous_alias_output(id, output_id).await?;
+      if previous_output.state_index() > alias_output.state_index() {
+        return Err(Error::AliasOutputHistoryError(
+          "state index of the previous output is higher",
+        ));
+      }
+      (output_id, alias_output) = (previous_id, previous_output);
+    }
+
+    IotaDocument::unpack_from_output(did, &alias_output, true)
+  }
+
   /// Fetches the [`AliasOutput`] associated with the given DID.
   ///
   /// # Errors
@@ -203,15 +262,143 @@ pub(super) async fn validate_network<T>(client: &T, did: &DemiaDID) -> Result<()
 where
   T: IotaIdentityClient + ?Sized,
 {
-  let network_hrp: String = client
+  let network: NetworkName = client
     .get_protocol_parameters()
     .await
-    .map(|parameters| parameters.bech32_hrp().to_owned())?;
-  if did.network_str() != network_hrp.as_str() {
-    return Err(Error::NetworkMismatch {
-      expected: did.network_str().to_owned(),
-      actual: network_hrp,
-    });
-  };
-  Ok(())
+    .map(|parameters| parameters.bech32_hrp().to_owned())
+    .and_then(NetworkName::try_from)?;
+  did.assert_network_matches(&network)
+}
+
+#[cfg(test)]
+mod tests {
+  use isocountry::CountryCode;
+
+  use super::*;
+  use crate::block::address::Ed25519Address;
+  use crate::block::payload::transaction::TransactionId;
+  use crate::StateIndex;
+
+  /// A client configured for a fixed set of networks.
+  struct MockClient {
+    networks: Vec<NetworkName>,
+  }
+
+  #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+  #[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+  impl IotaIdentityClient for MockClient {
+    async fn get_alias_output(&self, _alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+      // The mock only serves network information and does not hold any outputs.
+      let output_id: OutputId = OutputId::new(TransactionId::new([0; 32]), 0).unwrap();
+      Err(Error::NotAnAliasOutput(output_id))
+    }
+
+    async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+      Ok(ProtocolParameters::default())
+    }
+
+    async fn available_networks(&self) -> Result<Vec<NetworkName>> {
+      Ok(self.networks.clone())
+    }
+  }
+
+  #[tokio::test]
+  async fn available_networks() {
+    let networks: Vec<NetworkName> = vec![
+      NetworkName::try_from("dmia").unwrap(),
+      NetworkName::try_from("dev").unwrap(),
+    ];
+    let client: MockClient = MockClient {
+      networks: networks.clone(),
+    };
+    assert_eq!(client.available_networks().await.unwrap(), networks);
+
+    // By default the network of the protocol parameters is served, `MockLedger` does not override it.
+    let ledger: MockLedger = MockLedger { history: Vec::new() };
+    let expected: NetworkName = NetworkName::try_from(ProtocolParameters::default().bech32_hrp().to_owned()).unwrap();
+    assert_eq!(ledger.available_networks().await.unwrap(), vec![expected]);
+  }
+
+  /// A ledger holding the full history of a single Alias Output, oldest state first.
+  struct MockLedger {
+    history: Vec<(OutputId, AliasOutput)>,
+  }
+
+  impl MockLedger {
+    fn new(did: &DemiaDID, documents: &[IotaDocument]) -> Self {
+      let address: Address = Address::Ed25519(Ed25519Address::new([1; 32]));
+      let history = documents
+        .iter()
+        .enumerate()
+        .map(|(state_index, document)| {
+          let alias_output: AliasOutput = AliasOutputBuilder::new_with_amount(1, AliasId::from(did))
+            .with_state_index(state_index as u32)
+            .with_state_metadata(document.clone().pack().unwrap())
+            .add_unlock_condition(UnlockCondition::StateControllerAddress(
+              StateControllerAddressUnlockCondition::new(address),
+            ))
+            .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+              address,
+            )))
+            .finish(ProtocolParameters::default().token_supply())
+            .unwrap();
+          let output_id: OutputId = OutputId::new(TransactionId::new([state_index as u8; 32]), 0).unwrap();
+          (output_id, alias_output)
+        })
+        .collect();
+      Self { history }
+    }
+  }
+
+  #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+  #[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+  impl IotaIdentityClient for MockLedger {
+    async fn get_alias_output(&self, _alias_id: AliasId) -> Result<(OutputId, AliasOutput)> {
+      Ok(self.history.last().cloned().unwrap())
+    }
+
+    async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
+      Ok(ProtocolParameters::default())
+    }
+
+    async fn get_previous_alias_output(
+      &self,
+      _alias_id: AliasId,
+      output_id: OutputId,
+    ) -> Result<(OutputId, AliasOutput)> {
+      let position: usize = self.history.iter().position(|(id, _)| *id == output_id).unwrap();
+      position
+        .checked_sub(1)
+        .map(|previous| self.history[previous].clone())
+        .ok_or(Error::AliasOutputHistoryError("no previous output"))
+    }
+  }
+
+  #[tokio::test]
+  async fn resolve_did_at() {
+    let network: NetworkName = NetworkName::try_from(ProtocolParameters::default().bech32_hrp().to_owned()).unwrap();
+    let did: DemiaDID = DemiaDID::new(&[3; 32], &CountryCode::USA, &network);
+    let first: IotaDocument = IotaDocument::new_with_id(did.clone());
+    let mut second: IotaDocument = first.clone();
+    second
+      .also_known_as_mut()
+      .append("https://example.com/".parse().unwrap());
+    let ledger: MockLedger = MockLedger::new(&did, &[first.clone(), second.clone()]);
+
+    let resolved: IotaDocument = ledger.resolve_did_at(&did, 0).await.unwrap();
+    assert_eq!(resolved.core_document(), first.core_document());
+    assert_eq!(resolved.metadata.state_index, Some(StateIndex::new(0)));
+
+    let resolved: IotaDocument = ledger.resolve_did_at(&did, 1).await.unwrap();
+    assert_eq!(resolved.core_document(), second.core_document());
+    assert_eq!(resolved, ledger.resolve_did(&did).await.unwrap());
+
+    assert!(matches!(
+      ledger.resolve_did_at(&did, 2).await.unwrap_err(),
+      Error::StateIndexOutOfRange {
+        requested: 2,
+        current: 1
+      }
+    ));
+  }
 }
@@ -11,6 +11,7 @@ use iota_sdk::client::Client;
 use iota_sdk::types::block::protocol::ProtocolParameters;
 
 use crate::block::address::Address;
+use crate::block::input::Input;
 use crate::block::output::unlock_condition::AddressUnlockCondition;
 use crate::block::output::AliasId;
 use crate::block::output::AliasOutput;
@@ -18,6 +19,8 @@ use crate::block::output::BasicOutputBuilder;
 use crate::block::output::Output;
 use crate::block::output::OutputId;
 use crate::block::output::UnlockCondition;
+use crate::block::payload::transaction::TransactionEssence;
+use crate::block::payload::Payload;
 use crate::block::Block;
 use crate::client::identity_client::validate_network;
 use crate::error::Result;
@@ -142,6 +145,37 @@ impl IotaIdentityClient for Client {
       Err(Error::NotAnAliasOutput(output_id))
     }
   }
+
+  async fn get_previous_alias_output(&self, alias_id: AliasId, output_id: OutputId) -> Result<(OutputId, AliasOutput)> {
+    let block: Block = self
+      .get_included_block(output_id.transaction_id())
+      .await
+      .map_err(Error::DIDResolutionError)?;
+    let Some(Payload::Transaction(transaction)) = block.payload() else {
+      return Err(Error::AliasOutputHistoryError("expected a transaction payload"));
+    };
+    let TransactionEssence::Regular(essence) = transaction.essence();
+
+    for input in essence.inputs() {
+      if let Input::Utxo(input) = input {
+        let previous_id: OutputId = *input.output_id();
+        let output: Output = self
+          .get_output(&previous_id)
+          .await
+          .map_err(Error::DIDResolutionError)?
+          .into_output();
+        if let Output::Alias(previous_output) = output {
+          if previous_output.alias_id_non_null(&previous_id) == alias_id {
+            return Ok((previous_id, previous_output));
+          }
+        }
+      }
+    }
+
+    Err(Error::AliasOutputHistoryError(
+      "no previous alias output found in the transaction inputs",
+    ))
+  }
 }
 
 /// Publishes an `alias_output`.
@@ -0,0 +1,6 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+pub use presentation_validator_ext::DemiaPresentationValidatorExt;
+
+mod presentation_validator_ext;
@@ -0,0 +1,167 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use identity_credential::credential::Credential;
+use identity_credential::credential::Jwt;
+use identity_credential::validator::DecodedJwtPresentation;
+use identity_credential::validator::JwtPresentationValidator;
+use identity_credential::validator::ValidationError;
+
+use crate::DemiaDID;
+use crate::Error;
+use crate::Result;
+
+/// Demia specific extensions to the [`JwtPresentationValidator`].
+pub trait DemiaPresentationValidatorExt {
+  /// Attempt to extract the holder of the presentation as a [`DemiaDID`].
+  ///
+  /// The signature of the presentation is **not** verified.
+  ///
+  /// # Errors:
+  /// * If deserialization/decoding of the presentation fails.
+  /// * If the holder is not a valid [`DemiaDID`].
+  fn extract_holder_demia(presentation: &Jwt) -> Result<DemiaDID, ValidationError>;
+
+  /// Checks that the issuers and subjects of all credentials in a validated `presentation` are on the same
+  /// network as its holder, e.g. for single-network deployments.
+  ///
+  /// Identifiers that are not [`DemiaDID`]s are not checked, neither is anything if the holder is not a
+  /// [`DemiaDID`].
+  ///
+  /// # Errors
+  ///
+  /// [`Error::CredentialNetworkMismatch`] for the first issuer or subject on a different network.
+  fn check_holder_network<T, U>(presentation: &DecodedJwtPresentation<T, U>) -> Result<()>;
+}
+
+impl DemiaPresentationValidatorExt for JwtPresentationValidator {
+  fn extract_holder_demia(presentation: &Jwt) -> Result<DemiaDID, ValidationError> {
+    JwtPresentationValidator::extract_holder::<DemiaDID>(presentation)
+  }
+
+  fn check_holder_network<T, U>(presentation: &DecodedJwtPresentation<T, U>) -> Result<()> {
+    check_networks(
+      &presentation.presentation.holder,
+      presentation.credentials.iter().map(|decoded| &decoded.credential),
+    )
+  }
+}
+
+fn check_networks<'a, U: 'a>(holder: &Url, credentials: impl IntoIterator<Item = &'a Credential<U>>) -> Result<()> {
+  let Ok(holder) = DemiaDID::parse(holder.as_str()) else {
+    return Ok(());
+  };
+
+  for credential in credentials {
+    let subjects = credential
+      .credential_subject
+      .iter()
+      .filter_map(|subject| subject.id.as_ref());
+    for url in std::iter::once(credential.issuer.url()).chain(subjects) {
+      match DemiaDID::parse(url.as_str()) {
+        Ok(did) if did.network_str() != holder.network_str() => {
+          return Err(Error::CredentialNetworkMismatch {
+            did: did.to_string(),
+            holder_network: holder.network_str().to_owned(),
+          });
+        }
+        _ => (),
+      }
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_core::utils::Base;
+  use identity_core::utils::BaseEncoding;
+  use identity_credential::credential::Credential;
+  use identity_credential::credential::Jwt;
+  use identity_credential::credential::Subject;
+  use identity_credential::presentation::JwtPresentation;
+  use identity_credential::presentation::JwtPresentationOptions;
+  use identity_credential::validator::JwtPresentationValidator;
+  use identity_credential::validator::SignerContext;
+  use identity_credential::validator::ValidationError;
+  use identity_did::DID;
+  use isocountry::CountryCode;
+
+  use crate::DemiaDID;
+  use crate::NetworkName;
+
+  use super::check_networks;
+  use super::DemiaPresentationValidatorExt;
+
+  /// Creates an unsigned presentation JWT for the given `holder`.
+  fn presentation_jwt(holder: &str) -> Jwt {
+    let presentation: JwtPresentation = JwtPresentation::builder(Url::parse(holder).unwrap(), Object::new())
+      .build()
+      .unwrap();
+    let claims: String = presentation.serialize_jwt(&JwtPresentationOptions::default()).unwrap();
+    Jwt::new(format!(
+      "{}.{}.{}",
+      BaseEncoding::encode(br#"{"alg":"EdDSA"}"#, Base::Base64Url),
+      BaseEncoding::encode(claims.as_bytes(), Base::Base64Url),
+      BaseEncoding::encode(&[0; 64], Base::Base64Url),
+    ))
+  }
+
+  #[test]
+  fn extract_holder_demia() {
+    let holder: DemiaDID = DemiaDID::placeholder(&CountryCode::USA, &NetworkName::try_from("dmia").unwrap());
+    let jwt: Jwt = presentation_jwt(holder.as_str());
+    assert_eq!(JwtPresentationValidator::extract_holder_demia(&jwt).unwrap(), holder);
+
+    let jwt: Jwt = presentation_jwt("did:example:holder");
+    assert!(matches!(
+      JwtPresentationValidator::extract_holder_demia(&jwt).unwrap_err(),
+      ValidationError::SignerUrl {
+        signer_ctx: SignerContext::Holder,
+        ..
+      }
+    ));
+  }
+
+  fn credential(issuer: &str, subject: &str) -> Credential {
+    Credential::builder(Object::new())
+      .issuer(Url::parse(issuer).unwrap())
+      .subject(Subject::with_id(Url::parse(subject).unwrap()))
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn check_holder_network() {
+    let dmia: NetworkName = NetworkName::try_from("dmia").unwrap();
+    let dev: NetworkName = NetworkName::try_from("dev").unwrap();
+    let holder: Url = Url::parse(DemiaDID::new(&[1; 32], &CountryCode::USA, &dmia).as_str()).unwrap();
+    let same_network: String = DemiaDID::new(&[2; 32], &CountryCode::DEU, &dmia).to_string();
+    let other_network: String = DemiaDID::new(&[2; 32], &CountryCode::USA, &dev).to_string();
+
+    let credentials: Vec<Credential> = vec![
+      credential(&same_network, holder.as_str()),
+      credential("did:example:issuer", "did:example:subject"),
+    ];
+    assert!(check_networks(&holder, &credentials).is_ok());
+
+    // A mismatching issuer or subject fails.
+    for mixed in [
+      credential(&other_network, holder.as_str()),
+      credential(&same_network, &other_network),
+    ] {
+      let credentials: Vec<Credential> = vec![credential(&same_network, holder.as_str()), mixed];
+      assert!(matches!(
+        check_networks(&holder, &credentials).unwrap_err(),
+        crate::Error::CredentialNetworkMismatch { did, holder_network } if did == other_network && holder_network == "dmia"
+      ));
+    }
+
+    // Nothing is checked for holders that are not Demia DIDs.
+    let credentials: Vec<Credential> = vec![credential(&other_network, &same_network)];
+    assert!(check_networks(&Url::parse("did:example:holder").unwrap(), &credentials).is_ok());
+  }
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use isocountry::CountryCode;
+
+use crate::DemiaDID;
+
+/// Counts the given `dids` per country, see [`DemiaDID::country_code`].
+///
+/// DIDs omitting the country segment are counted towards [`DemiaDID::DEFAULT_COUNTRY`]. Countries without any DID
+/// are not contained in the returned map.
+pub fn country_histogram(dids: &[DemiaDID]) -> HashMap<CountryCode, usize> {
+  let mut histogram: HashMap<CountryCode, usize> = HashMap::new();
+  for did in dids {
+    *histogram.entry(did.country_code()).or_default() += 1;
+  }
+  histogram
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TAG: &str = "0xf29dd16310c2100fd1bf568b345fb1cc14d71caa3bd9b5ad735d2bd6d455ca3b";
+
+  #[test]
+  fn country_histogram_counts() {
+    let dids: Vec<DemiaDID> = [
+      format!("did:demia:{TAG}"),
+      format!("did:demia:usa:rms:{TAG}"),
+      format!("did:demia:USA:dev:{TAG}"),
+      format!("did:demia:deu:rms:{TAG}"),
+      format!("did:demia:DEU:smr:{TAG}"),
+      format!("did:demia:jpn:rms:{TAG}"),
+    ]
+    .iter()
+    .map(|did| DemiaDID::parse(did).unwrap())
+    .collect();
+
+    let histogram: HashMap<CountryCode, usize> = country_histogram(&dids);
+    assert_eq!(histogram.len(), 3);
+    assert_eq!(histogram[&CountryCode::USA], 3);
+    assert_eq!(histogram[&CountryCode::DEU], 2);
+    assert_eq!(histogram[&CountryCode::JPN], 1);
+    assert!(!histogram.contains_key(&CountryCode::FRA));
+
+    assert!(country_histogram(&[]).is_empty());
+  }
+}
@@ -7,18 +7,29 @@ use core::fmt::Display;
 use core::fmt::Formatter;
 use core::str::FromStr;
 
+use crypto::hashes::blake2b::Blake2b256;
+use crypto::hashes::Digest;
 use identity_core::common::KeyComparable;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_core::utils::Base;
+use identity_core::utils::BaseEncoding;
 use identity_did::BaseDIDUrl;
 use identity_did::CoreDID;
+use identity_did::DIDUrl;
 use identity_did::Error as DIDError;
 use identity_did::DID;
+use identity_document::document::CoreDocument;
 use isocountry::CountryCode;
 use ref_cast::ref_cast_custom;
 use ref_cast::RefCastCustom;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::DemiaDIDBuilder;
+use crate::DisplayedDemiaDID;
 use crate::NetworkName;
+use crate::Region;
 
 pub type Result<T> = std::result::Result<T, DIDError>;
 
@@ -50,6 +61,9 @@ impl DemiaDID {
   /// The length of an Alias ID, which is a BLAKE2b-256 hash (32-bytes).
   pub(crate) const TAG_BYTES_LEN: usize = 32;
 
+  /// The length of the compact binary form of a DID on the default network, see [`DemiaDID::to_compact_bytes`].
+  pub const COMPACT_BYTES_LEN: usize = 3 + Self::TAG_BYTES_LEN;
+
   /// Convert a `CoreDID` reference to an `DemiaDID` reference without checking the referenced value.
   ///  
   /// # Warning
@@ -88,6 +102,11 @@ impl DemiaDID {
     Self::parse(did).expect("DIDs constructed with new should be valid")
   }
 
+  /// Creates a [`DemiaDIDBuilder`] to construct a [`DemiaDID`] from named parts.
+  pub fn builder() -> DemiaDIDBuilder {
+    DemiaDIDBuilder::new()
+  }
+
   /// Constructs a new [`DemiaDID`] from a hex representation of an Alias Id and the given
   /// network name.
   pub fn from_alias_id(alias_id: &str, country_code: &CountryCode, network_name: &NetworkName) -> Self {
@@ -95,6 +114,58 @@ impl DemiaDID {
     Self::parse(did).expect("DIDs constructed with new should be valid")
   }
 
+  /// Constructs a new [`DemiaDID`] from a base58 (bitcoin alphabet) representation of the tag and the given
+  /// country code and network name.
+  ///
+  /// The tag of the resulting DID is stored in its canonical hex form, see [`DemiaDID::tag_base58`] for the
+  /// inverse conversion.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `tag` is not valid base58 or does not decode to exactly 32 bytes.
+  pub fn from_base58_tag(tag: &str, country_code: &CountryCode, network_name: &NetworkName) -> Result<Self> {
+    let bytes: [u8; Self::TAG_BYTES_LEN] = BaseEncoding::decode_base58(tag)
+      .map_err(|_| DIDError::InvalidMethodId)?
+      .try_into()
+      .map_err(|_| DIDError::InvalidMethodId)?;
+    Ok(Self::new(&bytes, country_code, network_name))
+  }
+
+  /// Decodes a [`DemiaDID`] from the compact binary form produced by [`DemiaDID::to_compact_bytes`].
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `bytes` does not follow the documented layout, references an unknown country code or
+  /// contains an invalid network name.
+  pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self> {
+    if bytes.len() < Self::COMPACT_BYTES_LEN {
+      return Err(DIDError::Other("invalid compact DID encoding"));
+    }
+    let network_len: usize = bytes[2] as usize;
+    if bytes.len() != Self::COMPACT_BYTES_LEN + network_len {
+      return Err(DIDError::Other("invalid compact DID encoding"));
+    }
+
+    let country_code: CountryCode = CountryCode::for_id(u16::from_be_bytes([bytes[0], bytes[1]]) as u32)
+      .map_err(|_| DIDError::Other("invalid country code"))?;
+    let network_name: NetworkName = if network_len == 0 {
+      NetworkName::try_from(Self::DEFAULT_NETWORK)
+    } else {
+      let network: &str = core::str::from_utf8(&bytes[3..3 + network_len]).map_err(|_| DIDError::InvalidMethodId)?;
+      // The default network has a dedicated encoding, which keeps the compact form of every DID unique.
+      if network == Self::DEFAULT_NETWORK {
+        return Err(DIDError::Other("invalid compact DID encoding"));
+      }
+      NetworkName::try_from(network.to_owned())
+    }
+    .map_err(|_| DIDError::InvalidMethodId)?;
+    let tag: [u8; Self::TAG_BYTES_LEN] = bytes[3 + network_len..]
+      .try_into()
+      .map_err(|_| DIDError::InvalidMethodId)?;
+
+    Ok(Self::new(&tag, &country_code, &network_name))
+  }
+
   /// Creates a new placeholder [`DemiaDID`] with the given network name.
   ///
   /// # Example
@@ -137,6 +208,68 @@ impl DemiaDID {
     CoreDID::parse(input.as_ref().to_lowercase()).and_then(Self::try_from_core)
   }
 
+  /// Parses a [`DemiaDID`] from the given `input` like [`DemiaDID::parse`], but retains `input` for display.
+  ///
+  /// This accommodates identifiers whose tag is shared with other systems in a checksummed mixed-case form: the
+  /// returned [`DisplayedDemiaDID::as_str`] is normalized while [`DisplayedDemiaDID::display_str`] returns `input`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input does not conform to the [`DemiaDID`] specification.
+  pub fn parse_preserving_display(input: impl Into<String>) -> Result<DisplayedDemiaDID> {
+    let input: String = input.into();
+    let did: Self = Self::parse(&input)?;
+    Ok(DisplayedDemiaDID::new(did, input))
+  }
+
+  /// Parses a [`DemiaDID`] from the given `input`, rejecting DIDs whose network name is contained in `reserved`.
+  ///
+  /// [`DemiaDID::parse`] accepts every spec-compliant network name, this allows deployments to additionally forbid
+  /// a set of reserved ones. Note that DIDs omitting the network segment belong to [`DemiaDID::DEFAULT_NETWORK`].
+  ///
+  /// # Errors
+  ///
+  /// Returns [`DIDSyntaxError`](crate::Error::DIDSyntaxError) if the input does not conform to the [`DemiaDID`]
+  /// specification and [`ReservedNetworkName`](crate::Error::ReservedNetworkName) if it uses a reserved network.
+  pub fn parse_with_reserved_networks(input: impl AsRef<str>, reserved: &[&str]) -> crate::Result<Self> {
+    let did: Self = Self::parse(input).map_err(crate::Error::DIDSyntaxError)?;
+    NetworkName::validate_network_name_with_reserved(did.network_str(), reserved)?;
+    Ok(did)
+  }
+
+  /// Parses a [`DemiaDID`] from the given `input`, rejecting DIDs whose country is not contained in `allowed`.
+  ///
+  /// [`DemiaDID::parse`] accepts every valid ISO 3166-1 alpha-3 country code, this allows deployments to restrict
+  /// the set of countries they operate in. Note that DIDs omitting the country segment belong to
+  /// [`DemiaDID::DEFAULT_COUNTRY`].
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input does not conform to the [`DemiaDID`] specification or its country is not allowed.
+  pub fn parse_with_allowed_countries(input: impl AsRef<str>, allowed: &[CountryCode]) -> Result<Self> {
+    let did: Self = Self::parse(input)?;
+    Self::check_country_allowed(&did, allowed)?;
+    Ok(did)
+  }
+
+  /// Parses a [`DemiaDID`] from the given `input`, requiring it to already be in normalized form.
+  ///
+  /// Unlike [`DemiaDID::parse`], which silently normalizes the input, this rejects any input whose string
+  /// representation would change, e.g. `did:demia:usa:dmia:0x...` with the redundant default network segment or
+  /// inputs containing uppercase characters. This guarantees a single canonical representation per DID.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input does not conform to the [`DemiaDID`] specification or is not normalized.
+  pub fn parse_strict(input: impl AsRef<str>) -> Result<Self> {
+    let input: &str = input.as_ref();
+    let did: Self = Self::parse(input)?;
+    if did.as_str() != input {
+      return Err(DIDError::Other("DID is not in normalized form"));
+    }
+    Ok(did)
+  }
+
   /// Converts a [`CoreDID`] to a [`DemiaDID`].
   ///
   /// # Errors
@@ -148,6 +281,15 @@ impl DemiaDID {
     Ok(Self(Self::normalize(did)))
   }
 
+  /// Returns a clone of the [`CoreDID`] wrapped by this `DID`.
+  ///
+  /// Prefer borrowing via [`AsRef<CoreDID>`] where a `&CoreDID` suffices, and [`From<DemiaDID>`] for [`CoreDID`]
+  /// when the [`DemiaDID`] is no longer needed. This method is intended for when an owned [`CoreDID`] is required
+  /// while the [`DemiaDID`] is kept.
+  pub fn to_core_did(&self) -> CoreDID {
+    self.0.clone()
+  }
+
   // ===========================================================================
   // Properties
   // ===========================================================================
@@ -157,6 +299,17 @@ impl DemiaDID {
     Self::denormalized_components(self.method_id()).0
   }
 
+  /// Returns the [`CountryCode`] of the `DID`.
+  pub fn country_code(&self) -> CountryCode {
+    CountryCode::for_alpha3_caseless(self.country_str()).expect("the country code is validated during DID creation")
+  }
+
+  /// Returns the coarse geographic [`Region`] of the country of the `DID`, or `None` if the country does not
+  /// belong to any region.
+  pub fn region(&self) -> Option<Region> {
+    Region::from_country(&self.country_code())
+  }
+
   /// Returns the IOTA `network` name of the `DID`.
   pub fn network_str(&self) -> &str {
     Self::denormalized_components(self.method_id()).1
@@ -167,6 +320,161 @@ impl DemiaDID {
     Self::denormalized_components(self.method_id()).2
   }
 
+  /// Returns the tag of the `DID` encoded as base58 (bitcoin alphabet) instead of hex.
+  pub fn tag_base58(&self) -> String {
+    let tag_bytes: [u8; Self::TAG_BYTES_LEN] =
+      prefix_hex::decode(self.tag()).expect("the tag is validated to be 32 hex-encoded bytes during DID creation");
+    BaseEncoding::encode_base58(&tag_bytes)
+  }
+
+  /// Encodes the `DID` into a compact binary form, e.g. for on-chain storage.
+  ///
+  /// The layout is:
+  /// - 2 bytes: the ISO 3166-1 numeric code of the country as a big-endian integer, e.g. `840` for the USA,
+  /// - 1 byte: the length of the network name, or `0` for [`DemiaDID::DEFAULT_NETWORK`],
+  /// - the UTF-8 network name, omitted for the default network,
+  /// - 32 bytes: the tag.
+  ///
+  /// DIDs on the default network are thus always [`DemiaDID::COMPACT_BYTES_LEN`] bytes long, DIDs on other networks
+  /// are longer by the length of their network name. See [`DemiaDID::from_compact_bytes`] for the inverse conversion.
+  pub fn to_compact_bytes(&self) -> Vec<u8> {
+    // ISO 3166-1 numeric codes have at most three digits.
+    let country: [u8; 2] = (self.country_code().numeric_id() as u16).to_be_bytes();
+    let network: &[u8] = match self.network_str() {
+      Self::DEFAULT_NETWORK => &[],
+      network => network.as_bytes(),
+    };
+    let tag_bytes: [u8; Self::TAG_BYTES_LEN] =
+      prefix_hex::decode(self.tag()).expect("the tag is validated to be 32 hex-encoded bytes during DID creation");
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(Self::COMPACT_BYTES_LEN + network.len());
+    bytes.extend_from_slice(&country);
+    // Network names are at most `NetworkName::MAX_LENGTH` bytes long.
+    bytes.push(network.len() as u8);
+    bytes.extend_from_slice(network);
+    bytes.extend_from_slice(&tag_bytes);
+    bytes
+  }
+
+  /// Returns whether `self` and `other` have the same country and tag, regardless of their network.
+  ///
+  /// This is intentionally weaker than `==`, which also compares the network: DIDs with the same tag on different
+  /// networks are distinct DIDs, but may be considered the same identity e.g. for deduplication.
+  pub fn same_identity(&self, other: &DemiaDID) -> bool {
+    self.country_str() == other.country_str() && self.tag() == other.tag()
+  }
+
+  /// Checks that this `DID` belongs to the network of a client, before making any network calls with it.
+  ///
+  /// Resolving a `DID` with a client of a different network fails with a confusing not-found error, this
+  /// reports the mismatch instead.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`NetworkMismatch`](crate::Error::NetworkMismatch) if the network of the `DID` differs from
+  /// `client_network`.
+  pub fn assert_network_matches(&self, client_network: &NetworkName) -> crate::Result<()> {
+    if self.network_str() != client_network.as_ref() {
+      return Err(crate::Error::NetworkMismatch {
+        expected: self.network_str().to_owned(),
+        actual: client_network.to_string(),
+      });
+    }
+    Ok(())
+  }
+
+  /// Returns a [`DIDUrl`] consisting of this `DID` and the given `fragment`, e.g. `did:demia:...#key-1`.
+  ///
+  /// A leading `#` in `fragment` is ignored.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the `fragment` is empty or contains invalid characters.
+  pub fn to_url_with_fragment(&self, fragment: &str) -> Result<DIDUrl> {
+    if fragment.strip_prefix('#').unwrap_or(fragment).is_empty() {
+      return Err(DIDError::InvalidFragment);
+    }
+    let mut url: DIDUrl = self.to_url();
+    url.set_fragment(Some(fragment))?;
+    Ok(url)
+  }
+
+  // ===========================================================================
+  // Long-form
+  // ===========================================================================
+
+  /// Constructs the short-form [`DemiaDID`] of a DID whose tag is derived from its initial state, i.e. the
+  /// BLAKE2b-256 hash of the JCS-canonicalized `document`.
+  ///
+  /// Such a DID can be expressed in long-form, see [`DemiaDID::long_form`].
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `document` cannot be canonicalized.
+  pub fn from_initial_state(
+    document: &CoreDocument,
+    country_code: &CountryCode,
+    network_name: &NetworkName,
+  ) -> crate::Result<Self> {
+    let (_, tag) = Self::encode_initial_state(document)?;
+    Ok(Self::new(&tag, country_code, network_name))
+  }
+
+  /// Returns the long-form representation of this `DID`, which embeds its initial state `document`, e.g.
+  /// `did:demia:0x...:<base64url(document)>`.
+  ///
+  /// A long-form DID can be resolved without a network call by extracting the embedded document, see
+  /// [`DemiaDID::from_long_form`].
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `document` cannot be canonicalized or its hash does not match the tag of this `DID`.
+  pub fn long_form(&self, document: &CoreDocument) -> crate::Result<DIDUrl> {
+    let (state, tag) = Self::encode_initial_state(document)?;
+    self.check_initial_state_tag(&tag)?;
+    DIDUrl::parse(format!("{self}:{state}")).map_err(crate::Error::DIDSyntaxError)
+  }
+
+  /// Splits a long-form DID, as produced by [`DemiaDID::long_form`], into its short-form [`DemiaDID`] and the
+  /// embedded initial state.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `long_form` is not a long-form [`DemiaDID`] or the embedded state does not hash to its tag.
+  pub fn from_long_form(long_form: &DIDUrl) -> crate::Result<(Self, CoreDocument)> {
+    let (short_form, state): (&str, &str) = long_form
+      .did()
+      .as_str()
+      .rsplit_once(':')
+      .ok_or(crate::Error::DIDSyntaxError(DIDError::InvalidMethodId))?;
+    let did: DemiaDID = DemiaDID::parse(short_form).map_err(crate::Error::DIDSyntaxError)?;
+
+    let state_bytes: Vec<u8> = BaseEncoding::decode(state, Base::Base64Url)
+      .map_err(|err| crate::Error::SerializationError("invalid long-form DID state encoding", Some(err)))?;
+    let document: CoreDocument = CoreDocument::from_json_slice(&state_bytes)
+      .map_err(|err| crate::Error::SerializationError("invalid long-form DID state", Some(err)))?;
+
+    let (_, tag) = Self::encode_initial_state(&document)?;
+    did.check_initial_state_tag(&tag)?;
+    Ok((did, document))
+  }
+
+  /// Returns the base64url-encoded canonical JSON of `document` together with its BLAKE2b-256 hash.
+  fn encode_initial_state(document: &CoreDocument) -> crate::Result<(String, [u8; Self::TAG_BYTES_LEN])> {
+    let state: Vec<u8> = document
+      .to_jcs()
+      .map_err(|err| crate::Error::SerializationError("failed to canonicalize the initial state", Some(err)))?;
+    let tag: [u8; Self::TAG_BYTES_LEN] = Blake2b256::digest(&state).into();
+    Ok((BaseEncoding::encode(&state, Base::Base64Url), tag))
+  }
+
+  fn check_initial_state_tag(&self, tag: &[u8; Self::TAG_BYTES_LEN]) -> crate::Result<()> {
+    if self.tag() != prefix_hex::encode(tag) {
+      return Err(crate::Error::InvalidLongFormDID("the initial state does not hash to the tag"));
+    }
+    Ok(())
+  }
+
   // ===========================================================================
   // Validation
   // ===========================================================================
@@ -231,6 +539,21 @@ impl DemiaDID {
     Ok(())
   }
 
+  /// Checks that the country of the given `DID` is contained in `allowed`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the country code of the input is invalid or not allowed.
+  fn check_country_allowed<D: DID>(did: &D, allowed: &[CountryCode]) -> Result<()> {
+    let (country_code, _, _) = Self::denormalized_components(did.method_id());
+    let country: CountryCode =
+      CountryCode::for_alpha3_caseless(country_code).map_err(|_| DIDError::Other("invalid country code"))?;
+    if !allowed.contains(&country) {
+      return Err(DIDError::Other("country code not allowed"));
+    }
+    Ok(())
+  }
+
   /// Checks if the given `DID` has a valid [`DemiaDID`] network name.
   ///
   /// # Errors
@@ -241,24 +564,32 @@ impl DemiaDID {
     NetworkName::validate_network_name(network_name).map_err(|_| DIDError::Other("invalid network name"))
   }
 
-  /// Normalizes the DID `method_id` by removing the default network segment if present.
+  /// Normalizes the DID `method_id` by removing the default network segment if present, as well as the default
+  /// country segment if both are the default.
   ///
   /// E.g.
-  /// - `"did:iota:main:123" -> "did:iota:123"` is normalized
-  /// - `"did:iota:dev:123" -> "did:iota:dev:123"` is unchanged
-  // TODO: Remove the lint once this bug in clippy has been fixed. Without to_owned a mutable reference will be aliased.
-  #[allow(clippy::unnecessary_to_owned)]
+  /// - `"did:demia:usa:dmia:123" -> "did:demia:123"` is normalized
+  /// - `"did:demia:usa:123" -> "did:demia:123"` is normalized
+  /// - `"did:demia:deu:dmia:123" -> "did:demia:deu:123"` is normalized
+  /// - `"did:demia:usa:dev:123" -> "did:demia:usa:dev:123"` is unchanged
   fn normalize(mut did: CoreDID) -> CoreDID {
     let method_id = did.method_id();
     let (country, network, tag) = Self::denormalized_components(method_id);
     if tag.len() == method_id.len() || network != Self::DEFAULT_NETWORK {
-      did
+      return did;
+    }
+
+    let normalized: String = if country == Self::DEFAULT_COUNTRY {
+      tag.to_owned()
     } else {
+      format!("{country}:{tag}")
+    };
+    if normalized != method_id {
       did
-        .set_method_id(tag.to_owned())
+        .set_method_id(normalized)
         .expect("normalizing a valid CoreDID should be Ok");
-      did
     }
+    did
   }
 
   /// foo:bar -> (foo, DemiaDID::DEFAULT_NETWORK, bar)
@@ -275,7 +606,7 @@ impl DemiaDID {
             .map(|idx| input[1..].split_at(idx))
             .map(|(network, tail)| (network, &tail[1..]))
             // Self::DEFAULT_NETWORK is built from a static reference so unwrapping is fine
-            .unwrap_or((Self::DEFAULT_NETWORK, input));
+            .unwrap_or((Self::DEFAULT_NETWORK, &input[1..]));
           (country, rest.0, rest.1)
         },
         None => (Self::DEFAULT_COUNTRY, Self::DEFAULT_NETWORK, input)
@@ -596,6 +927,121 @@ mod tests {
     }
   }
 
+  #[test]
+  fn denormalized_components() {
+    assert_eq!(
+      DemiaDID::denormalized_components("usa:dev:0x01"),
+      ("usa", "dev", "0x01")
+    );
+    assert_eq!(
+      DemiaDID::denormalized_components("deu:0x01"),
+      ("deu", DemiaDID::DEFAULT_NETWORK, "0x01")
+    );
+    assert_eq!(
+      DemiaDID::denormalized_components("0x01"),
+      (DemiaDID::DEFAULT_COUNTRY, DemiaDID::DEFAULT_NETWORK, "0x01")
+    );
+    assert_eq!(
+      DemiaDID::denormalized_components("usa:dev:0x01:rest"),
+      ("usa", "dev", "0x01:rest")
+    );
+  }
+
+  #[test]
+  fn parse_without_network() {
+    let did: DemiaDID = DemiaDID::parse(format!("did:{}:usa:{}", DemiaDID::METHOD, VALID_ALIAS_ID_STR)).unwrap();
+    assert_eq!(did.as_str(), format!("did:{}:{}", DemiaDID::METHOD, VALID_ALIAS_ID_STR));
+    assert_eq!(did.country_code(), CountryCode::USA);
+    assert_eq!(did.network_str(), DemiaDID::DEFAULT_NETWORK);
+
+    // A country other than the default one is kept when the default network segment is stripped.
+    let did: DemiaDID = DemiaDID::parse(format!("did:{}:deu:dmia:{}", DemiaDID::METHOD, VALID_ALIAS_ID_STR)).unwrap();
+    assert_eq!(did.as_str(), format!("did:{}:deu:{}", DemiaDID::METHOD, VALID_ALIAS_ID_STR));
+    assert_eq!(did.country_code(), CountryCode::DEU);
+    assert_eq!(did.network_str(), DemiaDID::DEFAULT_NETWORK);
+    assert_eq!(DemiaDID::parse(did.as_str()).unwrap(), did);
+  }
+
+  #[test]
+  fn parse_with_reserved_networks() {
+    let reserved: [&str; 1] = ["main"];
+    let main_did: String = format!("did:{}:{}:main:{}", DemiaDID::METHOD, DemiaDID::DEFAULT_COUNTRY, VALID_ALIAS_ID_STR);
+    let dev_did: String = format!("did:{}:{}:dev:{}", DemiaDID::METHOD, DemiaDID::DEFAULT_COUNTRY, VALID_ALIAS_ID_STR);
+
+    // The default behaviour is permissive.
+    assert!(DemiaDID::parse(&main_did).is_ok());
+
+    assert!(matches!(
+      DemiaDID::parse_with_reserved_networks(&main_did, &reserved),
+      Err(crate::Error::ReservedNetworkName(network)) if network == "main"
+    ));
+    assert!(matches!(
+      DemiaDID::parse_with_reserved_networks("did:demia:usa:main:0x01", &reserved),
+      Err(crate::Error::DIDSyntaxError(_))
+    ));
+    assert!(DemiaDID::parse_with_reserved_networks(&dev_did, &reserved).is_ok());
+    assert!(DemiaDID::parse_with_reserved_networks(VALID_IOTA_DID_STRING.as_str(), &reserved).is_ok());
+
+    // DIDs omitting the network segment are on the default network.
+    assert!(matches!(
+      DemiaDID::parse_with_reserved_networks(
+        format!("did:{}:{}", DemiaDID::METHOD, VALID_ALIAS_ID_STR),
+        &[DemiaDID::DEFAULT_NETWORK]
+      ),
+      Err(crate::Error::ReservedNetworkName(_))
+    ));
+  }
+
+  #[test]
+  fn parse_with_allowed_countries() {
+    let usa_did: String = format!("did:{}:usa:{}", DemiaDID::METHOD, VALID_ALIAS_ID_STR);
+    let deu_did: String = format!("did:{}:deu:dev:{}", DemiaDID::METHOD, VALID_ALIAS_ID_STR);
+    let allowed: [CountryCode; 2] = [CountryCode::USA, CountryCode::CAN];
+
+    // The default behaviour is permissive.
+    assert!(DemiaDID::parse(&deu_did).is_ok());
+
+    assert!(DemiaDID::parse_with_allowed_countries(&usa_did, &allowed).is_ok());
+    assert!(matches!(
+      DemiaDID::parse_with_allowed_countries(&deu_did, &allowed),
+      Err(DIDError::Other(_))
+    ));
+    assert!(DemiaDID::parse_with_allowed_countries(&deu_did, &[CountryCode::DEU]).is_ok());
+    assert!(DemiaDID::parse_with_allowed_countries(&usa_did, &[]).is_err());
+  }
+
+  #[test]
+  fn parse_strict() {
+    let normalized: String = format!("did:{}:{}", DemiaDID::METHOD, VALID_ALIAS_ID_STR);
+    let with_default_network: String = format!(
+      "did:{}:{}:{}:{}",
+      DemiaDID::METHOD,
+      DemiaDID::DEFAULT_COUNTRY,
+      DemiaDID::DEFAULT_NETWORK,
+      VALID_ALIAS_ID_STR
+    );
+
+    // Lenient parsing normalizes both to the same DID.
+    assert_eq!(
+      DemiaDID::parse(&with_default_network).unwrap(),
+      DemiaDID::parse(&normalized).unwrap()
+    );
+
+    assert_eq!(DemiaDID::parse_strict(&normalized).unwrap().as_str(), normalized);
+    assert!(matches!(
+      DemiaDID::parse_strict(&with_default_network),
+      Err(DIDError::Other(_))
+    ));
+    assert!(DemiaDID::parse_strict(normalized.to_uppercase().replacen("DID:DEMIA", "did:demia", 1)).is_err());
+
+    // Non-default networks are already normalized.
+    let with_network: String = format!("did:{}:{}:dev:{}", DemiaDID::METHOD, DemiaDID::DEFAULT_COUNTRY, VALID_ALIAS_ID_STR);
+    assert_eq!(DemiaDID::parse_strict(&with_network).unwrap().as_str(), with_network);
+
+    // Invalid DIDs are still rejected.
+    assert!(DemiaDID::parse_strict(format!("did:key:{VALID_ALIAS_ID_STR}")).is_err());
+  }
+
   #[test]
   fn parse_invalid() {
     let execute_assertions = |valid_alias_id: &str| {
@@ -664,6 +1110,18 @@ mod tests {
     }
   }
 
+  proptest! {
+    #[test]
+    fn property_based_parse_arbitrary_input_does_not_panic(input in ".*", suffix in "[a-z0-9:]{0,80}") {
+      // Neither arbitrary strings nor arbitrary method ids may cause a panic.
+      let _ = DemiaDID::parse(&input);
+      let _ = DemiaDID::parse_strict(&input);
+      if let Ok(did) = DemiaDID::parse(format!("did:{}:{}", DemiaDID::METHOD, suffix)) {
+        let _ = (did.country_str(), did.network_str(), did.tag());
+      }
+    }
+  }
+
   #[cfg(feature = "iota-client")]
   proptest! {
     #[test]
@@ -933,4 +1391,221 @@ mod tests {
     execute_assertions(DemiaDID::PLACEHOLDER_TAG);
     execute_assertions(VALID_ALIAS_ID_STR);
   }
+
+  #[test]
+  fn same_identity() {
+    let did_on_network = |country: &CountryCode, network: &'static str, bytes: &[u8; 32]| {
+      DemiaDID::new(bytes, country, &NetworkName::try_from(network).unwrap())
+    };
+
+    let did: DemiaDID = did_on_network(&CountryCode::USA, "dev", &[1; 32]);
+    for network in ["dev", "test", DemiaDID::DEFAULT_NETWORK] {
+      let other: DemiaDID = did_on_network(&CountryCode::USA, network, &[1; 32]);
+      assert!(did.same_identity(&other));
+      assert!(other.same_identity(&did));
+      assert_eq!(did == other, network == "dev");
+    }
+
+    // Different tags.
+    assert!(!did.same_identity(&did_on_network(&CountryCode::USA, "dev", &[2; 32])));
+    assert!(!did.same_identity(&did_on_network(&CountryCode::USA, "test", &[2; 32])));
+    // Different countries.
+    assert!(!did.same_identity(&did_on_network(&CountryCode::DEU, "dev", &[1; 32])));
+  }
+
+  #[test]
+  fn region() {
+    let network_name: NetworkName = NetworkName::try_from("dev").unwrap();
+    let did: DemiaDID = DemiaDID::new(&[1; 32], &CountryCode::USA, &network_name);
+    assert_eq!(did.region(), Some(Region::Americas));
+    // The default country is stripped during normalization.
+    let did: DemiaDID = DemiaDID::placeholder(&CountryCode::USA, &NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).unwrap());
+    assert_eq!(did.region(), Some(Region::Americas));
+    let did: DemiaDID = DemiaDID::new(&[1; 32], &CountryCode::FRA, &network_name);
+    assert_eq!(did.region(), Some(Region::Europe));
+    let did: DemiaDID = DemiaDID::new(&[1; 32], &CountryCode::ATA, &network_name);
+    assert_eq!(did.region(), None);
+  }
+
+  #[test]
+  fn assert_network_matches() {
+    let dev: NetworkName = NetworkName::try_from("dev").unwrap();
+    let did: DemiaDID = DemiaDID::new(&[1; 32], &CountryCode::USA, &dev);
+    assert!(did.assert_network_matches(&dev).is_ok());
+
+    let default_network: NetworkName = NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).unwrap();
+    let default_did: DemiaDID = DemiaDID::new(&[1; 32], &CountryCode::USA, &default_network);
+    assert!(default_did.assert_network_matches(&default_network).is_ok());
+
+    // INVALID: the networks differ.
+    match did.assert_network_matches(&default_network) {
+      Err(crate::Error::NetworkMismatch { expected, actual }) => {
+        assert_eq!(expected, "dev");
+        assert_eq!(actual, DemiaDID::DEFAULT_NETWORK);
+      }
+      other => panic!("expected a network mismatch, got {other:?}"),
+    }
+    assert!(matches!(
+      default_did.assert_network_matches(&dev),
+      Err(crate::Error::NetworkMismatch { .. })
+    ));
+  }
+
+  #[test]
+  fn base58_tag_round_trip() {
+    let network_name: NetworkName = NetworkName::try_from("dev").unwrap();
+    for bytes in [[0; 32], [1; 32], [255; 32]] {
+      let did: DemiaDID = DemiaDID::new(&bytes, &CountryCode::USA, &network_name);
+      let tag_base58: String = did.tag_base58();
+      assert_eq!(BaseEncoding::decode_base58(&tag_base58).unwrap(), bytes);
+
+      let decoded: DemiaDID = DemiaDID::from_base58_tag(&tag_base58, &CountryCode::USA, &network_name).unwrap();
+      assert_eq!(decoded, did);
+      // The DID itself keeps the canonical hex representation.
+      assert_eq!(decoded.tag(), prefix_hex::encode(bytes));
+    }
+
+    let did: DemiaDID = DemiaDID::parse(VALID_IOTA_DID_STRING.as_str()).unwrap();
+    let network_name: NetworkName = NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).unwrap();
+    assert_eq!(
+      DemiaDID::from_base58_tag(&did.tag_base58(), &CountryCode::USA, &network_name).unwrap(),
+      did
+    );
+  }
+
+  #[test]
+  fn compact_bytes_round_trip() {
+    let default_network: NetworkName = NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).unwrap();
+    let did: DemiaDID = DemiaDID::new(&[7; 32], &CountryCode::USA, &default_network);
+    let bytes: Vec<u8> = did.to_compact_bytes();
+    assert_eq!(bytes.len(), DemiaDID::COMPACT_BYTES_LEN);
+    assert_eq!(&bytes[..3], &[0x03, 0x48, 0]);
+    assert_eq!(&bytes[3..], &[7; 32]);
+    assert_eq!(DemiaDID::from_compact_bytes(&bytes).unwrap(), did);
+
+    let did: DemiaDID = DemiaDID::new(&[7; 32], &CountryCode::DEU, &NetworkName::try_from("dev").unwrap());
+    let bytes: Vec<u8> = did.to_compact_bytes();
+    assert_eq!(bytes.len(), DemiaDID::COMPACT_BYTES_LEN + 3);
+    assert_eq!(&bytes[..6], &[0x01, 0x14, 3, b'd', b'e', b'v']);
+    assert_eq!(DemiaDID::from_compact_bytes(&bytes).unwrap(), did);
+  }
+
+  #[test]
+  fn invalid_compact_bytes() {
+    let did: DemiaDID = DemiaDID::new(&[7; 32], &CountryCode::DEU, &NetworkName::try_from("dev").unwrap());
+    let bytes: Vec<u8> = did.to_compact_bytes();
+
+    // Truncated or trailing data.
+    assert!(DemiaDID::from_compact_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(DemiaDID::from_compact_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+    assert!(DemiaDID::from_compact_bytes(&[]).is_err());
+    // Unknown country code.
+    let mut unknown_country: Vec<u8> = bytes.clone();
+    unknown_country[..2].copy_from_slice(&999u16.to_be_bytes());
+    assert!(DemiaDID::from_compact_bytes(&unknown_country).is_err());
+    // Invalid network name.
+    let mut invalid_network: Vec<u8> = bytes;
+    invalid_network[3] = b'D';
+    assert!(DemiaDID::from_compact_bytes(&invalid_network).is_err());
+    // The default network must use its dedicated encoding.
+    let explicit_default: Vec<u8> = [&[0x03, 0x48, 4][..], DemiaDID::DEFAULT_NETWORK.as_bytes(), &[7; 32]].concat();
+    assert!(DemiaDID::from_compact_bytes(&explicit_default).is_err());
+  }
+
+  #[test]
+  fn invalid_base58_tag() {
+    let network_name: NetworkName = NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).unwrap();
+    // Not base58: `0`, `O`, `I` and `l` are not part of the alphabet.
+    assert!(DemiaDID::from_base58_tag("0OIl", &CountryCode::USA, &network_name).is_err());
+    // Valid base58 of the wrong length.
+    for bytes in [&[1; 31][..], &[1; 33][..], &[][..]] {
+      let tag: String = BaseEncoding::encode_base58(bytes);
+      assert!(matches!(
+        DemiaDID::from_base58_tag(&tag, &CountryCode::USA, &network_name),
+        Err(DIDError::InvalidMethodId)
+      ));
+    }
+  }
+
+  #[test]
+  fn url_with_fragment() {
+    let did: DemiaDID = DemiaDID::parse(VALID_IOTA_DID_STRING.as_str()).unwrap();
+
+    let did_url: DIDUrl = did.to_url_with_fragment("key-1").unwrap();
+    assert_eq!(did_url.fragment(), Some("key-1"));
+    assert_eq!(did_url.did(), did.as_ref());
+    assert_eq!(did_url.to_string(), format!("{did}#key-1"));
+    // A leading '#' is ignored.
+    assert_eq!(did.to_url_with_fragment("#key-1").unwrap(), did_url);
+
+    for invalid_fragment in ["", "#", "key 1", "key#1", "key%"] {
+      assert!(matches!(
+        did.to_url_with_fragment(invalid_fragment),
+        Err(DIDError::InvalidFragment)
+      ));
+    }
+  }
+
+  #[test]
+  fn long_form_round_trip() {
+    let network_name: NetworkName = NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).unwrap();
+    let document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(DemiaDID::placeholder(&CountryCode::USA, &network_name).into())
+      .build()
+      .unwrap();
+
+    let did: DemiaDID = DemiaDID::from_initial_state(&document, &CountryCode::USA, &network_name).unwrap();
+    assert!(!did.is_placeholder());
+
+    let long_form: DIDUrl = did.long_form(&document).unwrap();
+    assert!(long_form.to_string().starts_with(&format!("{did}:")));
+
+    let (short_form, embedded): (DemiaDID, CoreDocument) = DemiaDID::from_long_form(&long_form).unwrap();
+    assert_eq!(short_form, did);
+    assert_eq!(embedded, document);
+    assert_eq!(short_form.long_form(&embedded).unwrap(), long_form);
+  }
+
+  #[test]
+  fn long_form_rejects_mismatching_state() {
+    let network_name: NetworkName = NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).unwrap();
+    let document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(DemiaDID::placeholder(&CountryCode::USA, &network_name).into())
+      .build()
+      .unwrap();
+    let did: DemiaDID = DemiaDID::from_initial_state(&document, &CountryCode::USA, &network_name).unwrap();
+
+    // The state of a DID whose tag is not derived from it cannot be embedded.
+    let other: DemiaDID = DemiaDID::parse(VALID_IOTA_DID_STRING.as_str()).unwrap();
+    assert!(matches!(
+      other.long_form(&document),
+      Err(crate::Error::InvalidLongFormDID(_))
+    ));
+
+    // Tampering with the embedded state is detected.
+    let mut tampered: CoreDocument = document.clone();
+    tampered.also_known_as_mut().append(identity_core::common::Url::parse("https://example.com").unwrap());
+    let long_form: DIDUrl = did.long_form(&document).unwrap();
+    let tampered_long_form: DIDUrl = DIDUrl::parse(format!(
+      "{did}:{}",
+      BaseEncoding::encode(&tampered.to_jcs().unwrap(), Base::Base64Url)
+    ))
+    .unwrap();
+    assert!(DemiaDID::from_long_form(&long_form).is_ok());
+    assert!(matches!(
+      DemiaDID::from_long_form(&tampered_long_form),
+      Err(crate::Error::InvalidLongFormDID(_))
+    ));
+
+    // A short-form DID is not a long-form DID.
+    assert!(DemiaDID::from_long_form(&did.to_url()).is_err());
+  }
+
+  #[test]
+  fn to_core_did() {
+    let did: DemiaDID = DemiaDID::parse(VALID_IOTA_DID_STRING.as_str()).unwrap();
+    let core_did: CoreDID = did.to_core_did();
+    assert_eq!(&core_did, did.as_ref());
+    assert_eq!(core_did, CoreDID::from(did));
+  }
 }
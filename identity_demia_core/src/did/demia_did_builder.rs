@@ -0,0 +1,97 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::Error as DIDError;
+use isocountry::CountryCode;
+
+use crate::did::demia_did::Result;
+use crate::DemiaDID;
+use crate::NetworkName;
+
+/// A `DemiaDIDBuilder` is used to construct a [`DemiaDID`] from named parts.
+///
+/// Unset parts default to [`DemiaDID::DEFAULT_COUNTRY`] and [`DemiaDID::DEFAULT_NETWORK`]; the tag is required.
+#[derive(Clone, Debug, Default)]
+pub struct DemiaDIDBuilder {
+  tag_bytes: Option<[u8; 32]>,
+  country: Option<CountryCode>,
+  network: Option<NetworkName>,
+}
+
+impl DemiaDIDBuilder {
+  /// Creates a new `DemiaDIDBuilder`.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the byte representation of the tag.
+  #[must_use]
+  pub fn tag_bytes(mut self, value: [u8; 32]) -> Self {
+    self.tag_bytes = Some(value);
+    self
+  }
+
+  /// Sets the country code.
+  #[must_use]
+  pub fn country(mut self, value: CountryCode) -> Self {
+    self.country = Some(value);
+    self
+  }
+
+  /// Sets the network name.
+  #[must_use]
+  pub fn network(mut self, value: NetworkName) -> Self {
+    self.network = Some(value);
+    self
+  }
+
+  /// Returns a new [`DemiaDID`] based on the builder configuration.
+  ///
+  /// # Errors
+  ///
+  /// Fails if no tag was set.
+  pub fn build(self) -> Result<DemiaDID> {
+    let tag_bytes: [u8; 32] = self.tag_bytes.ok_or(DIDError::Other("missing tag"))?;
+    let country: CountryCode = match self.country {
+      Some(country) => country,
+      None => {
+        CountryCode::for_alpha3_caseless(DemiaDID::DEFAULT_COUNTRY).expect("default country code should be valid")
+      }
+    };
+    let network: NetworkName = match self.network {
+      Some(network) => network,
+      None => NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).expect("default network name should be valid"),
+    };
+
+    Ok(DemiaDID::new(&tag_bytes, &country, &network))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn build_matches_new() {
+    let network: NetworkName = NetworkName::try_from("test").unwrap();
+    let built: DemiaDID = DemiaDIDBuilder::new()
+      .tag_bytes([7; 32])
+      .country(CountryCode::DEU)
+      .network(network.clone())
+      .build()
+      .unwrap();
+    assert_eq!(built, DemiaDID::new(&[7; 32], &CountryCode::DEU, &network));
+
+    let defaulted: DemiaDID = DemiaDID::builder().tag_bytes([7; 32]).build().unwrap();
+    assert_eq!(
+      defaulted,
+      DemiaDID::new(
+        &[7; 32],
+        &CountryCode::USA,
+        &NetworkName::try_from(DemiaDID::DEFAULT_NETWORK).unwrap()
+      )
+    );
+
+    assert!(DemiaDIDBuilder::new().country(CountryCode::USA).build().is_err());
+  }
+}
@@ -0,0 +1,108 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::hash::Hash;
+use core::hash::Hasher;
+
+use identity_did::DID;
+
+use crate::DemiaDID;
+
+/// A [`DemiaDID`] which retains the string it was parsed from, see [`DemiaDID::parse_preserving_display`].
+///
+/// Comparisons and hashing only consider the normalized [`DemiaDID`], the original string is kept for display
+/// purposes only, e.g. to show a checksummed mixed-case tag as it was received.
+#[derive(Clone, Debug)]
+pub struct DisplayedDemiaDID {
+  did: DemiaDID,
+  display: String,
+}
+
+impl DisplayedDemiaDID {
+  pub(crate) fn new(did: DemiaDID, display: String) -> Self {
+    Self { did, display }
+  }
+
+  /// Returns the normalized [`DemiaDID`].
+  pub fn did(&self) -> &DemiaDID {
+    &self.did
+  }
+
+  /// Returns the normalized string representation of the DID.
+  pub fn as_str(&self) -> &str {
+    self.did.as_str()
+  }
+
+  /// Returns the string the DID was parsed from.
+  pub fn display_str(&self) -> &str {
+    &self.display
+  }
+
+  /// Consumes the `DisplayedDemiaDID`, returning the normalized [`DemiaDID`].
+  pub fn into_did(self) -> DemiaDID {
+    self.did
+  }
+}
+
+impl PartialEq for DisplayedDemiaDID {
+  fn eq(&self, other: &Self) -> bool {
+    self.did == other.did
+  }
+}
+
+impl Eq for DisplayedDemiaDID {}
+
+impl Hash for DisplayedDemiaDID {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.did.hash(state)
+  }
+}
+
+/// Formats the string the DID was parsed from.
+impl Display for DisplayedDemiaDID {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(&self.display)
+  }
+}
+
+impl AsRef<DemiaDID> for DisplayedDemiaDID {
+  fn as_ref(&self) -> &DemiaDID {
+    &self.did
+  }
+}
+
+impl From<DisplayedDemiaDID> for DemiaDID {
+  fn from(did: DisplayedDemiaDID) -> Self {
+    did.did
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const MIXED_CASE: &str = "did:demia:usa:rms:0x7591A0BC872e3a4ab66228d65773961a7a95d2299ec8464331c80fcd86B35F38";
+
+  #[test]
+  fn parse_preserving_display() {
+    let displayed: DisplayedDemiaDID = DemiaDID::parse_preserving_display(MIXED_CASE).unwrap();
+    let normalized: DemiaDID = DemiaDID::parse(MIXED_CASE).unwrap();
+
+    assert_eq!(displayed.as_str(), normalized.as_str());
+    assert_eq!(displayed.as_str(), MIXED_CASE.to_lowercase());
+    assert_eq!(displayed.display_str(), MIXED_CASE);
+    assert_eq!(displayed.to_string(), MIXED_CASE);
+    assert_eq!(displayed.did(), &normalized);
+
+    // The original string does not take part in comparisons.
+    let lowercase: DisplayedDemiaDID = DemiaDID::parse_preserving_display(MIXED_CASE.to_lowercase()).unwrap();
+    assert_eq!(lowercase, displayed);
+    assert_ne!(lowercase.display_str(), displayed.display_str());
+    assert_eq!(DemiaDID::from(displayed), normalized);
+
+    // The input is still validated.
+    assert!(DemiaDID::parse_preserving_display("did:demia:usa:rms:0x7591A0BC").is_err());
+  }
+}
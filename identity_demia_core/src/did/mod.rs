@@ -1,6 +1,14 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub use country_histogram::country_histogram;
 pub use demia_did::DemiaDID;
+pub use demia_did_builder::DemiaDIDBuilder;
+pub use displayed_demia_did::DisplayedDemiaDID;
+pub use region::Region;
 
+mod country_histogram;
 mod demia_did;
+mod demia_did_builder;
+mod displayed_demia_did;
+mod region;
@@ -0,0 +1,111 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use isocountry::CountryCode;
+
+/// A coarse geographic region grouping countries, following the regions of the
+/// [UN M49 standard](https://unstats.un.org/unsd/methodology/m49/).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Region {
+  /// Africa.
+  Africa,
+  /// North, Central and South America and the Caribbean.
+  Americas,
+  /// Asia, including the Middle East.
+  Asia,
+  /// Europe.
+  Europe,
+  /// Australia, New Zealand and the Pacific islands.
+  Oceania,
+}
+
+impl Region {
+  /// Returns the [`Region`] of the given `country`, or `None` if it does not belong to any region, e.g.
+  /// Antarctica.
+  pub fn from_country(country: &CountryCode) -> Option<Self> {
+    let region: Self = match country.alpha3() {
+      // Northern Africa.
+      "DZA" | "EGY" | "LBY" | "MAR" | "SDN" | "TUN" | "ESH"
+      // Eastern Africa.
+      | "IOT" | "BDI" | "COM" | "DJI" | "ERI" | "ETH" | "ATF" | "KEN" | "MDG" | "MWI" | "MUS" | "MYT" | "MOZ"
+      | "REU" | "RWA" | "SYC" | "SOM" | "SSD" | "UGA" | "TZA" | "ZMB" | "ZWE"
+      // Middle Africa.
+      | "AGO" | "CMR" | "CAF" | "TCD" | "COG" | "COD" | "GNQ" | "GAB" | "STP"
+      // Southern Africa.
+      | "BWA" | "SWZ" | "LSO" | "NAM" | "ZAF"
+      // Western Africa.
+      | "BEN" | "BFA" | "CPV" | "CIV" | "GMB" | "GHA" | "GIN" | "GNB" | "LBR" | "MLI" | "MRT" | "NER" | "NGA"
+      | "SHN" | "SEN" | "SLE" | "TGO" => Self::Africa,
+      // Caribbean.
+      "AIA" | "ATG" | "ABW" | "BHS" | "BRB" | "BES" | "VGB" | "CYM" | "CUB" | "CUW" | "DMA" | "DOM" | "GRD"
+      | "GLP" | "HTI" | "JAM" | "MTQ" | "MSR" | "PRI" | "BLM" | "KNA" | "LCA" | "MAF" | "VCT" | "SXM" | "TTO"
+      | "TCA" | "VIR"
+      // Central America.
+      | "BLZ" | "CRI" | "SLV" | "GTM" | "HND" | "MEX" | "NIC" | "PAN"
+      // South America.
+      | "ARG" | "BOL" | "BVT" | "BRA" | "CHL" | "COL" | "ECU" | "FLK" | "GUF" | "GUY" | "PRY" | "PER" | "SGS"
+      | "SUR" | "URY" | "VEN"
+      // Northern America.
+      | "BMU" | "CAN" | "GRL" | "SPM" | "USA" => Self::Americas,
+      // Central Asia.
+      "KAZ" | "KGZ" | "TJK" | "TKM" | "UZB"
+      // Eastern Asia.
+      | "CHN" | "HKG" | "MAC" | "PRK" | "JPN" | "MNG" | "KOR" | "TWN"
+      // South-eastern Asia.
+      | "BRN" | "KHM" | "IDN" | "LAO" | "MYS" | "MMR" | "PHL" | "SGP" | "THA" | "TLS" | "VNM"
+      // Southern Asia.
+      | "AFG" | "BGD" | "BTN" | "IND" | "IRN" | "MDV" | "NPL" | "PAK" | "LKA"
+      // Western Asia.
+      | "ARM" | "AZE" | "BHR" | "CYP" | "GEO" | "IRQ" | "ISR" | "JOR" | "KWT" | "LBN" | "OMN" | "QAT" | "SAU"
+      | "PSE" | "SYR" | "TUR" | "ARE" | "YEM" => Self::Asia,
+      // Eastern Europe.
+      "BLR" | "BGR" | "CZE" | "HUN" | "POL" | "MDA" | "ROU" | "RUS" | "SVK" | "UKR"
+      // Northern Europe.
+      | "ALA" | "DNK" | "EST" | "FRO" | "FIN" | "GGY" | "ISL" | "IRL" | "IMN" | "JEY" | "LVA" | "LTU" | "NOR"
+      | "SJM" | "SWE" | "GBR"
+      // Southern Europe.
+      | "ALB" | "AND" | "BIH" | "HRV" | "GIB" | "GRC" | "VAT" | "ITA" | "MLT" | "MNE" | "MKD" | "PRT" | "SMR"
+      | "SRB" | "SVN" | "ESP"
+      // Western Europe.
+      | "AUT" | "BEL" | "FRA" | "DEU" | "LIE" | "LUX" | "MCO" | "NLD" | "CHE" => Self::Europe,
+      // Australia and New Zealand.
+      "AUS" | "CXR" | "CCK" | "HMD" | "NZL" | "NFK"
+      // Melanesia.
+      | "FJI" | "NCL" | "PNG" | "SLB" | "VUT"
+      // Micronesia.
+      | "GUM" | "KIR" | "MHL" | "FSM" | "NRU" | "MNP" | "PLW" | "UMI"
+      // Polynesia.
+      | "ASM" | "COK" | "PYF" | "NIU" | "PCN" | "WSM" | "TKL" | "TON" | "TUV" | "WLF" => Self::Oceania,
+      _ => return None,
+    };
+    Some(region)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn from_country() {
+    assert_eq!(Region::from_country(&CountryCode::USA), Some(Region::Americas));
+    assert_eq!(Region::from_country(&CountryCode::BRA), Some(Region::Americas));
+    assert_eq!(Region::from_country(&CountryCode::DEU), Some(Region::Europe));
+    assert_eq!(Region::from_country(&CountryCode::JPN), Some(Region::Asia));
+    assert_eq!(Region::from_country(&CountryCode::KEN), Some(Region::Africa));
+    assert_eq!(Region::from_country(&CountryCode::NZL), Some(Region::Oceania));
+    assert_eq!(Region::from_country(&CountryCode::ATA), None);
+  }
+
+  #[test]
+  fn all_countries_except_antarctica_have_a_region() {
+    for country in CountryCode::iter() {
+      assert_eq!(
+        Region::from_country(country).is_some(),
+        *country != CountryCode::ATA,
+        "{}",
+        country.alpha3()
+      );
+    }
+  }
+}
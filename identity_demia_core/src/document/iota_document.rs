@@ -4,8 +4,9 @@
 use core::fmt;
 use core::fmt::Debug;
 use core::fmt::Display;
+use std::collections::HashSet;
+
 use identity_credential::credential::Jws;
-#[cfg(feature = "client")]
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_document::verifiable::JwsVerificationOptions;
@@ -41,6 +42,7 @@ use crate::Error;
 use crate::DemiaDID;
 use crate::IotaDocumentMetadata;
 use crate::NetworkName;
+use crate::StateIndex;
 use crate::StateMetadataDocument;
 use crate::StateMetadataEncoding;
 
@@ -127,6 +129,14 @@ impl IotaDocument {
     DemiaDID::from_inner_ref_unchecked(self.document.id())
   }
 
+  /// Returns the state index of the Alias Output the document was resolved from, if known.
+  ///
+  /// Comparing the state indices of two resolved documents of the same DID tells which one is the more recent
+  /// version. The state index is not part of the published state metadata.
+  pub fn state_index(&self) -> Option<StateIndex> {
+    self.metadata.state_index
+  }
+
   /// Returns an iterator yielding the DID controllers.
   ///
   /// NOTE: controllers are determined by the `state_controller` unlock condition of the output
@@ -144,6 +154,51 @@ impl IotaDocument {
     core_did_controller_iter.map(DemiaDID::from_inner_ref_unchecked)
   }
 
+  /// Validates the controller chain of the document, following the controllers through the given `documents`.
+  ///
+  /// Every controller encountered along the chain must be a valid [`DemiaDID`], and no document may be controlled
+  /// by itself, neither directly nor through other documents. Controllers whose document is not contained in
+  /// `documents` end the chain.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::InvalidController`] if a controller is not a valid [`DemiaDID`] or the chain contains a cycle.
+  pub fn validate_controllers(&self, documents: &[IotaDocument]) -> Result<()> {
+    Self::validate_controllers_of(&self.document, documents, &mut Vec::new(), &mut HashSet::new())
+  }
+
+  /// Validates the controllers of `document` and, recursively, of their documents. `path` holds the identifiers of
+  /// the documents currently being validated, `validated` those whose chain is known to be valid.
+  fn validate_controllers_of<'doc>(
+    document: &'doc CoreDocument,
+    documents: &'doc [IotaDocument],
+    path: &mut Vec<&'doc CoreDID>,
+    validated: &mut HashSet<&'doc CoreDID>,
+  ) -> Result<()> {
+    path.push(document.id());
+    for controller in document.controller().into_iter().flat_map(|controllers| controllers.iter()) {
+      DemiaDID::check_validity(controller).map_err(|_| Error::InvalidController {
+        did: controller.to_string(),
+        reason: "not a valid demia DID",
+      })?;
+      if path.contains(&controller) {
+        return Err(Error::InvalidController {
+          did: controller.to_string(),
+          reason: "the controller chain contains a cycle",
+        });
+      }
+      if validated.contains(controller) {
+        continue;
+      }
+      if let Some(controller_document) = documents.iter().find(|doc| doc.document.id() == controller) {
+        Self::validate_controllers_of(&controller_document.document, documents, path, validated)?;
+      }
+    }
+    path.pop();
+    validated.insert(document.id());
+    Ok(())
+  }
+
   /// Returns a reference to the `alsoKnownAs` set.
   pub fn also_known_as(&self) -> &OrderedSet<Url> {
     self.document.also_known_as()
@@ -442,6 +497,7 @@ use crate::block::address::Address;
         &CountryCode::for_alpha3_caseless(did.country_str()).map_err(Error::InvalidCountryCode)?, 
         &did.network_str().to_owned().try_into()?
       );
+      document.metadata.state_index = Some(StateIndex::new(alias_output.state_index()));
       Ok(document)
     }
 
@@ -457,6 +513,25 @@ use crate::block::address::Address;
       *self.core_document_mut().controller_mut() = controller_did.map(CoreDID::from).map(OneOrSet::new_one);
     }
 
+    /// Returns the DID Document of `did` stored in the state metadata of `alias_output`.
+    ///
+    /// See [`extract_document`].
+    pub fn extract_from_output(did: &DemiaDID, alias_output: &AliasOutput) -> Result<IotaDocument> {
+      if !alias_output.alias_id().is_null() && alias_output.alias_id() != &AliasId::from(did) {
+        return Err(Error::AliasOutputMismatch("the alias id does not match the tag of the DID"));
+      }
+      for address in [alias_output.state_controller_address(), alias_output.governor_address()] {
+        if matches!(address, Address::Alias(alias_address) if alias_address.alias_id() == &AliasId::from(did)) {
+          return Err(Error::AliasOutputMismatch("the alias output cannot be controlled by itself"));
+        }
+      }
+      if alias_output.state_metadata().is_empty() {
+        return Err(Error::InvalidStateMetadata("empty state metadata, the DID may have been deactivated"));
+      }
+
+      IotaDocument::unpack_from_output(did, alias_output, false)
+    }
+
     /// Returns all DID documents of the Alias Outputs contained in the block's transaction payload
     /// outputs, if any.
     ///
@@ -494,6 +569,23 @@ use crate::block::address::Address;
   }
 }
 
+/// Returns the DID Document of `did` stored in the state metadata of `alias_output`, as fetched for the
+/// [`AliasId`](crate::block::output::AliasId) of `did`.
+///
+/// The state controller and governor of the output are reflected in the controller of the returned document.
+///
+/// # Errors
+///
+/// - [`AliasOutputMismatch`](Error::AliasOutputMismatch) if the output does not belong to `did` or controls itself.
+/// - [`InvalidStateMetadata`](Error::InvalidStateMetadata) if the state metadata is empty or malformed.
+#[cfg(feature = "client")]
+pub fn extract_document(
+  alias_output: &crate::block::output::AliasOutput,
+  did: &DemiaDID,
+) -> Result<CoreDocument> {
+  IotaDocument::extract_from_output(did, alias_output).map(CoreDocument::from)
+}
+
 impl AsRef<CoreDocument> for IotaDocument {
   fn as_ref(&self) -> &CoreDocument {
     &self.document
@@ -572,6 +664,7 @@ impl TryMethod for IotaDocument {
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::OneOrSet;
   use identity_core::common::Timestamp;
   use identity_core::convert::FromJson;
   use identity_core::convert::ToJson;
@@ -870,20 +963,94 @@ mod tests {
 
     // Ensure no other fields are injected.
     let json: String = format!(
-      r#"{{"doc":{{"id":"{did}","controller":"{controller_did}"}},"meta":{{"deactivated":true,"governorAddress":"dmia1pz424242424242424242424242424242424242424242424242425uk7x2y","stateControllerAddress":"dmia1pz424242424242424242424242424242424242424242424242425uk7x2y"}}}}"#
+      r#"{{"doc":{{"id":"{did}","controller":"{controller_did}"}},"meta":{{"deactivated":true,"governorAddress":"dmia1pz424242424242424242424242424242424242424242424242425uk7x2y","stateControllerAddress":"dmia1pz424242424242424242424242424242424242424242424242425uk7x2y","stateIndex":0}}}}"#
     );
     assert_eq!(document.to_json().unwrap(), json);
 
     // INVALID: reject empty document.
     assert!(IotaDocument::unpack_from_output(&did, &alias_output, false).is_err());
 
-    // Ensure re-packing removes the controller, state controller address, and governor address.
+    // Ensure re-packing removes the controller, state controller address, governor address, and state index.
     let packed: Vec<u8> = document.pack_with_encoding(StateMetadataEncoding::Json).unwrap();
     let state_metadata_document: StateMetadataDocument = StateMetadataDocument::unpack(&packed).unwrap();
     let unpacked_document: IotaDocument = state_metadata_document.into_demia_document(&did).unwrap();
     assert!(unpacked_document.document.controller().is_none());
     assert!(unpacked_document.metadata.state_controller_address.is_none());
     assert!(unpacked_document.metadata.governor_address.is_none());
+    assert!(unpacked_document.metadata.state_index.is_none());
+  }
+
+  #[test]
+  fn test_state_index() {
+    let did: DemiaDID = valid_did();
+    let json: String = format!(r#"{{"doc":{{"id":"{did}"}},"meta":{{"stateIndex":5}}}}"#);
+    let document: IotaDocument = IotaDocument::from_json(&json).unwrap();
+    assert_eq!(document.state_index(), Some(StateIndex::new(5)));
+    assert!(document.state_index() > Some(StateIndex::new(4)));
+    assert_eq!(document.to_json().unwrap(), json);
+
+    // The state index is unknown for documents that were not resolved from an Alias Output.
+    assert!(IotaDocument::new_with_id(did).state_index().is_none());
+  }
+
+  #[test]
+  fn test_extract_document() {
+    let mock_token_supply: u64 = ProtocolParameters::default().token_supply();
+    let controller_did: DemiaDID = valid_did();
+    let did: DemiaDID = "did:demia:0xBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBBB"
+      .parse()
+      .unwrap();
+    let alias_output = |alias_id: AliasId, controller: Address, state_metadata: Vec<u8>| -> AliasOutput {
+      AliasOutputBuilder::new_with_amount(1, alias_id)
+        .with_state_metadata(state_metadata)
+        .add_unlock_condition(UnlockCondition::StateControllerAddress(
+          StateControllerAddressUnlockCondition::new(controller),
+        ))
+        .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+          controller,
+        )))
+        .finish(mock_token_supply)
+        .unwrap()
+    };
+    let controller: Address = Address::Alias(AliasAddress::new(AliasId::from(&controller_did)));
+    let packed: Vec<u8> = generate_document(&did).pack().unwrap();
+
+    // VALID: the document is extracted and its controller is set from the state controller.
+    let document: CoreDocument =
+      extract_document(&alias_output(AliasId::from(&did), controller, packed.clone()), &did).unwrap();
+    assert_eq!(document.id(), did.as_ref());
+    assert_eq!(document.methods(None).len(), 4);
+    assert_eq!(
+      document.controller().map(|controllers| controllers.iter().collect::<Vec<_>>()),
+      Some(vec![controller_did.as_ref()])
+    );
+    // The alias id of an output created in the same transaction is still null.
+    assert!(extract_document(&alias_output(AliasId::null(), controller, packed.clone()), &did).is_ok());
+
+    // INVALID: the output belongs to a different DID.
+    assert!(matches!(
+      extract_document(&alias_output(AliasId::from(&controller_did), controller, packed.clone()), &did),
+      Err(Error::AliasOutputMismatch(_))
+    ));
+
+    // INVALID: the output controls itself.
+    let self_controller: Address = Address::Alias(AliasAddress::new(AliasId::from(&did)));
+    assert!(matches!(
+      extract_document(&alias_output(AliasId::from(&did), self_controller, packed), &did),
+      Err(Error::AliasOutputMismatch(_))
+    ));
+
+    // INVALID: empty state metadata.
+    assert!(matches!(
+      extract_document(&alias_output(AliasId::from(&did), controller, Vec::new()), &did),
+      Err(Error::InvalidStateMetadata(_))
+    ));
+
+    // INVALID: malformed state metadata.
+    assert!(matches!(
+      extract_document(&alias_output(AliasId::from(&did), controller, b"DID\x01\x00garbage".to_vec()), &did),
+      Err(Error::InvalidStateMetadata(_)) | Err(Error::InvalidDoc(_)) | Err(Error::SerializationError(..))
+    ));
   }
 
   #[test]
@@ -1051,6 +1218,60 @@ mod tests {
     assert_eq!(&controllers, &[&expected_controller]);
   }
 
+  #[test]
+  fn validate_controllers() {
+    fn document_controlled_by(id: &str, controllers: &[&str]) -> IotaDocument {
+      let mut document: IotaDocument = IotaDocument::new_with_id(DemiaDID::parse(id).unwrap());
+      *document.core_document_mut().controller_mut() = OneOrSet::try_from(
+        controllers
+          .iter()
+          .map(|controller| CoreDID::parse(controller).unwrap())
+          .collect::<Vec<CoreDID>>(),
+      )
+      .ok();
+      document
+    }
+    const DID_A: &str = "did:demia:usa:rms:0x7591a0bc872e3a4ab66228d65773961a7a95d2299ec8464331c80fcd86b35f38";
+    const DID_B: &str = "did:demia:usa:rms:0xfbaaa919b51112d51a8f18b1500d98f0b2e91d793bc5b27fd5ab04cb1b806343";
+    const DID_C: &str = "did:demia:usa:rms:0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    // VALID: a chain of controllers ending in a document without controller, or in an unknown document.
+    let document: IotaDocument = document_controlled_by(DID_A, &[DID_B]);
+    let controller: IotaDocument = document_controlled_by(DID_B, &[DID_C]);
+    assert!(document.validate_controllers(&[]).is_ok());
+    assert!(document.validate_controllers(&[controller.clone()]).is_ok());
+    assert!(document
+      .validate_controllers(&[controller, document_controlled_by(DID_C, &[])])
+      .is_ok());
+
+    // INVALID: a controller of another method.
+    let document: IotaDocument = document_controlled_by(DID_A, &[DID_B, "did:example:1234"]);
+    assert!(matches!(
+      document.validate_controllers(&[]),
+      Err(Error::InvalidController { .. })
+    ));
+    let controller: IotaDocument = document_controlled_by(DID_B, &["did:example:1234"]);
+    assert!(matches!(
+      document_controlled_by(DID_A, &[DID_B]).validate_controllers(&[controller]),
+      Err(Error::InvalidController { .. })
+    ));
+
+    // INVALID: self-referential cycles.
+    assert!(matches!(
+      document_controlled_by(DID_A, &[DID_A]).validate_controllers(&[]),
+      Err(Error::InvalidController { .. })
+    ));
+    let document: IotaDocument = document_controlled_by(DID_A, &[DID_B]);
+    let controllers: [IotaDocument; 2] = [
+      document_controlled_by(DID_B, &[DID_C]),
+      document_controlled_by(DID_C, &[DID_A]),
+    ];
+    assert!(matches!(
+      document.validate_controllers(&controllers),
+      Err(Error::InvalidController { .. })
+    ));
+  }
+
   #[test]
   fn try_from_doc_metadata() {
     const DOC_JSON_NOT_IOTA_DOCUMENT_BECAUSE_OF_ID: &str = r#"
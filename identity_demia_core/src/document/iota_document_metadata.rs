@@ -27,6 +27,9 @@ pub struct IotaDocumentMetadata {
   /// Bech32-encoded address of the state controller unlock condition.
   #[serde(rename = "stateControllerAddress", skip_serializing_if = "Option::is_none")]
   pub state_controller_address: Option<String>,
+  /// State index of the Alias Output the document was resolved from.
+  #[serde(rename = "stateIndex", skip_serializing_if = "Option::is_none")]
+  pub state_index: Option<StateIndex>,
   #[serde(flatten)]
   pub properties: Object,
 }
@@ -42,6 +45,7 @@ impl IotaDocumentMetadata {
       deactivated: None,
       governor_address: None,
       state_controller_address: None,
+      state_index: None,
       properties: Object::default(),
     }
   }
@@ -58,3 +62,41 @@ impl Display for IotaDocumentMetadata {
     self.fmt_json(f)
   }
 }
+
+/// The state index of the Alias Output backing a DID document.
+///
+/// The state index is incremented with every state transition of the Alias Output, so it orders the published
+/// versions of a document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct StateIndex(u32);
+
+impl StateIndex {
+  /// Creates a new [`StateIndex`].
+  pub const fn new(index: u32) -> Self {
+    Self(index)
+  }
+
+  /// Returns the state index as a `u32`.
+  pub const fn get(self) -> u32 {
+    self.0
+  }
+}
+
+impl From<u32> for StateIndex {
+  fn from(index: u32) -> Self {
+    Self(index)
+  }
+}
+
+impl From<StateIndex> for u32 {
+  fn from(index: StateIndex) -> Self {
+    index.0
+  }
+}
+
+impl Display for StateIndex {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    Display::fmt(&self.0, f)
+  }
+}
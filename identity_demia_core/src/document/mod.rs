@@ -1,8 +1,11 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "client")]
+pub use iota_document::extract_document;
 pub use iota_document::IotaDocument;
 pub use iota_document_metadata::IotaDocumentMetadata;
+pub use iota_document_metadata::StateIndex;
 
 mod iota_document;
 mod iota_document_metadata;
@@ -23,6 +23,8 @@ pub enum Error {
   BasicOutputBuildError(#[source] iota_sdk::types::block::Error),
   #[error("\"{0}\" is not a valid network name in the context of the `demia` did method")]
   InvalidNetworkName(String),
+  #[error("\"{0}\" is a reserved network name")]
+  ReservedNetworkName(String),
   #[error("\"{0}\" is not a valid country code in the context of the `demia` did method")]
   InvalidCountryCode(isocountry::CountryCodeParseErr),
   #[cfg(feature = "iota-client")]
@@ -30,9 +32,24 @@ pub enum Error {
   TokenSupplyError(#[source] iota_sdk::client::Error),
   #[error("unable to resolve a `{expected}` DID on network `{actual}`")]
   NetworkMismatch { expected: String, actual: String },
+  #[error("`{did}` is not on the network `{holder_network}` of the presentation holder")]
+  CredentialNetworkMismatch { did: String, holder_network: String },
   #[cfg(feature = "iota-client")]
   #[error("could not fetch protocol parameters")]
   ProtocolParametersError(#[source] iota_sdk::client::Error),
+  #[cfg(feature = "client")]
+  #[error("alias output mismatch: {0}")]
+  AliasOutputMismatch(&'static str),
+  #[cfg(feature = "client")]
+  #[error("alias output history: {0}")]
+  AliasOutputHistoryError(&'static str),
+  #[cfg(feature = "client")]
+  #[error("state index {requested} is beyond the current state index {current}")]
+  StateIndexOutOfRange { requested: u32, current: u32 },
+  #[error("invalid controller `{did}`: {reason}")]
+  InvalidController { did: String, reason: &'static str },
+  #[error("invalid long-form DID: {0}")]
+  InvalidLongFormDID(&'static str),
   #[error("invalid state metadata {0}")]
   InvalidStateMetadata(&'static str),
   #[cfg(feature = "revocation-bitmap")]
@@ -12,7 +12,12 @@ pub use iota_sdk::types::block;
 
 #[cfg(feature = "client")]
 pub use client::*;
+pub use credential::*;
+pub use did::country_histogram;
 pub use did::DemiaDID;
+pub use did::DemiaDIDBuilder;
+pub use did::DisplayedDemiaDID;
+pub use did::Region;
 pub use document::*;
 pub use network::NetworkName;
 pub use state_metadata::*;
@@ -22,6 +27,7 @@ pub use self::error::Result;
 
 #[cfg(feature = "client")]
 mod client;
+mod credential;
 mod did;
 mod document;
 mod error;
@@ -33,6 +33,14 @@ impl NetworkName {
     Ok(Self(name_cow))
   }
 
+  /// Creates a new [`NetworkName`] from `input` with leading and trailing whitespace removed, e.g. when it is read
+  /// from a configuration file.
+  ///
+  /// The trimmed name must pass validation, so internal whitespace and uppercase characters are still rejected.
+  pub fn normalize(input: &str) -> Result<Self> {
+    Self::try_from(input.trim().to_owned())
+  }
+
   /// Validates whether a string is a spec-compliant IOTA DID [`NetworkName`].
   pub fn validate_network_name(name: &str) -> Result<()> {
     Some(())
@@ -43,6 +51,18 @@ impl NetworkName {
       })
       .ok_or_else(|| Error::InvalidNetworkName(name.to_owned()))
   }
+
+  /// Validates whether a string is a spec-compliant IOTA DID [`NetworkName`] that is not contained in `reserved`.
+  ///
+  /// This allows deployments to forbid network names, e.g. ones colliding with the conventions of other methods,
+  /// in addition to the checks of [`NetworkName::validate_network_name`].
+  pub fn validate_network_name_with_reserved(name: &str, reserved: &[&str]) -> Result<()> {
+    Self::validate_network_name(name)?;
+    if reserved.contains(&name) {
+      return Err(Error::ReservedNetworkName(name.to_owned()));
+    }
+    Ok(())
+  }
 }
 
 impl AsRef<str> for NetworkName {
@@ -120,4 +140,40 @@ mod tests {
       assert!(NetworkName::validate_network_name(name).is_err());
     }
   }
+
+  #[test]
+  fn normalize() {
+    assert_eq!(NetworkName::normalize(" dmia ").unwrap().as_ref(), "dmia");
+    assert_eq!(NetworkName::normalize("dmia\n").unwrap().as_ref(), "dmia");
+    for name in VALID_NETWORK_NAMES {
+      assert_eq!(NetworkName::normalize(name).unwrap().as_ref(), name);
+    }
+
+    assert!(matches!(
+      NetworkName::normalize("dm ia"),
+      Err(Error::InvalidNetworkName(_))
+    ));
+    assert!(NetworkName::normalize(" Dmia ").is_err());
+    assert!(NetworkName::normalize(" \t ").is_err());
+  }
+
+  #[test]
+  fn validate_network_name_with_reserved() {
+    let reserved: [&str; 2] = ["main", "dev"];
+    for name in reserved {
+      assert!(matches!(
+        NetworkName::validate_network_name_with_reserved(name, &reserved),
+        Err(Error::ReservedNetworkName(_))
+      ));
+    }
+    for name in VALID_NETWORK_NAMES.into_iter().filter(|name| !reserved.contains(name)) {
+      assert!(NetworkName::validate_network_name_with_reserved(name, &reserved).is_ok());
+    }
+    for name in INVALID_NETWORK_NAMES {
+      assert!(matches!(
+        NetworkName::validate_network_name_with_reserved(name, &reserved),
+        Err(Error::InvalidNetworkName(_))
+      ));
+    }
+  }
 }
@@ -5,11 +5,10 @@ use core::convert::TryInto as _;
 use core::fmt::Display;
 use core::fmt::Formatter;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::convert::Infallible;
 
-use identity_verification::jose::jwk::Jwk;
 use identity_verification::jose::jws::DecodedJws;
-use identity_verification::jose::jws::Decoder;
 use identity_verification::jose::jws::JwsVerifier;
 use serde::Serialize;
 
@@ -29,6 +28,7 @@ use identity_core::crypto::Verifier;
 use serde::Serializer;
 
 use crate::document::DocumentBuilder;
+use crate::document::MethodDiff;
 use crate::error::Error;
 use crate::error::Result;
 use crate::service::Service;
@@ -39,6 +39,7 @@ use crate::verifiable::JwsVerificationOptions;
 use crate::verifiable::VerifierOptions;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
+use identity_did::DID;
 use identity_verification::MethodRef;
 use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
@@ -535,6 +536,33 @@ impl CoreDocument {
       .ok_or(Error::InvalidServiceInsertion)
   }
 
+  /// Adds a new [`Service`] of type `type_` with a single URL `endpoint`, e.g. a `LinkedDomains` service,
+  /// identified by `fragment` relative to the document's DID.
+  ///
+  /// The fragment may be given with or without its leading `#`.
+  ///
+  /// # Errors
+  ///
+  /// - [`Error::MissingIdFragment`] if `fragment` is empty.
+  /// - [`Error::InvalidService`] if `endpoint` is not a valid URL.
+  /// - [`Error::InvalidServiceInsertion`] if a service or verification method with the same identifier already
+  ///   exists.
+  pub fn insert_service_entry(&mut self, fragment: &str, type_: impl Into<String>, endpoint: &str) -> Result<()> {
+    let fragment: &str = fragment.strip_prefix('#').unwrap_or(fragment);
+    if fragment.is_empty() {
+      return Err(Error::MissingIdFragment);
+    }
+    let id: DIDUrl = self.id().to_url().join(format!("#{fragment}"))?;
+    let endpoint: Url = Url::parse(endpoint).map_err(|_| Error::InvalidService("invalid service endpoint url"))?;
+
+    let service: Service = Service::builder(Object::new())
+      .id(id)
+      .type_(type_)
+      .service_endpoint(endpoint)
+      .build()?;
+    self.insert_service(service)
+  }
+
   /// Removes and returns a [`Service`] from the document if it exists.
   pub fn remove_service(&mut self, id: &DIDUrl) -> Option<Service> {
     self.data.service.remove(id)
@@ -657,6 +685,18 @@ impl CoreDocument {
     }
   }
 
+  /// Returns the fragments of all embedded verification methods in the DID Document, e.g. for display purposes.
+  ///
+  /// Method references in verification relationships are excluded, since their fragment is already returned for
+  /// the method they refer to if it belongs to this document.
+  pub fn method_fragments(&self) -> Vec<String> {
+    self
+      .all_methods()
+      .filter_map(|method| method.id().fragment())
+      .map(ToOwned::to_owned)
+      .collect()
+  }
+
   /// Returns an iterator over all embedded verification methods in the DID Document.
   ///
   /// This excludes verification methods that are referenced by the DID Document.
@@ -785,6 +825,19 @@ impl CoreDocument {
     self.service().query(query.into())
   }
 
+  /// Dereferences a DID URL such as `did:example:123#messaging` to the endpoint URLs of the [`Service`] it
+  /// identifies, see [`ServiceEndpoint::urls`](crate::service::ServiceEndpoint::urls).
+  ///
+  /// Returns `None` if the document contains no service matching the `query`.
+  pub fn dereference_service_endpoint<'query, 'me, Q>(&'me self, query: Q) -> Option<Vec<&'me Url>>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    self
+      .resolve_service(query)
+      .map(|service| service.service_endpoint().urls())
+  }
+
   #[doc(hidden)]
   pub fn resolve_method_ref<'a>(&'a self, method_ref: &'a MethodRef) -> Option<&'a VerificationMethod> {
     match method_ref {
@@ -1037,6 +1090,86 @@ impl Display for CoreDocument {
   }
 }
 
+// =============================================================================
+// Integrity
+// =============================================================================
+impl CoreDocument {
+  /// Checks the internal consistency of the document, e.g. before publishing a hand-edited document.
+  ///
+  /// The following conditions are checked:
+  /// - Every method reference in a verification relationship to a method of the document's own DID resolves to a
+  ///   verification method of the document. References to methods of other DIDs are not checked.
+  /// - The ids of all verification methods (embedded or general purpose) and services are unique across the document.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`Error::InvalidDocument`] describing the first inconsistency encountered.
+  pub fn self_check(&self) -> Result<()> {
+    if self
+      .verification_relationships()
+      .filter(|method_ref| method_ref.is_referred() && method_ref.id().did() == self.id())
+      .any(|method_ref| self.resolve_method_ref(method_ref).is_none())
+    {
+      return Err(Error::InvalidDocument(
+        "method reference does not resolve to a verification method",
+        None,
+      ));
+    }
+
+    let mut ids: HashSet<&DIDUrl> = HashSet::new();
+    for id in self
+      .all_methods()
+      .map(VerificationMethod::id)
+      .chain(self.service().iter().map(Service::id))
+    {
+      if !ids.insert(id) {
+        return Err(Error::InvalidDocument(
+          "id is shared by multiple verification methods or services",
+          None,
+        ));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Compares the verification methods of this document with those of `other`, e.g. to audit a key rotation.
+  ///
+  /// Methods are matched by fragment, `self` being treated as the older and `other` as the newer version. A method
+  /// is considered changed if any of its properties differ, such as its key material after a rotation. Methods
+  /// without a fragment are ignored.
+  pub fn method_diff(&self, other: &CoreDocument) -> MethodDiff {
+    fn by_fragment(document: &CoreDocument) -> HashMap<&str, &VerificationMethod> {
+      document
+        .all_methods()
+        .filter_map(|method| method.id().fragment().map(|fragment| (fragment, method)))
+        .collect()
+    }
+
+    let old: HashMap<&str, &VerificationMethod> = by_fragment(self);
+    let new: HashMap<&str, &VerificationMethod> = by_fragment(other);
+
+    let mut diff: MethodDiff = MethodDiff::default();
+    for (fragment, method) in old.iter() {
+      match new.get(fragment) {
+        Some(new_method) if new_method != method => {
+          diff.changed.insert((*fragment).to_owned());
+        }
+        Some(_) => {}
+        None => {
+          diff.removed.insert((*fragment).to_owned());
+        }
+      }
+    }
+    diff.added = new
+      .keys()
+      .filter(|fragment| !old.contains_key(*fragment))
+      .map(|fragment| (*fragment).to_owned())
+      .collect();
+    diff
+  }
+}
+
 // =============================================================================
 // JWS verification
 // =============================================================================
@@ -1048,6 +1181,8 @@ impl CoreDocument {
   /// take place.
   /// - The JWS must be encoded according to the JWS compact serialization.
   /// - The `kid` value in the protected header must be an identifier of a verification method in this DID document.
+  ///
+  /// See [`verify_jws`](crate::verifiable::verify_jws) to also obtain the verification method that verified the JWS.
   //
   // NOTE: This is tested in `identity_storage` and `identity_credential`.
   // TODO: Consider including some unit tests for this method in this crate.
@@ -1058,32 +1193,8 @@ impl CoreDocument {
     signature_verifier: &T,
     options: &JwsVerificationOptions,
   ) -> Result<DecodedJws<'jws>> {
-    let validation_item = Decoder::new()
-      .decode_compact_serialization(jws.as_bytes(), detached_payload)
-      .map_err(Error::JwsVerificationError)?;
-
-    let nonce: Option<&str> = options.nonce.as_deref();
-    // Validate the nonce
-    if validation_item.nonce() != nonce {
-      return Err(Error::JwsVerificationError(
-        identity_verification::jose::error::Error::InvalidParam("invalid nonce value"),
-      ));
-    }
-
-    let kid = validation_item.kid().ok_or(Error::JwsVerificationError(
-      identity_verification::jose::error::Error::InvalidParam("missing kid value"),
-    ))?;
-
-    let public_key: &Jwk = self
-      .resolve_method(kid, options.method_scope)
-      .ok_or(Error::MethodNotFound)?
-      .data()
-      .try_public_key_jwk()
-      .map_err(Error::InvalidKeyData)?;
-
-    validation_item
-      .verify(signature_verifier, public_key)
-      .map_err(Error::JwsVerificationError)
+    crate::verifiable::verify_jws(jws, detached_payload, self, signature_verifier, options)
+      .map(|verified| verified.decoded)
   }
 }
 
@@ -1091,7 +1202,6 @@ impl CoreDocument {
 mod tests {
   use identity_core::convert::FromJson;
   use identity_core::convert::ToJson;
-  use identity_did::DID;
 
   use crate::service::ServiceBuilder;
   use identity_verification::MethodBuilder;
@@ -1516,6 +1626,178 @@ mod tests {
       .is_none());
   }
 
+  #[test]
+  fn test_insert_service_entry() {
+    let mut document: CoreDocument = document();
+    document
+      .insert_service_entry("#linked-domain", "LinkedDomains", "https://example.com/")
+      .unwrap();
+
+    let service: &Service = document.resolve_service("#linked-domain").unwrap();
+    assert_eq!(service.id(), &document.id().to_url().join("#linked-domain").unwrap());
+    assert_eq!(service.type_().as_slice(), ["LinkedDomains".to_owned()]);
+    assert_eq!(
+      service.service_endpoint(),
+      &Url::parse("https://example.com/").unwrap().into()
+    );
+
+    // Duplicate fragments are rejected, with or without the leading `#`.
+    assert!(matches!(
+      document.insert_service_entry("linked-domain", "LinkedDomains", "https://other.example.com/"),
+      Err(Error::InvalidServiceInsertion)
+    ));
+    assert!(matches!(
+      document.insert_service_entry("#key-1", "LinkedDomains", "https://example.com/"),
+      Err(Error::InvalidServiceInsertion)
+    ));
+    assert!(matches!(
+      document.insert_service_entry("#", "LinkedDomains", "https://example.com/"),
+      Err(Error::MissingIdFragment)
+    ));
+    assert!(matches!(
+      document.insert_service_entry("#messaging", "DIDCommMessaging", "not a url"),
+      Err(Error::InvalidService(_))
+    ));
+    assert_eq!(document.service().len(), 1);
+  }
+
+  #[test]
+  fn test_dereference_service_endpoint() {
+    let mut document: CoreDocument = document();
+    document
+      .insert_service_entry("#linked-domain", "LinkedDomains", "https://example.com/")
+      .unwrap();
+    let urls: OrderedSet<Url> = ["https://one.example.com/", "https://two.example.com/"]
+      .into_iter()
+      .map(|url| Url::parse(url).unwrap())
+      .collect();
+    let messaging: Service = ServiceBuilder::default()
+      .id(document.id().to_url().join("#messaging").unwrap())
+      .type_("DIDCommMessaging")
+      .service_endpoint(urls.clone())
+      .build()
+      .unwrap();
+    document.insert_service(messaging).unwrap();
+
+    assert_eq!(
+      document.dereference_service_endpoint("#linked-domain").unwrap(),
+      [&Url::parse("https://example.com/").unwrap()]
+    );
+    let messaging_url: DIDUrl = document.id().to_url().join("#messaging").unwrap();
+    assert_eq!(
+      document.dereference_service_endpoint(&messaging_url).unwrap(),
+      urls.iter().collect::<Vec<&Url>>()
+    );
+    assert!(document.dereference_service_endpoint("#unknown").is_none());
+  }
+
+  #[test]
+  fn test_self_check() {
+    let controller: CoreDID = controller();
+    let mut document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(controller.clone())
+      .verification_method(method(&controller, "#key-1"))
+      .authentication(method(&controller, "#auth-key"))
+      .authentication(controller.to_url().join("#key-1").unwrap())
+      .assertion_method(controller.to_url().join("#key-1").unwrap())
+      .build()
+      .unwrap();
+    assert!(document.self_check().is_ok());
+
+    // A service sharing the fragment of a method.
+    let service: Service = Service::builder(Default::default())
+      .id(controller.to_url().join("#key-1").unwrap())
+      .type_("LinkedDomains")
+      .service_endpoint(Url::parse("https://example.com/").unwrap())
+      .build()
+      .unwrap();
+    document.service_mut_unchecked().append(service);
+    assert!(matches!(document.self_check(), Err(Error::InvalidDocument(_, None))));
+  }
+
+  #[test]
+  fn test_self_check_dangling_reference() {
+    let controller: CoreDID = controller();
+    let document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(controller.clone())
+      .verification_method(method(&controller, "#key-1"))
+      .key_agreement(controller.to_url().join("#key-2").unwrap())
+      .build()
+      .unwrap();
+    assert!(matches!(document.self_check(), Err(Error::InvalidDocument(_, None))));
+  }
+
+  #[test]
+  fn test_self_check_external_reference() {
+    // References to methods of other DIDs cannot be resolved within the document and are not checked.
+    let controller: CoreDID = controller();
+    let other_controller: CoreDID = "did:example:5678".parse().unwrap();
+    let document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(controller.clone())
+      .verification_method(method(&controller, "#key-1"))
+      .authentication(other_controller.to_url().join("#key-1").unwrap())
+      .build()
+      .unwrap();
+    assert!(document.self_check().is_ok());
+  }
+
+  #[test]
+  fn test_method_fragments() {
+    let fragments: Vec<String> = document().method_fragments();
+    // The references to `#key-3` and `#key-4` in verification relationships are not included.
+    assert_eq!(fragments, ["key-1", "key-2", "key-3", "auth-key"]);
+  }
+
+  #[test]
+  fn test_method_diff() {
+    let controller: CoreDID = controller();
+    let before: CoreDocument = CoreDocument::builder(Default::default())
+      .id(controller.clone())
+      .verification_method(method(&controller, "#key-1"))
+      .verification_method(method(&controller, "#key-2"))
+      .authentication(method(&controller, "#auth-key"))
+      .build()
+      .unwrap();
+    assert!(before.method_diff(&before).is_empty());
+
+    // Rotate `#key-1`, replace `#key-2` with `#key-3` and leave `#auth-key` untouched.
+    let mut after: CoreDocument = before.clone();
+    *after.resolve_method_mut("#key-1", None).unwrap().data_mut() = MethodData::new_multibase(b"rotated");
+    after
+      .remove_method(&controller.to_url().join("#key-2").unwrap())
+      .unwrap();
+    after
+      .insert_method(method(&controller, "#key-3"), MethodScope::VerificationMethod)
+      .unwrap();
+
+    let diff: MethodDiff = before.method_diff(&after);
+    assert_eq!(diff.added.iter().collect::<Vec<_>>(), ["key-3"]);
+    assert_eq!(diff.removed.iter().collect::<Vec<_>>(), ["key-2"]);
+    assert_eq!(diff.changed.iter().collect::<Vec<_>>(), ["key-1"]);
+
+    // Diffing in the opposite direction swaps added and removed methods.
+    let reverse: MethodDiff = after.method_diff(&before);
+    assert_eq!(reverse.added, diff.removed);
+    assert_eq!(reverse.removed, diff.added);
+    assert_eq!(reverse.changed, diff.changed);
+  }
+
+  #[test]
+  fn test_self_check_shared_fragment() {
+    // Methods of different controllers are distinct, even though they share the same fragment.
+    let controller: CoreDID = controller();
+    let other_controller: CoreDID = "did:example:5678".parse().unwrap();
+    let mut document: CoreDocument = CoreDocument::builder(Default::default())
+      .id(controller.clone())
+      .verification_method(method(&controller, "#key-1"))
+      .build()
+      .unwrap();
+    document
+      .insert_method(method(&other_controller, "#key-1"), MethodScope::VerificationMethod)
+      .unwrap();
+    assert!(document.self_check().is_ok());
+  }
+
   #[test]
   fn serialize_deserialize_roundtrip() {
     let document: CoreDocument = document();
@@ -0,0 +1,24 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+
+/// The differences between the verification methods of two versions of a DID Document, identified by fragment.
+///
+/// See [`CoreDocument::method_diff`](crate::document::CoreDocument::method_diff).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MethodDiff {
+  /// Fragments of methods only present in the newer document.
+  pub added: BTreeSet<String>,
+  /// Fragments of methods only present in the older document.
+  pub removed: BTreeSet<String>,
+  /// Fragments of methods present in both documents whose properties differ.
+  pub changed: BTreeSet<String>,
+}
+
+impl MethodDiff {
+  /// Returns `true` if no method was added, removed or changed.
+  pub fn is_empty(&self) -> bool {
+    self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+  }
+}
@@ -7,8 +7,10 @@
 
 pub use self::builder::DocumentBuilder;
 pub use self::core_document::CoreDocument;
+pub use self::method_diff::MethodDiff;
 
 #[cfg(feature = "diff")]
 pub(crate) use core_document::CoreDocumentData;
 mod builder;
 mod core_document;
+mod method_diff;
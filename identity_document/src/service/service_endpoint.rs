@@ -23,6 +23,17 @@ pub enum ServiceEndpoint {
   // TODO: enforce set/map is non-empty?
 }
 
+impl ServiceEndpoint {
+  /// Returns all URLs of the endpoint in order, flattening the sets of a map endpoint.
+  pub fn urls(&self) -> Vec<&Url> {
+    match self {
+      ServiceEndpoint::One(url) => vec![url],
+      ServiceEndpoint::Set(set) => set.iter().collect(),
+      ServiceEndpoint::Map(map) => map.values().flat_map(|set| set.iter()).collect(),
+    }
+  }
+}
+
 impl From<Url> for ServiceEndpoint {
   fn from(url: Url) -> Self {
     ServiceEndpoint::One(url)
@@ -0,0 +1,143 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jws::DecodedJws;
+use identity_verification::jose::jws::Decoder;
+use identity_verification::jose::jws::JwsValidationItem;
+use identity_verification::jose::jws::JwsVerifier;
+use identity_verification::jose::jws::SignatureVerificationErrorKind;
+use identity_verification::VerificationMethod;
+
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::error::Result;
+use crate::verifiable::JwsVerificationOptions;
+
+/// A JWS whose signature was verified by a verification method of a DID document, see [`verify_jws`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct VerifiedJws<'doc, 'jws> {
+  /// The decoded headers and payload of the JWS.
+  pub decoded: DecodedJws<'jws>,
+  /// The verification method whose public key verified the signature.
+  pub method: &'doc VerificationMethod,
+}
+
+/// Decodes and verifies the provided JWS against the verification methods of `document`, without applying any
+/// credential or presentation semantics to its payload.
+///
+/// A detached payload must be passed as `detached_payload`, otherwise the payload is taken from the JWS. It is passed
+/// as it appears in the signing input: base64url-encoded, unless the protected header sets `b64` to `false` (see
+/// [RFC 7797](https://www.rfc-editor.org/rfc/rfc7797)), in which case the raw payload is passed.
+/// The same conditions as for [`CoreDocument::verify_jws`] apply, on success the decoded JWS is returned together with
+/// the verification method referenced by its `kid`.
+pub fn verify_jws<'doc, 'jws, T: JwsVerifier>(
+  jws: &'jws str,
+  detached_payload: Option<&'jws [u8]>,
+  document: &'doc CoreDocument,
+  signature_verifier: &T,
+  options: &JwsVerificationOptions,
+) -> Result<VerifiedJws<'doc, 'jws>> {
+  let validation_item: JwsValidationItem<'jws> = decode_jws(jws, detached_payload, options)?;
+
+  let kid = validation_item.kid().ok_or(Error::JwsVerificationError(
+    identity_verification::jose::error::Error::InvalidParam("missing kid value"),
+  ))?;
+
+  let method: &VerificationMethod = document
+    .resolve_method(kid, options.method_scope)
+    .ok_or(Error::MethodNotFound)?;
+  let public_key: &Jwk = method.data().try_public_key_jwk().map_err(Error::InvalidKeyData)?;
+
+  let decoded: DecodedJws<'jws> = validation_item
+    .verify(signature_verifier, public_key)
+    .map_err(Error::JwsVerificationError)?;
+
+  Ok(VerifiedJws { decoded, method })
+}
+
+/// Decodes and verifies the provided JWS like [`verify_jws`], but tries every verification method of `document` in
+/// the [`method_scope`](JwsVerificationOptions::method_scope) of `options` instead of only the one referenced by the
+/// `kid` of the JWS.
+///
+/// The method referenced by the `kid`, if any, is tried first and the remaining methods in document order. Methods
+/// whose key is not a JWK are skipped. On success the decoded JWS is returned together with the first method whose
+/// public key verified the signature.
+///
+/// # Errors
+///
+/// Fails if the nonce or algorithm of the JWS are rejected by `options`, with [`Error::MethodNotFound`] if no method
+/// with a JWK is in scope, and with the error of the last tried method if none of them verified the signature.
+pub fn verify_jws_with_any_method<'doc, 'jws, T: JwsVerifier>(
+  jws: &'jws str,
+  detached_payload: Option<&'jws [u8]>,
+  document: &'doc CoreDocument,
+  signature_verifier: &T,
+  options: &JwsVerificationOptions,
+) -> Result<VerifiedJws<'doc, 'jws>> {
+  let validation_item: JwsValidationItem<'jws> = decode_jws(jws, detached_payload, options)?;
+
+  let referenced: Option<&VerificationMethod> = validation_item
+    .kid()
+    .and_then(|kid| document.resolve_method(kid, options.method_scope));
+  let candidates = referenced.into_iter().chain(
+    document
+      .methods(options.method_scope)
+      .into_iter()
+      .filter(|method| Some(method.id()) != referenced.map(VerificationMethod::id)),
+  );
+
+  let mut validation_item: Option<JwsValidationItem<'jws>> = Some(validation_item);
+  let mut last_error: Option<Error> = None;
+  for method in candidates {
+    let Ok(public_key) = method.data().try_public_key_jwk() else {
+      continue;
+    };
+    // Verification consumes the validation item, so the JWS is decoded again for every further attempt.
+    let item: JwsValidationItem<'jws> = match validation_item.take() {
+      Some(item) => item,
+      None => decode_jws(jws, detached_payload, options)?,
+    };
+    match item.verify(signature_verifier, public_key) {
+      Ok(decoded) => return Ok(VerifiedJws { decoded, method }),
+      Err(err) => last_error = Some(Error::JwsVerificationError(err)),
+    }
+  }
+
+  Err(last_error.unwrap_or(Error::MethodNotFound))
+}
+
+/// Decodes the provided JWS and checks its nonce and algorithm against `options`.
+fn decode_jws<'jws>(
+  jws: &'jws str,
+  detached_payload: Option<&'jws [u8]>,
+  options: &JwsVerificationOptions,
+) -> Result<JwsValidationItem<'jws>> {
+  let validation_item: JwsValidationItem<'jws> = Decoder::new()
+    .decode_compact_serialization(jws.as_bytes(), detached_payload)
+    .map_err(Error::JwsVerificationError)?;
+
+  let nonce: Option<&str> = options.nonce.as_deref();
+  // Validate the nonce
+  if validation_item.nonce() != nonce {
+    return Err(Error::JwsVerificationError(
+      identity_verification::jose::error::Error::InvalidParam("invalid nonce value"),
+    ));
+  }
+
+  // Reject algorithms the verifier does not accept before looking up the key.
+  if !validation_item
+    .alg()
+    .map(|alg| options.is_accepted_algorithm(alg))
+    .unwrap_or(false)
+  {
+    return Err(Error::JwsVerificationError(
+      identity_verification::jose::error::Error::SignatureVerificationError(
+        SignatureVerificationErrorKind::UnsupportedAlg.into(),
+      ),
+    ));
+  }
+
+  Ok(validation_item)
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::MethodScope;
 
 /// Holds additional options for verifying a JWS with
@@ -14,9 +15,31 @@ pub struct JwsVerificationOptions {
   pub nonce: Option<String>,
   /// Verify the signing verification method relation matches this.
   pub method_scope: Option<MethodScope>,
+  /// The algorithms the JWS may be signed with, [`Self::DEFAULT_ACCEPTED_ALGORITHMS`] if not set.
+  ///
+  /// A JWS with `alg` set to `none` is always rejected.
+  pub accepted_algorithms: Option<Vec<JwsAlgorithm>>,
 }
 
 impl JwsVerificationOptions {
+  /// The algorithms accepted if [`Self::accepted_algorithms`] is not set: all asymmetric signature algorithms.
+  ///
+  /// The HMAC based algorithms are excluded to prevent algorithm confusion, in which a public key would be used as
+  /// the HMAC secret.
+  pub const DEFAULT_ACCEPTED_ALGORITHMS: &'static [JwsAlgorithm] = &[
+    JwsAlgorithm::RS256,
+    JwsAlgorithm::RS384,
+    JwsAlgorithm::RS512,
+    JwsAlgorithm::PS256,
+    JwsAlgorithm::PS384,
+    JwsAlgorithm::PS512,
+    JwsAlgorithm::ES256,
+    JwsAlgorithm::ES384,
+    JwsAlgorithm::ES512,
+    JwsAlgorithm::ES256K,
+    JwsAlgorithm::EdDSA,
+  ];
+
   /// Set the expected value for the `nonce` parameter of the protected header.
   pub fn nonce(mut self, value: impl Into<String>) -> Self {
     self.nonce = Some(value.into());
@@ -28,4 +51,43 @@ impl JwsVerificationOptions {
     self.method_scope = Some(value);
     self
   }
+
+  /// Set the algorithms the JWS may be signed with.
+  pub fn accepted_algorithms(mut self, value: impl IntoIterator<Item = JwsAlgorithm>) -> Self {
+    self.accepted_algorithms = Some(value.into_iter().collect());
+    self
+  }
+
+  /// Returns whether a JWS signed with `alg` is accepted according to [`Self::accepted_algorithms`].
+  ///
+  /// `none` is never accepted.
+  pub fn is_accepted_algorithm(&self, alg: JwsAlgorithm) -> bool {
+    let accepted_algorithms: &[JwsAlgorithm] = self
+      .accepted_algorithms
+      .as_deref()
+      .unwrap_or(Self::DEFAULT_ACCEPTED_ALGORITHMS);
+    alg != JwsAlgorithm::NONE && accepted_algorithms.contains(&alg)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_verification::jose::jws::JwsAlgorithm;
+
+  use super::JwsVerificationOptions;
+
+  #[test]
+  fn test_accepted_algorithms() {
+    let options: JwsVerificationOptions = JwsVerificationOptions::default();
+    assert!(options.is_accepted_algorithm(JwsAlgorithm::EdDSA));
+    assert!(options.is_accepted_algorithm(JwsAlgorithm::ES256K));
+    assert!(!options.is_accepted_algorithm(JwsAlgorithm::HS256));
+    assert!(!options.is_accepted_algorithm(JwsAlgorithm::NONE));
+
+    let options: JwsVerificationOptions =
+      JwsVerificationOptions::default().accepted_algorithms([JwsAlgorithm::ES256, JwsAlgorithm::NONE]);
+    assert!(options.is_accepted_algorithm(JwsAlgorithm::ES256));
+    assert!(!options.is_accepted_algorithm(JwsAlgorithm::EdDSA));
+    assert!(!options.is_accepted_algorithm(JwsAlgorithm::NONE));
+  }
 }
@@ -4,11 +4,15 @@
 //! Additional functionality for DID assisted digital signatures.
 
 pub use self::document_signer::DocumentSigner;
+pub use self::jws_verification::verify_jws;
+pub use self::jws_verification::verify_jws_with_any_method;
+pub use self::jws_verification::VerifiedJws;
 pub use self::jws_verification_options::JwsVerificationOptions;
 pub use self::properties::VerifiableProperties;
 pub use self::verifier_options::VerifierOptions;
 
 mod document_signer;
+mod jws_verification;
 mod jws_verification_options;
 mod properties;
 mod verifier_options;
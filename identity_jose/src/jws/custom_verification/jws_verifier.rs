@@ -6,6 +6,8 @@ use crate::jwk::Jwk;
 use crate::jws::JwsAlgorithm;
 #[cfg(any(feature = "eddsa", doc))]
 pub use eddsa_verifier::*;
+#[cfg(feature = "es256k")]
+pub use es256k_verifier::*;
 /// Input a [`JwsVerifier`] verifies.
 pub struct VerificationInput {
   /// The `alg` parsed from the protected header.
@@ -95,8 +97,8 @@ mod eddsa_verifier {
 
   impl EdDSAJwsVerifier {
     /// Verify a JWS signature secured with the [`JwsAlgorithm::EdDSA`](crate::jws::JwsAlgorithm::EdDSA) algorithm.
-    /// Only the [`EdCurve::Ed25519`] variant is supported for now. This associated method is only available when the
-    /// `eddsa` feature is enabled.
+    /// The [`EdCurve::Ed25519`] variant is always supported, while [`EdCurve::Ed448`] additionally requires the
+    /// `ed448` feature. This associated method is only available when the `eddsa` feature is enabled.
     ///
     /// This function is useful when one is building a [`JwsVerifier`] that handles the
     /// [`JwsAlgorithm::EdDSA`](crate::jws::JwsAlgorithm::EdDSA) in the same manner as the [`EdDSAJwsVerifier`]
@@ -106,32 +108,20 @@ mod eddsa_verifier {
     /// This function does not check whether `alg = EdDSA` in the protected header. Callers are expected to assert this
     /// prior to calling the function.
     pub fn verify_eddsa(input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
-      // Obtain an Ed25519 public key
-
       let params: &JwkParamsOkp = public_key
         .try_okp_params()
         .map_err(|_| SignatureVerificationErrorKind::UnsupportedKeyType)?;
 
-      if params
-        .try_ed_curve()
-        .ok()
-        .filter(|curve_param| *curve_param == EdCurve::Ed25519)
-        .is_none()
-      {
-        return Err(SignatureVerificationErrorKind::UnsupportedKeyParams.into());
+      match params.try_ed_curve() {
+        Ok(EdCurve::Ed25519) => Self::verify_ed25519(input, params),
+        #[cfg(feature = "ed448")]
+        Ok(EdCurve::Ed448) => Self::verify_ed448(input, params),
+        _ => Err(SignatureVerificationErrorKind::UnsupportedKeyParams.into()),
       }
+    }
 
-      let pk: [u8; crypto::signatures::ed25519::PUBLIC_KEY_LENGTH] = crate::jwu::decode_b64(params.x.as_str())
-        .map_err(|_| {
-          SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
-            .with_custom_message("could not decode x parameter from jwk")
-        })
-        .and_then(|value| {
-          TryInto::try_into(value).map_err(|_| {
-            SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
-              .with_custom_message("invalid public key length")
-          })
-        })?;
+    fn verify_ed25519(input: VerificationInput, params: &JwkParamsOkp) -> Result<(), SignatureVerificationError> {
+      let pk: [u8; crypto::signatures::ed25519::PUBLIC_KEY_LENGTH] = decode_public_key(params)?;
 
       let public_key_ed25519 = crypto::signatures::ed25519::PublicKey::try_from(pk).map_err(|err| {
         SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
@@ -149,6 +139,41 @@ mod eddsa_verifier {
         Err(SignatureVerificationErrorKind::InvalidSignature.into())
       }
     }
+
+    #[cfg(feature = "ed448")]
+    fn verify_ed448(input: VerificationInput, params: &JwkParamsOkp) -> Result<(), SignatureVerificationError> {
+      use ed448_goldilocks_plus::Signature;
+      use ed448_goldilocks_plus::VerifyingKey;
+      use ed448_goldilocks_plus::PUBLIC_KEY_LENGTH;
+
+      let pk: [u8; PUBLIC_KEY_LENGTH] = decode_public_key(params)?;
+
+      let public_key_ed448 = VerifyingKey::from_bytes(&pk).map_err(|err| {
+        SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+      })?;
+
+      let signature = Signature::try_from(input.decoded_signature.deref())
+        .map_err(|_| SignatureVerificationErrorKind::InvalidSignature)?;
+
+      public_key_ed448
+        .verify_raw(&signature, &input.signing_input)
+        .map_err(|_| SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature))
+    }
+  }
+
+  /// Decodes the `x` parameter of `params` into a public key of length `N`.
+  fn decode_public_key<const N: usize>(params: &JwkParamsOkp) -> Result<[u8; N], SignatureVerificationError> {
+    crate::jwu::decode_b64(params.x.as_str())
+      .map_err(|_| {
+        SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
+          .with_custom_message("could not decode x parameter from jwk")
+      })
+      .and_then(|value| {
+        TryInto::try_into(value).map_err(|_| {
+          SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
+            .with_custom_message("invalid public key length")
+        })
+      })
   }
 
   impl Default for EdDSAJwsVerifier {
@@ -160,9 +185,9 @@ mod eddsa_verifier {
   }
 
   impl JwsVerifier for EdDSAJwsVerifier {
-    /// This implements verification of jws signatures signed with the `EdDSA` algorithm. For now
-    /// [`EdDSAJwsVerifier::verify`](EdDSAJwsVerifier::verify) can only handle the `alg = EdDSA` with
-    /// `crv = Ed25519`, but the implementation may also support `crv = Ed448` in the future.
+    /// This implements verification of jws signatures signed with the `EdDSA` algorithm.
+    /// [`EdDSAJwsVerifier::verify`](EdDSAJwsVerifier::verify) handles `alg = EdDSA` with `crv = Ed25519`, and
+    /// additionally with `crv = Ed448` when the `ed448` feature is enabled.
     fn verify(
       &self,
       input: VerificationInput,
@@ -176,3 +201,108 @@ mod eddsa_verifier {
     }
   }
 }
+
+#[cfg(feature = "es256k")]
+mod es256k_verifier {
+  use k256::ecdsa::signature::Verifier;
+  use k256::ecdsa::Signature;
+  use k256::ecdsa::VerifyingKey;
+
+  use super::*;
+  use crate::jwk::EcCurve;
+  use crate::jwk::JwkParamsEc;
+  use crate::jws::SignatureVerificationErrorKind;
+
+  /// Length of the `x` and `y` coordinates of a secp256k1 public key.
+  const COORDINATE_LENGTH: usize = 32;
+
+  /// An implementor of [`JwsVerifier`] that can handle the
+  /// [`JwsAlgorithm::ES256K`](crate::jws::JwsAlgorithm::ES256K) algorithm.
+  ///
+  /// See [`Self::verify`](ES256KJwsVerifier::verify).
+  ///
+  /// NOTE: This type can only be constructed when the `es256k` feature is enabled.
+  #[derive(Debug)]
+  #[non_exhaustive]
+  pub struct ES256KJwsVerifier;
+
+  impl ES256KJwsVerifier {
+    /// Verify a JWS signature secured with the [`JwsAlgorithm::ES256K`](crate::jws::JwsAlgorithm::ES256K) algorithm.
+    ///
+    /// The signature is expected to be the concatenation of the `r` and `s` values as defined in
+    /// [RFC 8812 section 3.2](https://www.rfc-editor.org/rfc/rfc8812#section-3.2). Signatures with a high `s` value
+    /// are rejected to prevent signature malleability.
+    ///
+    /// # Warning
+    /// This function does not check whether `alg = ES256K` in the protected header. Callers are expected to assert
+    /// this prior to calling the function.
+    pub fn verify_es256k(input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+      let params: &JwkParamsEc = public_key
+        .try_ec_params()
+        .map_err(|_| SignatureVerificationErrorKind::UnsupportedKeyType)?;
+
+      if params
+        .try_ec_curve()
+        .ok()
+        .filter(|curve_param| *curve_param == EcCurve::Secp256K1)
+        .is_none()
+      {
+        return Err(SignatureVerificationErrorKind::UnsupportedKeyParams.into());
+      }
+
+      let decode_coordinate = |coordinate: &str| -> Result<Vec<u8>, SignatureVerificationError> {
+        crate::jwu::decode_b64(coordinate)
+          .ok()
+          .filter(|value| value.len() == COORDINATE_LENGTH)
+          .ok_or_else(|| {
+            SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
+              .with_custom_message("could not decode coordinates from jwk")
+          })
+      };
+      let sec1_point: Vec<u8> = [
+        &[0x04][..],
+        &decode_coordinate(params.x.as_str())?,
+        &decode_coordinate(params.y.as_str())?,
+      ]
+      .concat();
+
+      let verifying_key: VerifyingKey = VerifyingKey::from_sec1_bytes(&sec1_point).map_err(|err| {
+        SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+      })?;
+
+      let signature: Signature = Signature::from_slice(&input.decoded_signature)
+        .map_err(|_| SignatureVerificationErrorKind::InvalidSignature)?;
+      if signature.normalize_s().is_some() {
+        return Err(
+          SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature)
+            .with_custom_message("signatures with a high s value are not accepted"),
+        );
+      }
+
+      verifying_key
+        .verify(&input.signing_input, &signature)
+        .map_err(|_| SignatureVerificationErrorKind::InvalidSignature.into())
+    }
+  }
+
+  impl Default for ES256KJwsVerifier {
+    /// Constructs a [`ES256KJwsVerifier`]. This is only available when the `es256k` feature is set.
+    fn default() -> Self {
+      Self
+    }
+  }
+
+  impl JwsVerifier for ES256KJwsVerifier {
+    /// This implements verification of jws signatures signed with the `ES256K` algorithm.
+    fn verify(
+      &self,
+      input: VerificationInput,
+      public_key: &Jwk,
+    ) -> std::result::Result<(), SignatureVerificationError> {
+      match input.alg {
+        JwsAlgorithm::ES256K => ES256KJwsVerifier::verify_es256k(input, public_key),
+        _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+      }
+    }
+  }
+}
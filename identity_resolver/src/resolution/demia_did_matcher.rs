@@ -0,0 +1,110 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+
+use identity_demia_core::DemiaDID;
+use identity_did::Error as DIDError;
+
+/// Matches [`DemiaDID`]s by country and network, e.g. to route the DIDs of a development network to a
+/// development node (see [`Resolver::attach_demia_route`](crate::Resolver::attach_demia_route)).
+///
+/// A component that is not set matches any value. The string representation of a matcher is
+/// `did:demia:<country>:<network>`, where `*` is the wildcard.
+///
+/// # Example
+/// ```
+/// # use identity_resolver::DemiaDIDMatcher;
+/// # use identity_demia_core::DemiaDID;
+/// let dev: DemiaDIDMatcher = "did:demia:*:dev".parse().unwrap();
+/// let did: DemiaDID =
+///   DemiaDID::parse("did:demia:usa:dev:0x0000000000000000000000000000000000000000000000000000000000000000").unwrap();
+/// assert!(dev.matches(&did));
+/// assert!(!DemiaDIDMatcher::any().network("main").matches(&did));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DemiaDIDMatcher {
+  country: Option<String>,
+  network: Option<String>,
+}
+
+impl DemiaDIDMatcher {
+  const WILDCARD: &'static str = "*";
+
+  /// Creates a matcher matching every [`DemiaDID`].
+  pub fn any() -> Self {
+    Self::default()
+  }
+
+  /// Restricts the matcher to DIDs of the given country, e.g. `"usa"`.
+  #[must_use]
+  pub fn country(mut self, country: impl Into<String>) -> Self {
+    self.country = Some(country.into().to_ascii_lowercase());
+    self
+  }
+
+  /// Restricts the matcher to DIDs of the given network, e.g. `"dev"`.
+  #[must_use]
+  pub fn network(mut self, network: impl Into<String>) -> Self {
+    self.network = Some(network.into().to_ascii_lowercase());
+    self
+  }
+
+  /// Parses a matcher of the form `did:demia:<country>:<network>`, where either component may be the wildcard `*`.
+  /// Trailing components can be omitted, e.g. `did:demia:usa` matches all networks of the country `usa`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if `pattern` is not a Demia DID pattern or has too many components.
+  pub fn parse(pattern: &str) -> Result<Self, DIDError> {
+    let mut components = pattern.split(':');
+    if components.next() != Some(DemiaDID::SCHEME) {
+      return Err(DIDError::InvalidScheme);
+    }
+    if components.next() != Some(DemiaDID::METHOD) {
+      return Err(DIDError::InvalidMethodName);
+    }
+
+    let mut matcher: Self = Self::any();
+    if let Some(country) = components.next().filter(|country| *country != Self::WILDCARD) {
+      matcher = matcher.country(country);
+    }
+    if let Some(network) = components.next().filter(|network| *network != Self::WILDCARD) {
+      matcher = matcher.network(network);
+    }
+    if components.next().is_some() {
+      return Err(DIDError::InvalidMethodId);
+    }
+    Ok(matcher)
+  }
+
+  /// Returns whether `did` is matched.
+  pub fn matches(&self, did: &DemiaDID) -> bool {
+    let component_matches =
+      |pattern: &Option<String>, value: &str| pattern.is_none() || pattern.as_deref() == Some(value);
+    component_matches(&self.country, did.country_str()) && component_matches(&self.network, did.network_str())
+  }
+}
+
+impl FromStr for DemiaDIDMatcher {
+  type Err = DIDError;
+
+  fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+    Self::parse(pattern)
+  }
+}
+
+impl Display for DemiaDIDMatcher {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(
+      f,
+      "{}:{}:{}:{}",
+      DemiaDID::SCHEME,
+      DemiaDID::METHOD,
+      self.country.as_deref().unwrap_or(Self::WILDCARD),
+      self.network.as_deref().unwrap_or(Self::WILDCARD)
+    )
+  }
+}
@@ -1,11 +1,15 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 mod commands;
+#[cfg(feature = "demia")]
+mod demia_did_matcher;
 mod resolver;
 #[cfg(test)]
 mod tests;
 
 use self::commands::SingleThreadedCommand;
+#[cfg(feature = "demia")]
+pub use demia_did_matcher::DemiaDIDMatcher;
 use identity_document::document::CoreDocument;
 pub use resolver::Resolver;
 /// Alias for a [`Resolver`] that is not [`Send`] + [`Sync`].
@@ -18,6 +18,10 @@ use crate::Result;
 use super::commands::Command;
 use super::commands::SendSyncCommand;
 use super::commands::SingleThreadedCommand;
+#[cfg(feature = "demia")]
+use super::DemiaDIDMatcher;
+#[cfg(feature = "demia")]
+use identity_demia_core::DemiaDID;
 
 /// Convenience type for resolving DID documents from different DID methods.   
 ///
@@ -25,11 +29,16 @@ use super::commands::SingleThreadedCommand;
 ///
 /// The resolver will only be able to resolve DID documents for methods it has been configured for. This is done by
 /// attaching method specific handlers with [`Self::attach_handler`](Self::attach_handler()).
+///
+/// With the `demia` feature enabled, handlers for Demia DIDs can additionally be attached per country and network
+/// with `attach_demia_route`. Matching routes take precedence over the handler attached for the `demia` method.
 pub struct Resolver<DOC = CoreDocument, CMD = SendSyncCommand<DOC>>
 where
   CMD: for<'r> Command<'r, Result<DOC>>,
 {
   command_map: HashMap<String, CMD>,
+  #[cfg(feature = "demia")]
+  demia_routes: Vec<(DemiaDIDMatcher, CMD)>,
   _required: PhantomData<DOC>,
 }
 
@@ -52,6 +61,8 @@ where
   pub fn new() -> Self {
     Self {
       command_map: HashMap::new(),
+      #[cfg(feature = "demia")]
+      demia_routes: Vec::new(),
       _required: PhantomData::<DOC>,
     }
   }
@@ -86,11 +97,14 @@ where
   ///   todo!()
   /// }
   /// ```
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(did = did.as_str()), err(Display))
+  )]
   pub async fn resolve<D: DID>(&self, did: &D) -> Result<DOC> {
     let method = did.method();
     let delegate = self
-      .command_map
-      .get(method)
+      .command(did)
       .ok_or_else(|| ErrorCause::UnsupportedMethodError {
         method: method.to_owned(),
       })
@@ -120,6 +134,28 @@ where
     let documents: HashMap<D, DOC> = futures.try_collect().await?;
     Ok(documents)
   }
+
+  /// Returns the command responsible for resolving `did`, preferring the first matching Demia route.
+  fn command<D: DID>(&self, did: &D) -> Option<&M> {
+    #[cfg(feature = "demia")]
+    if let Some(command) = self.demia_route(did) {
+      return Some(command);
+    }
+    self.command_map.get(did.method())
+  }
+
+  #[cfg(feature = "demia")]
+  fn demia_route<D: DID>(&self, did: &D) -> Option<&M> {
+    if did.method() != DemiaDID::METHOD || self.demia_routes.is_empty() {
+      return None;
+    }
+    let did: DemiaDID = DemiaDID::parse(did.as_str()).ok()?;
+    self
+      .demia_routes
+      .iter()
+      .find(|(matcher, _)| matcher.matches(&did))
+      .map(|(_, command)| command)
+  }
 }
 
 impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
@@ -180,6 +216,49 @@ impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
     let command = SendSyncCommand::new(handler);
     self.command_map.insert(method, command);
   }
+
+  /// Attach a new handler responsible for resolving the Demia DIDs matched by `matcher`.
+  ///
+  /// Routes are consulted in the order they were attached, before any handler attached for the `demia` method with
+  /// [`Self::attach_handler`](Self::attach_handler()). This allows a single resolver to serve multiple networks.
+  ///
+  /// # Example
+  /// ```no_run
+  /// # use identity_resolver::DemiaDIDMatcher;
+  /// # use identity_resolver::Resolver;
+  /// # use identity_demia_core::DemiaDID;
+  /// # use identity_demia_core::IotaDocument;
+  /// # use identity_did::CoreDID;
+  /// # use identity_document::document::CoreDocument;
+  /// # #[tokio::main]
+  /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+  /// // Resolves the DIDs of the "dev" network, e.g. from a local node.
+  /// async fn resolve_dev(did: DemiaDID) -> std::result::Result<IotaDocument, std::io::Error> {
+  ///   Ok(IotaDocument::new_with_id(did))
+  /// }
+  ///
+  /// let mut resolver: Resolver = Resolver::new();
+  /// resolver.attach_demia_route(DemiaDIDMatcher::any().network("dev"), resolve_dev);
+  ///
+  /// let did: CoreDID = CoreDID::parse(
+  ///   "did:demia:usa:dev:0x1111111111111111111111111111111111111111111111111111111111111111",
+  /// )?;
+  /// let document: CoreDocument = resolver.resolve(&did).await?;
+  /// println!("resolved {}", document.id());
+  /// # Ok(())
+  /// # }
+  /// ```
+  #[cfg(feature = "demia")]
+  pub fn attach_demia_route<F, Fut, DOCUMENT, E>(&mut self, matcher: DemiaDIDMatcher, handler: F)
+  where
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(DemiaDID) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let command = SendSyncCommand::new(handler);
+    self.demia_routes.push((matcher, command));
+  }
 }
 
 impl<DOC: 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
@@ -239,6 +318,21 @@ impl<DOC: 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
     let command = SingleThreadedCommand::new(handler);
     self.command_map.insert(method, command);
   }
+
+  /// Attach a new handler responsible for resolving the Demia DIDs matched by `matcher`.
+  ///
+  /// See also [`Resolver::attach_demia_route`](Resolver::attach_demia_route()).
+  #[cfg(feature = "demia")]
+  pub fn attach_demia_route<F, Fut, DOCUMENT, E>(&mut self, matcher: DemiaDIDMatcher, handler: F)
+  where
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(DemiaDID) -> Fut + 'static + Clone,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>>,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let command = SingleThreadedCommand::new(handler);
+    self.demia_routes.push((matcher, command));
+  }
 }
 
 
@@ -325,8 +419,10 @@ where
   DOC: AsRef<CoreDocument>,
 {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    f.debug_struct("Resolver")
-      .field("command_map", &self.command_map)
-      .finish()
+    let mut debug = f.debug_struct("Resolver");
+    debug.field("command_map", &self.command_map);
+    #[cfg(feature = "demia")]
+    debug.field("demia_routes", &self.demia_routes);
+    debug.finish()
   }
 }
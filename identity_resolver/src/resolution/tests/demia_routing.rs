@@ -0,0 +1,47 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_demia_core::DemiaDID;
+use identity_did::CoreDID;
+
+use crate::DemiaDIDMatcher;
+use crate::Resolver;
+
+const TAG: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+
+#[tokio::test]
+async fn routes_by_network() {
+  async fn dev_handler(_did: DemiaDID) -> std::result::Result<&'static str, std::io::Error> {
+    Ok("dev")
+  }
+  async fn main_handler(_did: DemiaDID) -> std::result::Result<&'static str, std::io::Error> {
+    Ok("main")
+  }
+  async fn fallback_handler(_did: DemiaDID) -> std::result::Result<&'static str, std::io::Error> {
+    Ok("fallback")
+  }
+
+  let mut resolver: Resolver<&'static str> = Resolver::new();
+  resolver.attach_demia_route("did:demia:*:dev".parse().unwrap(), dev_handler);
+  resolver.attach_demia_route(DemiaDIDMatcher::any().network("main"), main_handler);
+  resolver.attach_handler(DemiaDID::METHOD.to_owned(), fallback_handler);
+
+  let dev_did: CoreDID = CoreDID::parse(format!("did:demia:usa:dev:{TAG}")).unwrap();
+  let main_did: CoreDID = CoreDID::parse(format!("did:demia:usa:main:{TAG}")).unwrap();
+  let other_did: CoreDID = CoreDID::parse(format!("did:demia:usa:test:{TAG}")).unwrap();
+
+  assert_eq!(resolver.resolve(&dev_did).await.unwrap(), "dev");
+  assert_eq!(resolver.resolve(&main_did).await.unwrap(), "main");
+  assert_eq!(resolver.resolve(&other_did).await.unwrap(), "fallback");
+}
+
+#[test]
+fn matcher_parsing() {
+  let matcher: DemiaDIDMatcher = "did:demia:usa:*".parse().unwrap();
+  assert_eq!(matcher, DemiaDIDMatcher::any().country("usa"));
+  assert_eq!(matcher.to_string(), "did:demia:usa:*");
+  assert_eq!(DemiaDIDMatcher::parse("did:demia").unwrap(), DemiaDIDMatcher::any());
+
+  assert!(DemiaDIDMatcher::parse("did:iota:*:dev").is_err());
+  assert!(DemiaDIDMatcher::parse("did:demia:usa:dev:extra").is_err());
+}
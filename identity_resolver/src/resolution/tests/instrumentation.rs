@@ -0,0 +1,125 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentBuilder;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span::Attributes;
+use tracing::span::Id;
+use tracing::span::Record;
+use tracing::Event;
+use tracing::Level;
+use tracing::Metadata;
+use tracing::Subscriber;
+
+use crate::Resolver;
+
+#[derive(Debug, Default)]
+struct CapturedSpan {
+  name: &'static str,
+  fields: HashMap<&'static str, String>,
+  error_events: usize,
+}
+
+/// A subscriber recording every span along with its fields and the number of error events emitted within it.
+#[derive(Clone, Default)]
+struct CapturingSubscriber {
+  next_id: Arc<AtomicU64>,
+  spans: Arc<Mutex<Vec<CapturedSpan>>>,
+  entered: Arc<Mutex<Vec<Id>>>,
+}
+
+impl CapturingSubscriber {
+  fn with_span<F: FnOnce(&mut CapturedSpan)>(&self, id: &Id, f: F) {
+    let index: usize = id.into_u64() as usize - 1;
+    f(&mut self.spans.lock().unwrap()[index]);
+  }
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<&'static str, String>);
+
+impl Visit for FieldVisitor<'_> {
+  fn record_str(&mut self, field: &Field, value: &str) {
+    self.0.insert(field.name(), value.to_owned());
+  }
+
+  fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+    self.0.insert(field.name(), format!("{value:?}"));
+  }
+}
+
+impl Subscriber for CapturingSubscriber {
+  fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+    true
+  }
+
+  fn new_span(&self, span: &Attributes<'_>) -> Id {
+    let mut captured = CapturedSpan {
+      name: span.metadata().name(),
+      ..Default::default()
+    };
+    span.record(&mut FieldVisitor(&mut captured.fields));
+    self.spans.lock().unwrap().push(captured);
+    Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+  }
+
+  fn record(&self, span: &Id, values: &Record<'_>) {
+    self.with_span(span, |captured| values.record(&mut FieldVisitor(&mut captured.fields)));
+  }
+
+  fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+  fn event(&self, event: &Event<'_>) {
+    let current: Option<Id> = self.entered.lock().unwrap().last().cloned();
+    if let (Some(span), &Level::ERROR) = (current, event.metadata().level()) {
+      self.with_span(&span, |captured| captured.error_events += 1);
+    }
+  }
+
+  fn enter(&self, span: &Id) {
+    self.entered.lock().unwrap().push(span.clone());
+  }
+
+  fn exit(&self, _span: &Id) {
+    self.entered.lock().unwrap().pop();
+  }
+}
+
+async fn mock_handler(did: CoreDID) -> std::result::Result<CoreDocument, std::io::Error> {
+  Ok(DocumentBuilder::default().id(did).build().unwrap())
+}
+
+#[tokio::test]
+async fn resolution_is_traced() {
+  let subscriber = CapturingSubscriber::default();
+  let _guard = tracing::subscriber::set_default(subscriber.clone());
+
+  let mut resolver: Resolver = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), mock_handler);
+
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+  resolver.resolve(&did).await.unwrap();
+  let unsupported_did: CoreDID = CoreDID::parse("did:bar:1234").unwrap();
+  resolver.resolve(&unsupported_did).await.unwrap_err();
+
+  let spans = subscriber.spans.lock().unwrap();
+  assert_eq!(spans.len(), 2);
+  assert!(spans.iter().all(|span| span.name == "resolve"));
+
+  // The successful resolution records the DID without emitting an error.
+  assert_eq!(spans[0].fields["did"], "did:foo:1234");
+  assert_eq!(spans[0].error_events, 0);
+
+  // The failed resolution emits an error within its span.
+  assert_eq!(spans[1].fields["did"], "did:bar:1234");
+  assert_eq!(spans[1].error_events, 1);
+}
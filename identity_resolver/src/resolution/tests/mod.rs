@@ -2,5 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::resolver::*;
+#[cfg(feature = "demia")]
+mod demia_routing;
+#[cfg(feature = "tracing")]
+mod instrumentation;
 mod resolution;
 mod send_sync;
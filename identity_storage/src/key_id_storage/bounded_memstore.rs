@@ -0,0 +1,267 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+use crate::key_id_storage::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::key_id_storage_error::KeyIdStorageError;
+use crate::key_id_storage::key_id_storage_error::KeyIdStorageErrorKind;
+use crate::key_storage::shared::Shared;
+use crate::key_storage::KeyId;
+use async_trait::async_trait;
+use tokio::sync::RwLockReadGuard;
+use tokio::sync::RwLockWriteGuard;
+
+use super::key_id_storage::KeyIdStorageResult;
+use super::method_digest::MethodDigest;
+
+/// Callback invoked with every entry evicted from a [`BoundedKeyIdMemstore`].
+type EvictionCallback = Box<dyn Fn(MethodDigest, KeyId) + Send + Sync>;
+
+/// The entries of a [`BoundedKeyIdMemstore`] together with their recency order.
+#[derive(Debug, Default)]
+struct BoundedKeyIdStore {
+  entries: HashMap<MethodDigest, KeyId>,
+  /// Method digests ordered from least to most recently used.
+  recency: VecDeque<MethodDigest>,
+}
+
+impl BoundedKeyIdStore {
+  /// Marks `method_digest` as the most recently used entry.
+  fn touch(&mut self, method_digest: &MethodDigest) {
+    if let Some(position) = self.recency.iter().position(|digest| digest == method_digest) {
+      if let Some(digest) = self.recency.remove(position) {
+        self.recency.push_back(digest);
+      }
+    }
+  }
+
+  /// Removes the entry for `method_digest`, returning its key id if it was present.
+  fn remove(&mut self, method_digest: &MethodDigest) -> Option<KeyId> {
+    let key_id: KeyId = self.entries.remove(method_digest)?;
+    self.recency.retain(|digest| digest != method_digest);
+    Some(key_id)
+  }
+
+  /// Inserts a new entry and evicts the least recently used entries exceeding `capacity`.
+  fn insert(&mut self, method_digest: MethodDigest, key_id: KeyId, capacity: usize) -> Vec<(MethodDigest, KeyId)> {
+    self.recency.push_back(method_digest.clone());
+    self.entries.insert(method_digest, key_id);
+
+    let mut evicted: Vec<(MethodDigest, KeyId)> = Vec::new();
+    while self.entries.len() > capacity {
+      let Some(digest) = self.recency.pop_front() else {
+        break;
+      };
+      if let Some(key_id) = self.entries.remove(&digest) {
+        evicted.push((digest, key_id));
+      }
+    }
+    evicted
+  }
+}
+
+/// An insecure, in-memory [`KeyIdStorage`] implementation holding at most a fixed number of entries.
+///
+/// Once the capacity is exceeded, the least recently used entries are evicted. Both insertions and lookups count as
+/// a use. Evicted entries are passed to the callback registered with [`BoundedKeyIdMemstore::on_evict`], which allows
+/// the corresponding keys to be cleaned up elsewhere.
+pub struct BoundedKeyIdMemstore {
+  capacity: usize,
+  key_id_store: Shared<BoundedKeyIdStore>,
+  on_evict: Option<EvictionCallback>,
+}
+
+impl BoundedKeyIdMemstore {
+  /// Creates a new, empty `BoundedKeyIdMemstore` holding at most `capacity` entries.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `capacity` is zero.
+  pub fn with_capacity(capacity: usize) -> Self {
+    assert!(capacity > 0, "the capacity of a BoundedKeyIdMemstore must be non-zero");
+    Self {
+      capacity,
+      key_id_store: Shared::new(BoundedKeyIdStore::default()),
+      on_evict: None,
+    }
+  }
+
+  /// Sets a callback that is invoked with the method digest and key id of every evicted entry.
+  ///
+  /// Entries removed through [`KeyIdStorage::delete_key_id`] are not considered evicted.
+  #[must_use]
+  pub fn on_evict(mut self, callback: impl Fn(MethodDigest, KeyId) + Send + Sync + 'static) -> Self {
+    self.on_evict = Some(Box::new(callback));
+    self
+  }
+
+  /// Returns the maximum number of items the [`BoundedKeyIdMemstore`] holds.
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// Returns the number of items contained in the [`BoundedKeyIdMemstore`].
+  pub async fn count(&self) -> usize {
+    self.key_id_store.read().await.entries.len()
+  }
+
+  fn notify_evicted(&self, evicted: Vec<(MethodDigest, KeyId)>) {
+    if let Some(on_evict) = self.on_evict.as_ref() {
+      for (method_digest, key_id) in evicted {
+        on_evict(method_digest, key_id);
+      }
+    }
+  }
+}
+
+impl Debug for BoundedKeyIdMemstore {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("BoundedKeyIdMemstore")
+      .field("capacity", &self.capacity)
+      .field("key_id_store", &self.key_id_store)
+      .field("on_evict", &self.on_evict.is_some())
+      .finish()
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(? Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl KeyIdStorage for BoundedKeyIdMemstore {
+  async fn insert_key_id(&self, key: MethodDigest, value: KeyId) -> KeyIdStorageResult<()> {
+    let mut key_id_store: RwLockWriteGuard<'_, BoundedKeyIdStore> = self.key_id_store.write().await;
+    if key_id_store.entries.contains_key(&key) {
+      return Err(KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdAlreadyExists));
+    }
+    let evicted: Vec<(MethodDigest, KeyId)> = key_id_store.insert(key, value, self.capacity);
+    // Release the lock before running the callback so it may access the store.
+    drop(key_id_store);
+    self.notify_evicted(evicted);
+    Ok(())
+  }
+
+  async fn get_key_id(&self, key: &MethodDigest) -> KeyIdStorageResult<KeyId> {
+    // A lookup updates the recency order, hence the write lock.
+    let mut key_id_store: RwLockWriteGuard<'_, BoundedKeyIdStore> = self.key_id_store.write().await;
+    let key_id: KeyId = key_id_store
+      .entries
+      .get(key)
+      .ok_or_else(|| KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound))?
+      .clone();
+    key_id_store.touch(key);
+    Ok(key_id)
+  }
+
+  async fn delete_key_id(&self, key: &MethodDigest) -> KeyIdStorageResult<()> {
+    let mut key_id_store: RwLockWriteGuard<'_, BoundedKeyIdStore> = self.key_id_store.write().await;
+    key_id_store
+      .remove(key)
+      .ok_or_else(|| KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound))?;
+    Ok(())
+  }
+
+  async fn method_digests(&self) -> KeyIdStorageResult<Vec<MethodDigest>> {
+    let key_id_store: RwLockReadGuard<'_, BoundedKeyIdStore> = self.key_id_store.read().await;
+    Ok(key_id_store.recency.iter().cloned().collect())
+  }
+
+  async fn get_or_insert(&self, key: MethodDigest, value: KeyId) -> KeyIdStorageResult<KeyId> {
+    let mut key_id_store: RwLockWriteGuard<'_, BoundedKeyIdStore> = self.key_id_store.write().await;
+    if let Some(key_id) = key_id_store.entries.get(&key).cloned() {
+      key_id_store.touch(&key);
+      return Ok(key_id);
+    }
+    let evicted: Vec<(MethodDigest, KeyId)> = key_id_store.insert(key, value.clone(), self.capacity);
+    drop(key_id_store);
+    self.notify_evicted(evicted);
+    Ok(value)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+  use std::sync::Mutex;
+
+  use crate::key_id_storage::bounded_memstore::BoundedKeyIdMemstore;
+  use crate::key_id_storage::key_id_storage::KeyIdStorage;
+  use crate::key_id_storage::method_digest::MethodDigest;
+  use crate::key_id_storage::KeyIdStorageErrorKind;
+  use crate::key_storage::KeyId;
+  use identity_core::crypto::KeyPair;
+  use identity_core::crypto::KeyType;
+  use identity_core::utils::BaseEncoding;
+  use identity_did::CoreDID;
+  use identity_verification::VerificationMethod;
+  use static_assertions::assert_impl_all;
+
+  assert_impl_all!(BoundedKeyIdMemstore: Send, Sync);
+
+  fn method_digests(count: usize) -> Vec<MethodDigest> {
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let did: CoreDID =
+      CoreDID::parse(format!("did:example:{}", BaseEncoding::encode_base58(keypair.public()))).unwrap();
+    (0..count)
+      .map(|index| {
+        let verification_method: VerificationMethod = VerificationMethod::new(
+          did.clone(),
+          KeyType::Ed25519,
+          keypair.public(),
+          &format!("frag_{index}"),
+        )
+        .unwrap();
+        MethodDigest::new(&verification_method).unwrap()
+      })
+      .collect()
+  }
+
+  #[tokio::test]
+  async fn evicts_least_recently_used() {
+    let evicted: Arc<Mutex<Vec<KeyId>>> = Arc::new(Mutex::new(Vec::new()));
+    let memstore: BoundedKeyIdMemstore = BoundedKeyIdMemstore::with_capacity(2).on_evict({
+      let evicted = Arc::clone(&evicted);
+      move |_, key_id| evicted.lock().unwrap().push(key_id)
+    });
+    let digests: Vec<MethodDigest> = method_digests(4);
+
+    memstore
+      .insert_key_id(digests[0].clone(), KeyId::new("keyid-0"))
+      .await
+      .unwrap();
+    memstore
+      .insert_key_id(digests[1].clone(), KeyId::new("keyid-1"))
+      .await
+      .unwrap();
+
+    // Exceeding the capacity evicts the oldest entry.
+    memstore
+      .insert_key_id(digests[2].clone(), KeyId::new("keyid-2"))
+      .await
+      .unwrap();
+    assert_eq!(memstore.count().await, 2);
+    assert_eq!(evicted.lock().unwrap().as_slice(), &[KeyId::new("keyid-0")]);
+    let err = memstore.get_key_id(&digests[0]).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyIdStorageErrorKind::KeyIdNotFound));
+
+    // A lookup counts as a use, so the entry inserted after it is evicted next.
+    memstore.get_key_id(&digests[1]).await.unwrap();
+    memstore
+      .insert_key_id(digests[3].clone(), KeyId::new("keyid-3"))
+      .await
+      .unwrap();
+    assert_eq!(
+      evicted.lock().unwrap().as_slice(),
+      &[KeyId::new("keyid-0"), KeyId::new("keyid-2")]
+    );
+    assert_eq!(memstore.get_key_id(&digests[1]).await.unwrap(), KeyId::new("keyid-1"));
+    assert_eq!(memstore.get_key_id(&digests[3]).await.unwrap(), KeyId::new("keyid-3"));
+
+    // Deleting an entry does not count as an eviction.
+    memstore.delete_key_id(&digests[1]).await.unwrap();
+    assert_eq!(evicted.lock().unwrap().len(), 2);
+    assert_eq!(memstore.count().await, 1);
+  }
+}
@@ -3,8 +3,10 @@
 
 use crate::key_storage::KeyId;
 use async_trait::async_trait;
+use std::collections::HashMap;
 
 use super::key_id_storage_error::KeyIdStorageError;
+use super::key_id_storage_error::KeyIdStorageErrorKind;
 use super::method_digest::MethodDigest;
 
 /// Result of key id storage operations.
@@ -27,6 +29,103 @@ pub trait KeyIdStorage: storage_sub_trait::StorageSendSyncMaybe {
   ///
   /// If `key` is not found in storage, an Error must be returned.
   async fn delete_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<()>;
+
+  /// Obtain the [`MethodDigest`]s of all entries in the [`KeyIdStorage`].
+  ///
+  /// This is required by [`Storage::purge_orphans`](crate::storage::Storage::purge_orphans). The default
+  /// implementation fails with [`KeyIdStorageErrorKind::Unspecified`], implementations that are able to enumerate
+  /// their entries should override it.
+  async fn method_digests(&self) -> KeyIdStorageResult<Vec<MethodDigest>> {
+    Err(
+      KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified)
+        .with_custom_message("the key id storage does not support enumerating its entries"),
+    )
+  }
+
+  /// Obtain the [`KeyId`] associated with the given [`MethodDigest`], inserting `key_id` first if there is none.
+  ///
+  /// When several callers race to register the same method, all of them obtain the key id of the caller that
+  /// inserted first.
+  ///
+  /// The default implementation relies on [`KeyIdStorage::insert_key_id`] failing with
+  /// [`KeyIdStorageErrorKind::KeyIdAlreadyExists`] without altering the storage when an entry already exists.
+  /// Implementations that can perform the lookup and insertion in a single step should override it.
+  async fn get_or_insert(&self, method_digest: MethodDigest, key_id: KeyId) -> KeyIdStorageResult<KeyId> {
+    match self.get_key_id(&method_digest).await {
+      Ok(existing_key_id) => return Ok(existing_key_id),
+      Err(error) if matches!(error.kind(), KeyIdStorageErrorKind::KeyIdNotFound) => (),
+      Err(error) => return Err(error),
+    }
+
+    match self.insert_key_id(method_digest.clone(), key_id.clone()).await {
+      Ok(()) => Ok(key_id),
+      // Another caller inserted a key id after the lookup.
+      Err(error) if matches!(error.kind(), KeyIdStorageErrorKind::KeyIdAlreadyExists) => {
+        self.get_key_id(&method_digest).await
+      }
+      Err(error) => Err(error),
+    }
+  }
+
+  /// Moves the [`KeyId`] stored under each key of `remap` to the corresponding value, e.g. to migrate a storage to a
+  /// new [`MethodDigest`] version.
+  ///
+  /// Every digest to be moved must be contained in the storage and none of the new digests may be, otherwise
+  /// [`KeyIdStorageErrorKind::KeyIdNotFound`] or [`KeyIdStorageErrorKind::KeyIdAlreadyExists`] is returned.
+  ///
+  /// The default implementation checks all digests up front and then moves one entry at a time using
+  /// [`KeyIdStorage::insert_key_id`] and [`KeyIdStorage::delete_key_id`], so a failure part way through leaves the
+  /// storage partially migrated. Since entries are inserted under the new digest before the old one is deleted,
+  /// every entry stays reachable through at least one digest. Implementations that can apply all changes in a single
+  /// step should override it.
+  async fn rekey(&self, remap: &HashMap<MethodDigest, MethodDigest>) -> KeyIdStorageResult<()> {
+    let mut entries: Vec<(&MethodDigest, &MethodDigest, KeyId)> = Vec::with_capacity(remap.len());
+    for (old_digest, new_digest) in remap {
+      match self.get_key_id(new_digest).await {
+        Ok(_) => return Err(KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdAlreadyExists)),
+        Err(error) if matches!(error.kind(), KeyIdStorageErrorKind::KeyIdNotFound) => (),
+        Err(error) => return Err(error),
+      }
+      entries.push((old_digest, new_digest, self.get_key_id(old_digest).await?));
+    }
+
+    for (old_digest, new_digest, key_id) in entries {
+      self.insert_key_id(new_digest.clone(), key_id).await?;
+      self.delete_key_id(old_digest).await?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<S> KeyIdStorage for &S
+where
+  S: KeyIdStorage + ?Sized,
+{
+  async fn insert_key_id(&self, method_digest: MethodDigest, key_id: KeyId) -> KeyIdStorageResult<()> {
+    (**self).insert_key_id(method_digest, key_id).await
+  }
+
+  async fn get_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<KeyId> {
+    (**self).get_key_id(method_digest).await
+  }
+
+  async fn delete_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<()> {
+    (**self).delete_key_id(method_digest).await
+  }
+
+  async fn method_digests(&self) -> KeyIdStorageResult<Vec<MethodDigest>> {
+    (**self).method_digests().await
+  }
+
+  async fn get_or_insert(&self, method_digest: MethodDigest, key_id: KeyId) -> KeyIdStorageResult<KeyId> {
+    (**self).get_or_insert(method_digest, key_id).await
+  }
+
+  async fn rekey(&self, remap: &HashMap<MethodDigest, MethodDigest>) -> KeyIdStorageResult<()> {
+    (**self).rekey(remap).await
+  }
 }
 
 #[cfg(not(feature = "send-sync-storage"))]
@@ -8,6 +8,7 @@ use crate::key_storage::shared::Shared;
 use crate::key_storage::KeyId;
 use async_trait::async_trait;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use tokio::sync::RwLockReadGuard;
 use tokio::sync::RwLockWriteGuard;
 
@@ -17,6 +18,9 @@ use super::method_digest::MethodDigest;
 type KeyIdStore = HashMap<MethodDigest, KeyId>;
 
 /// An insecure, in-memory [`KeyIdStorage`] implementation that serves as an example and may be used in tests.
+///
+/// The store is guarded by an asynchronous read-write lock, so a single instance is `Send + Sync` and may be shared
+/// between threads, e.g. behind an [`Arc`](std::sync::Arc).
 #[derive(Debug)]
 pub struct KeyIdMemstore {
   key_id_store: Shared<KeyIdStore>,
@@ -71,6 +75,39 @@ impl KeyIdStorage for KeyIdMemstore {
       .ok_or_else(|| KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound))?;
     Ok(())
   }
+
+  async fn method_digests(&self) -> KeyIdStorageResult<Vec<MethodDigest>> {
+    let key_id_store: RwLockReadGuard<'_, KeyIdStore> = self.key_id_store.read().await;
+    Ok(key_id_store.keys().cloned().collect())
+  }
+
+  async fn get_or_insert(&self, key: MethodDigest, value: KeyId) -> KeyIdStorageResult<KeyId> {
+    let mut key_id_store: RwLockWriteGuard<'_, KeyIdStore> = self.key_id_store.write().await;
+    Ok(key_id_store.entry(key).or_insert(value).clone())
+  }
+
+  async fn rekey(&self, remap: &HashMap<MethodDigest, MethodDigest>) -> KeyIdStorageResult<()> {
+    let mut key_id_store: RwLockWriteGuard<'_, KeyIdStore> = self.key_id_store.write().await;
+
+    // Validate all changes first so the store is either fully migrated or left untouched.
+    if remap.keys().any(|old_digest| !key_id_store.contains_key(old_digest)) {
+      return Err(KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound));
+    }
+    let mut new_digests: HashSet<&MethodDigest> = HashSet::with_capacity(remap.len());
+    if remap
+      .values()
+      .any(|new_digest| key_id_store.contains_key(new_digest) || !new_digests.insert(new_digest))
+    {
+      return Err(KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdAlreadyExists));
+    }
+
+    let entries: Vec<(MethodDigest, KeyId)> = remap
+      .iter()
+      .filter_map(|(old_digest, new_digest)| Some((new_digest.clone(), key_id_store.remove(old_digest)?)))
+      .collect();
+    key_id_store.extend(entries);
+    Ok(())
+  }
 }
 
 #[cfg(test)]
@@ -86,6 +123,10 @@ mod tests {
   use identity_core::utils::BaseEncoding;
   use identity_did::CoreDID;
   use identity_verification::VerificationMethod;
+  use static_assertions::assert_impl_all;
+  use std::collections::HashMap;
+
+  assert_impl_all!(KeyIdMemstore: Send, Sync);
 
   #[tokio::test]
   pub async fn memstore_operations() {
@@ -121,4 +162,97 @@ mod tests {
     let _expected_error: KeyIdStorageError = KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound);
     assert!(matches!(repeat_deletion_result.unwrap_err(), _expected_error));
   }
+
+  #[test]
+  fn concurrent_insertions() {
+    const THREADS: usize = 8;
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let did: CoreDID =
+      CoreDID::parse(format!("did:example:{}", BaseEncoding::encode_base58(keypair.public()))).unwrap();
+    let memstore: KeyIdMemstore = KeyIdMemstore::new();
+
+    std::thread::scope(|scope| {
+      for index in 0..THREADS {
+        let (memstore, did, public_key) = (&memstore, did.clone(), keypair.public());
+        scope.spawn(move || {
+          let verification_method: VerificationMethod =
+            VerificationMethod::new(did, KeyType::Ed25519, public_key, &format!("frag_{index}")).unwrap();
+          let method_digest: MethodDigest = MethodDigest::new(&verification_method).unwrap();
+          let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+          runtime
+            .block_on(memstore.insert_key_id(method_digest, KeyId::new(format!("keyid-{index}"))))
+            .unwrap();
+        });
+      }
+    });
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    assert_eq!(runtime.block_on(memstore.count()), THREADS);
+  }
+
+  #[test]
+  fn concurrent_get_or_insert() {
+    const THREADS: usize = 8;
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let did: CoreDID =
+      CoreDID::parse(format!("did:example:{}", BaseEncoding::encode_base58(keypair.public()))).unwrap();
+    let verification_method: VerificationMethod =
+      VerificationMethod::new(did, KeyType::Ed25519, keypair.public(), "frag_1").unwrap();
+    let method_digest: MethodDigest = MethodDigest::new(&verification_method).unwrap();
+    let memstore: KeyIdMemstore = KeyIdMemstore::new();
+
+    let key_ids: Vec<KeyId> = std::thread::scope(|scope| {
+      let handles: Vec<_> = (0..THREADS)
+        .map(|index| {
+          let (memstore, method_digest) = (&memstore, method_digest.clone());
+          scope.spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+            runtime
+              .block_on(memstore.get_or_insert(method_digest, KeyId::new(format!("keyid-{index}"))))
+              .unwrap()
+          })
+        })
+        .collect();
+      handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+
+    // Every caller obtains the key id that was inserted first, which is the only one stored.
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let stored: KeyId = runtime.block_on(memstore.get_key_id(&method_digest)).unwrap();
+    assert!(key_ids.iter().all(|key_id| key_id == &stored));
+    assert_eq!(runtime.block_on(memstore.count()), 1);
+  }
+
+  #[tokio::test]
+  async fn rekey() {
+    let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
+    let did: CoreDID =
+      CoreDID::parse(format!("did:example:{}", BaseEncoding::encode_base58(keypair.public()))).unwrap();
+    let verification_method: VerificationMethod =
+      VerificationMethod::new(did, KeyType::Ed25519, keypair.public(), "frag_1").unwrap();
+    // Stand-in for a digest of a future version, computed differently from the current one.
+    let old_digest: MethodDigest = MethodDigest::new(&verification_method).unwrap();
+    let new_digest: MethodDigest = MethodDigest::new_keyed(&verification_method, 1).unwrap();
+    assert_ne!(old_digest, new_digest);
+
+    let memstore: KeyIdMemstore = KeyIdMemstore::new();
+    memstore
+      .insert_key_id(old_digest.clone(), KeyId::new("keyid"))
+      .await
+      .unwrap();
+
+    // INVALID: the digest to be moved does not exist, the store is left untouched.
+    let remap: HashMap<MethodDigest, MethodDigest> = HashMap::from([(new_digest.clone(), old_digest.clone())]);
+    let err = memstore.rekey(&remap).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyIdStorageErrorKind::KeyIdNotFound));
+    assert_eq!(memstore.get_key_id(&old_digest).await.unwrap(), KeyId::new("keyid"));
+
+    // VALID: the entry is only reachable through the new digest.
+    let remap: HashMap<MethodDigest, MethodDigest> = HashMap::from([(old_digest.clone(), new_digest.clone())]);
+    memstore.rekey(&remap).await.unwrap();
+    assert_eq!(memstore.get_key_id(&new_digest).await.unwrap(), KeyId::new("keyid"));
+    let err = memstore.get_key_id(&old_digest).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyIdStorageErrorKind::KeyIdNotFound));
+    assert_eq!(memstore.count().await, 1);
+  }
 }
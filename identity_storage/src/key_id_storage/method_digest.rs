@@ -6,6 +6,7 @@ use identity_verification::VerificationMethod;
 use seahash::SeaHasher;
 use std::fmt::Display;
 use std::hash::Hasher;
+use std::str::FromStr;
 
 use super::KeyIdStorageError;
 
@@ -30,6 +31,21 @@ impl Display for MethodDigestConstructionErrorKind {
 }
 
 /// Unique identifier of a [`VerificationMethod`].
+///
+/// # Packed format
+///
+/// [`MethodDigest::pack`] produces exactly [`MethodDigest::PACK_LEN`] bytes laid out as
+///
+/// | offset | length | content                                                  |
+/// |--------|--------|----------------------------------------------------------|
+/// | 0      | 2      | magic: [`MethodDigest::PACK_MAGIC`], the ASCII bytes `MD` |
+/// | 2      | 1      | the digest version, currently always `0`                 |
+/// | 3      | 8      | digest value as a little-endian unsigned integer         |
+///
+/// Bytes without the magic or with the version of an unknown digest are rejected when unpacking, instead of being
+/// read as a digest. This layout is stable, packed digests are persisted by storage implementations and exchanged
+/// with the bindings. A future digest version is introduced under a new version value, leaving the layout of
+/// existing versions unchanged.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MethodDigest {
   /// Version of hashing.
@@ -39,11 +55,32 @@ pub struct MethodDigest {
 }
 
 impl MethodDigest {
+  /// Magic prefix of a packed [`MethodDigest`], see [`MethodDigest::pack`].
+  pub const PACK_MAGIC: [u8; 2] = *b"MD";
+
+  /// Length in bytes of a packed [`MethodDigest`], see [`MethodDigest::pack`].
+  pub const PACK_LEN: usize = 11;
+
   /// Creates a new [`MethodDigest`].
   pub fn new(verification_method: &VerificationMethod) -> Result<Self, MethodDigestConstructionError> {
+    Self::with_hasher(verification_method, SeaHasher::new())
+  }
+
+  /// Creates a new [`MethodDigest`] keyed with `seed`.
+  ///
+  /// Deployments using different seeds obtain different digests for the same method, which separates their key id
+  /// namespaces. Keyed digests cannot be told apart from unkeyed ones, so the same seed must be used for every
+  /// lookup in a given [`KeyIdStorage`](crate::key_id_storage::KeyIdStorage).
+  pub fn new_keyed(verification_method: &VerificationMethod, seed: u64) -> Result<Self, MethodDigestConstructionError> {
+    Self::with_hasher(verification_method, SeaHasher::with_seeds(seed, seed, seed, seed))
+  }
+
+  fn with_hasher(
+    verification_method: &VerificationMethod,
+    mut hasher: SeaHasher,
+  ) -> Result<Self, MethodDigestConstructionError> {
     // Method digest version 0 formula:  SeaHash(<fragment><JWK thumbprint if JWK else decoded public key>)
     use MethodDigestConstructionErrorKind::*;
-    let mut hasher: SeaHasher = SeaHasher::new();
     let fragment: &str = verification_method.id().fragment().ok_or(MissingIdFragment)?;
     let method_data: &MethodData = verification_method.data();
 
@@ -65,28 +102,178 @@ impl MethodDigest {
     })
   }
 
-  /// Packs [`MethodDigest`] into bytes.
+  /// Packs [`MethodDigest`] into bytes, see the [packed format](MethodDigest#packed-format).
   pub fn pack(&self) -> Vec<u8> {
-    let mut pack: Vec<u8> = vec![self.version];
-    pack.append(&mut self.value.to_le_bytes().to_vec());
+    let mut pack: Vec<u8> = Vec::with_capacity(Self::PACK_LEN);
+    pack.extend_from_slice(&Self::PACK_MAGIC);
+    pack.push(self.version);
+    pack.extend_from_slice(&self.value.to_le_bytes());
     pack
   }
 
   /// Unpacks bytes into [`MethodDigest`].
+  ///
+  /// Equivalent to [`MethodDigest::try_from_packed`].
   pub fn unpack(bytes: Vec<u8>) -> crate::key_id_storage::KeyIdStorageResult<Self> {
-    if bytes.len() != 9 {
-      return Err(KeyIdStorageError::new(super::KeyIdStorageErrorKind::SerializationError));
-    }
-    let version: u8 = bytes[0];
-    if version != 0 {
-      return Err(KeyIdStorageError::new(super::KeyIdStorageErrorKind::SerializationError));
+    Self::try_from_packed(&bytes)
+  }
+
+  /// Unpacks bytes produced by [`MethodDigest::pack`] into a [`MethodDigest`].
+  ///
+  /// # Errors
+  ///
+  /// Returns a [`SerializationError`](super::KeyIdStorageErrorKind::SerializationError) if `bytes` is not exactly
+  /// [`MethodDigest::PACK_LEN`] bytes long, does not start with [`MethodDigest::PACK_MAGIC`] or does not contain the
+  /// version of a supported digest.
+  pub fn try_from_packed(bytes: &[u8]) -> crate::key_id_storage::KeyIdStorageResult<Self> {
+    let serialization_error = || KeyIdStorageError::new(super::KeyIdStorageErrorKind::SerializationError);
+
+    let bytes: &[u8; Self::PACK_LEN] = bytes.try_into().map_err(|_| serialization_error())?;
+    let (&version, value_le_bytes) = bytes
+      .strip_prefix(&Self::PACK_MAGIC)
+      .and_then(<[u8]>::split_first)
+      .ok_or_else(serialization_error)?;
+    if !Self::is_supported_version(version) {
+      return Err(serialization_error());
     }
-    let value_le_bytes: [u8; 8] = bytes[1..9]
-      .try_into()
-      .map_err(|_| KeyIdStorageError::new(super::KeyIdStorageErrorKind::SerializationError))?;
+    let value_le_bytes: [u8; 8] = value_le_bytes.try_into().map_err(|_| serialization_error())?;
     let value: u64 = u64::from_le_bytes(value_le_bytes);
     Ok(Self { version, value })
   }
+
+  /// Encodes [`MethodDigest`] as a CBOR ([RFC 8949](https://www.rfc-editor.org/rfc/rfc8949)) array
+  /// `[version, value]` of unsigned integers.
+  ///
+  /// Integers are encoded in their shortest form as required by the core deterministic encoding, so the output is
+  /// stable and at most 11 bytes long. Since the version is encoded alongside the value, digests of future versions
+  /// share this representation.
+  pub fn to_cbor(&self) -> Vec<u8> {
+    let mut cbor: Vec<u8> = vec![CBOR_ARRAY_OF_TWO];
+    cbor_encode_uint(&mut cbor, self.version.into());
+    cbor_encode_uint(&mut cbor, self.value);
+    cbor
+  }
+
+  /// Decodes a [`MethodDigest`] from the CBOR representation produced by [`MethodDigest::to_cbor`].
+  ///
+  /// Non-deterministic encodings, trailing bytes and unsupported versions are rejected.
+  pub fn from_cbor(bytes: &[u8]) -> crate::key_id_storage::KeyIdStorageResult<Self> {
+    let serialization_error = || KeyIdStorageError::new(super::KeyIdStorageErrorKind::SerializationError);
+
+    let rest: &[u8] = match bytes.split_first() {
+      Some((&CBOR_ARRAY_OF_TWO, rest)) => rest,
+      _ => return Err(serialization_error()),
+    };
+    let (version, rest) = cbor_decode_uint(rest).ok_or_else(serialization_error)?;
+    let (value, rest) = cbor_decode_uint(rest).ok_or_else(serialization_error)?;
+    if !rest.is_empty() {
+      return Err(serialization_error());
+    }
+
+    let version: u8 = u8::try_from(version).map_err(|_| serialization_error())?;
+    if !Self::is_supported_version(version) {
+      return Err(serialization_error());
+    }
+    Ok(Self { version, value })
+  }
+
+  /// Returns whether digests of the given `version` can be deserialized.
+  fn is_supported_version(version: u8) -> bool {
+    version == 0
+  }
+}
+
+/// Formats the digest as `md<version>:<hex of the packed bytes>`, e.g. `md0:004a3c0ac74ccdb485`. The
+/// [magic](MethodDigest::PACK_MAGIC) of the packed bytes is omitted.
+impl Display for MethodDigest {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "md{}:", self.version)?;
+    self.pack()[Self::PACK_MAGIC.len()..]
+      .iter()
+      .try_for_each(|byte| write!(f, "{byte:02x}"))
+  }
+}
+
+/// Parses the output of the [`Display`] implementation, `md<version>:<hex of the packed bytes>`.
+impl FromStr for MethodDigest {
+  type Err = KeyIdStorageError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let error = |message: &'static str| {
+      KeyIdStorageError::new(super::KeyIdStorageErrorKind::SerializationError).with_custom_message(message)
+    };
+
+    let (version, hex) = s
+      .strip_prefix("md")
+      .and_then(|s| s.split_once(':'))
+      .ok_or_else(|| error("method digest must have the form `md<version>:<hex>`"))?;
+    if version.is_empty() || !version.bytes().all(|byte| byte.is_ascii_digit()) {
+      return Err(error("invalid method digest version"));
+    }
+    let version: u8 = version.parse().map_err(|_| error("invalid method digest version"))?;
+    if hex.len() != 2 * (Self::PACK_LEN - Self::PACK_MAGIC.len()) || !hex.is_ascii() {
+      return Err(error("invalid method digest length"));
+    }
+
+    let mut packed: Vec<u8> = Self::PACK_MAGIC.to_vec();
+    for idx in (0..hex.len()).step_by(2) {
+      packed.push(u8::from_str_radix(&hex[idx..idx + 2], 16).map_err(|_| error("invalid hex in method digest"))?);
+    }
+    let method_digest: Self = Self::try_from_packed(&packed)?;
+    if method_digest.version != version {
+      return Err(error("method digest version does not match its packed bytes"));
+    }
+    Ok(method_digest)
+  }
+}
+
+/// Initial byte of a CBOR array (major type 4) with two elements.
+const CBOR_ARRAY_OF_TWO: u8 = 0x82;
+
+/// Appends the shortest CBOR encoding of the unsigned integer (major type 0) `value` to `buf`.
+fn cbor_encode_uint(buf: &mut Vec<u8>, value: u64) {
+  match value {
+    0..=23 => buf.push(value as u8),
+    24..=0xff => buf.extend_from_slice(&[24, value as u8]),
+    0x100..=0xffff => {
+      buf.push(25);
+      buf.extend_from_slice(&(value as u16).to_be_bytes());
+    }
+    0x1_0000..=0xffff_ffff => {
+      buf.push(26);
+      buf.extend_from_slice(&(value as u32).to_be_bytes());
+    }
+    _ => {
+      buf.push(27);
+      buf.extend_from_slice(&value.to_be_bytes());
+    }
+  }
+}
+
+/// Decodes a CBOR unsigned integer (major type 0) from the start of `bytes`, returning it with the remaining bytes.
+///
+/// Returns `None` if `bytes` does not start with an unsigned integer in its shortest encoding.
+fn cbor_decode_uint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+  let (&initial_byte, rest) = bytes.split_first()?;
+  if initial_byte >> 5 != 0 {
+    return None;
+  }
+  let (len, min): (usize, u64) = match initial_byte & 0x1f {
+    info @ 0..=23 => return Some((info.into(), rest)),
+    24 => (1, 24),
+    25 => (2, 0x100),
+    26 => (4, 0x1_0000),
+    27 => (8, 0x1_0000_0000),
+    _ => return None,
+  };
+  if rest.len() < len {
+    return None;
+  }
+  let (int_bytes, rest) = rest.split_at(len);
+  let mut be_bytes: [u8; 8] = [0; 8];
+  be_bytes[8 - len..].copy_from_slice(int_bytes);
+  let value: u64 = u64::from_be_bytes(be_bytes);
+  (value >= min).then_some((value, rest))
 }
 
 #[cfg(test)]
@@ -100,7 +287,12 @@ mod test {
   use identity_core::utils::BaseEncoding;
   use identity_did::CoreDID;
   use identity_verification::VerificationMethod;
+  use proptest::collection::vec;
+  use proptest::prelude::any;
+  use proptest::proptest;
+  use proptest::sample::select;
   use serde_json::Value;
+  use std::str::FromStr;
 
   use super::MethodDigest;
 
@@ -124,10 +316,78 @@ mod test {
     assert_eq!(method_digest, method_digest_expected);
 
     let packed: Vec<u8> = method_digest.pack();
-    let packed_expected: Vec<u8> = vec![0, 74, 60, 10, 199, 76, 205, 180, 133];
+    let packed_expected: Vec<u8> = vec![77, 68, 0, 74, 60, 10, 199, 76, 205, 180, 133];
     assert_eq!(packed, packed_expected);
   }
 
+  #[test]
+  pub fn keyed() {
+    let verification_method: VerificationMethod = create_verification_method();
+    let unkeyed: MethodDigest = MethodDigest::new(&verification_method).unwrap();
+    let keyed_1: MethodDigest = MethodDigest::new_keyed(&verification_method, 1).unwrap();
+    let keyed_2: MethodDigest = MethodDigest::new_keyed(&verification_method, 2).unwrap();
+
+    assert_ne!(keyed_1, keyed_2);
+    assert_ne!(keyed_1, unkeyed);
+    assert_ne!(keyed_2, unkeyed);
+    // The same seed yields the same digest.
+    assert_eq!(keyed_1, MethodDigest::new_keyed(&verification_method, 1).unwrap());
+    // Keyed digests share the packed format.
+    assert_eq!(MethodDigest::unpack(keyed_1.pack()).unwrap(), keyed_1);
+  }
+
+  #[test]
+  pub fn display() {
+    let method_digest: MethodDigest = MethodDigest {
+      version: 0,
+      value: 9634551232492878922,
+    };
+    assert_eq!(method_digest.to_string(), "md0:004a3c0ac74ccdb485");
+
+    let method_digest: MethodDigest = MethodDigest::new(&create_verification_method()).unwrap();
+    // The magic of the packed bytes is omitted.
+    let hex: String = method_digest.pack()[MethodDigest::PACK_MAGIC.len()..]
+      .iter()
+      .map(|byte| format!("{byte:02x}"))
+      .collect();
+    assert_eq!(method_digest.to_string(), format!("md0:{hex}"));
+  }
+
+  #[test]
+  pub fn from_str() {
+    let method_digest: MethodDigest = MethodDigest::new(&create_verification_method()).unwrap();
+    let parsed: MethodDigest = method_digest.to_string().parse().unwrap();
+    assert_eq!(parsed, method_digest);
+
+    let parsed: MethodDigest = MethodDigest::from_str("md0:004a3c0ac74ccdb485").unwrap();
+    assert_eq!(
+      parsed,
+      MethodDigest {
+        version: 0,
+        value: 9634551232492878922,
+      }
+    );
+
+    for invalid in [
+      "",
+      "md0",
+      "004a3c0ac74ccdb485",
+      "md:004a3c0ac74ccdb485",
+      "md+0:004a3c0ac74ccdb485",
+      // Unsupported version.
+      "md1:014a3c0ac74ccdb485",
+      // Version prefix does not match the packed bytes.
+      "md1:004a3c0ac74ccdb485",
+      // Too short and too long.
+      "md0:004a3c0ac74ccdb4",
+      "md0:004a3c0ac74ccdb48500",
+      // Not hex.
+      "md0:004a3c0ac74ccdb4zz",
+    ] {
+      assert!(MethodDigest::from_str(invalid).is_err(), "{invalid}");
+    }
+  }
+
   #[test]
   pub fn pack() {
     let verification_method: VerificationMethod = create_verification_method();
@@ -139,7 +399,7 @@ mod test {
 
   #[test]
   pub fn unpack() {
-    let packed: Vec<u8> = vec![0, 255, 212, 82, 63, 57, 19, 134, 193];
+    let packed: Vec<u8> = vec![77, 68, 0, 255, 212, 82, 63, 57, 19, 134, 193];
     let method_digest_unpacked: MethodDigest = MethodDigest::unpack(packed).unwrap();
     let method_digest_expected: MethodDigest = MethodDigest {
       version: 0,
@@ -150,19 +410,19 @@ mod test {
 
   #[test]
   pub fn invalid_unpack() {
-    let packed: Vec<u8> = vec![1, 255, 212, 82, 63, 57, 19, 134, 193];
+    let packed: Vec<u8> = vec![77, 68, 1, 255, 212, 82, 63, 57, 19, 134, 193];
     let method_digest_unpacked = MethodDigest::unpack(packed).unwrap_err();
     let _expected_error = KeyIdStorageError::new(KeyIdStorageErrorKind::SerializationError);
     assert!(matches!(method_digest_unpacked, _expected_error));
 
-    // Vec size > 9.
-    let packed: Vec<u8> = vec![1, 255, 212, 82, 63, 57, 19, 134, 193, 200];
+    // Vec size > 11.
+    let packed: Vec<u8> = vec![77, 68, 0, 255, 212, 82, 63, 57, 19, 134, 193, 200];
     let method_digest_unpacked = MethodDigest::unpack(packed).unwrap_err();
     let _expected_error = KeyIdStorageError::new(KeyIdStorageErrorKind::SerializationError);
     assert!(matches!(method_digest_unpacked, _expected_error));
 
-    // Vec size < 9.
-    let packed: Vec<u8> = vec![1, 255, 212, 82, 63, 57, 19, 134];
+    // Vec size < 11.
+    let packed: Vec<u8> = vec![77, 68, 0, 255, 212, 82, 63, 57, 19, 134];
     let method_digest_unpacked = MethodDigest::unpack(packed).unwrap_err();
     let _expected_error = KeyIdStorageError::new(KeyIdStorageErrorKind::SerializationError);
     assert!(matches!(method_digest_unpacked, _expected_error));
@@ -174,6 +434,134 @@ mod test {
     assert!(matches!(method_digest_unpacked, _expected_error));
   }
 
+  #[test]
+  pub fn packed_format() {
+    let method_digest: MethodDigest = MethodDigest::new(&create_verification_method()).unwrap();
+    let packed: Vec<u8> = method_digest.pack();
+    assert_eq!(packed.len(), MethodDigest::PACK_LEN);
+    assert_eq!(packed[..2], MethodDigest::PACK_MAGIC);
+    assert_eq!(packed[2], 0);
+    assert_eq!(MethodDigest::try_from_packed(&packed).unwrap(), method_digest);
+    assert_eq!(MethodDigest::unpack(packed.clone()).unwrap(), method_digest);
+
+    // Serializing the packed bytes with serde does not alter them.
+    let json: String = serde_json::to_string(&packed).unwrap();
+    let deserialized: Vec<u8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(MethodDigest::try_from_packed(&deserialized).unwrap(), method_digest);
+
+    // Corrupted headers are rejected.
+    for (position, byte) in [(0, b'm'), (1, 0), (2, 1), (2, 0x82), (2, u8::MAX)] {
+      let mut corrupted: Vec<u8> = packed.clone();
+      corrupted[position] = byte;
+      assert!(MethodDigest::try_from_packed(&corrupted).is_err());
+    }
+
+    // Bytes without the magic are rejected, including the unprefixed layout.
+    assert!(MethodDigest::try_from_packed(&packed[MethodDigest::PACK_MAGIC.len()..]).is_err());
+    let mut shifted: Vec<u8> = packed[MethodDigest::PACK_MAGIC.len()..].to_vec();
+    shifted.extend_from_slice(&[0, 0]);
+    assert!(MethodDigest::try_from_packed(&shifted).is_err());
+
+    // Foreign bytes of the wrong length are rejected, even with a valid header.
+    assert!(MethodDigest::try_from_packed(&packed[..MethodDigest::PACK_LEN - 1]).is_err());
+    assert!(MethodDigest::try_from_packed(&[packed.as_slice(), &[0]].concat()).is_err());
+    assert!(MethodDigest::try_from_packed(&method_digest.to_cbor()).is_err());
+  }
+
+  #[test]
+  pub fn cbor() {
+    let method_digest: MethodDigest = MethodDigest {
+      version: 0,
+      value: 9634551232492878922,
+    };
+    let cbor: Vec<u8> = method_digest.to_cbor();
+    // [0, 9634551232492878922] with the value encoded as a big-endian 64-bit unsigned integer.
+    assert_eq!(cbor, vec![0x82, 0x00, 0x1b, 133, 180, 205, 76, 199, 10, 60, 74]);
+    assert_eq!(MethodDigest::from_cbor(&cbor).unwrap(), method_digest);
+
+    let method_digest: MethodDigest = MethodDigest::new(&create_verification_method()).unwrap();
+    assert_eq!(
+      MethodDigest::from_cbor(&method_digest.to_cbor()).unwrap(),
+      method_digest
+    );
+
+    // Values of every encoding length round-trip in their shortest form.
+    for (value, len) in [(0, 3), (23, 3), (24, 4), (255, 4), (256, 5), (65536, 7), (u64::MAX, 11)] {
+      let method_digest: MethodDigest = MethodDigest { version: 0, value };
+      let cbor: Vec<u8> = method_digest.to_cbor();
+      assert_eq!(cbor.len(), len);
+      assert_eq!(MethodDigest::from_cbor(&cbor).unwrap(), method_digest);
+    }
+  }
+
+  #[test]
+  pub fn invalid_cbor() {
+    let invalid: [&[u8]; 7] = [
+      // Empty.
+      &[],
+      // Not an array of two elements.
+      &[0x83, 0x00, 0x00, 0x00],
+      // Unsupported version.
+      &[0x82, 0x01, 0x00],
+      // Version exceeding u8.
+      &[0x82, 0x19, 0x01, 0x00, 0x00],
+      // Non-deterministic encoding of the value 1.
+      &[0x82, 0x00, 0x18, 0x01],
+      // Truncated value.
+      &[0x82, 0x00, 0x1b, 0x01],
+      // Trailing bytes.
+      &[0x82, 0x00, 0x00, 0x00],
+    ];
+    for bytes in invalid {
+      assert!(MethodDigest::from_cbor(bytes).is_err());
+    }
+  }
+
+  /// Packed bytes of known-good digests, used to seed the property based tests.
+  const PACKED_SEEDS: [[u8; MethodDigest::PACK_LEN]; 2] = [
+    [77, 68, 0, 74, 60, 10, 199, 76, 205, 180, 133],
+    [77, 68, 0, 255, 212, 82, 63, 57, 19, 134, 193],
+  ];
+
+  /// Checks that unpacking `bytes` does not panic and succeeds exactly for the packed format, in which case the
+  /// digest packs back into `bytes`.
+  fn assert_unpack_consistent(bytes: Vec<u8>) {
+    let is_packed_format: bool =
+      bytes.len() == MethodDigest::PACK_LEN && bytes.starts_with(&MethodDigest::PACK_MAGIC) && bytes[2] == 0;
+    match MethodDigest::unpack(bytes.clone()) {
+      Ok(method_digest) => {
+        assert!(is_packed_format);
+        assert_eq!(method_digest.pack(), bytes);
+      }
+      Err(error) => {
+        assert!(!is_packed_format);
+        assert!(matches!(error.kind(), KeyIdStorageErrorKind::SerializationError));
+      }
+    }
+  }
+
+  proptest! {
+    #[test]
+    fn property_based_unpack_arbitrary_bytes(bytes in vec(any::<u8>(), 0..=2 * MethodDigest::PACK_LEN)) {
+      assert_unpack_consistent(bytes);
+    }
+
+    #[test]
+    fn property_based_unpack_mutated_seeds(
+      seed in select(PACKED_SEEDS.to_vec()),
+      (position, byte) in (0..MethodDigest::PACK_LEN, any::<u8>()),
+      suffix in vec(any::<u8>(), 0..2),
+    ) {
+      let mut bytes: Vec<u8> = seed.to_vec();
+      bytes[position] = byte;
+      assert_unpack_consistent(bytes.clone());
+      bytes.extend(suffix);
+      assert_unpack_consistent(bytes.clone());
+      bytes.truncate(position);
+      assert_unpack_consistent(bytes);
+    }
+  }
+
   fn create_verification_method() -> VerificationMethod {
     let keypair: KeyPair = KeyPair::new(KeyType::Ed25519).unwrap();
     let did: CoreDID =
@@ -1,6 +1,8 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "memstore")]
+mod bounded_memstore;
 #[allow(clippy::module_inception)]
 mod key_id_storage;
 mod key_id_storage_error;
@@ -9,6 +11,8 @@ mod method_digest;
 #[cfg(feature = "memstore")]
 mod memstore;
 
+#[cfg(feature = "memstore")]
+pub use bounded_memstore::*;
 pub use key_id_storage::*;
 pub use key_id_storage_error::*;
 #[cfg(feature = "memstore")]
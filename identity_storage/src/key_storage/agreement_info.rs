@@ -0,0 +1,28 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Agreement information used as the input for the Concat KDF of an ECDH-ES key agreement,
+/// see [RFC 7518 section 4.6.2](https://www.rfc-editor.org/rfc/rfc7518#section-4.6.2).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AgreementInfo {
+  /// Agreement PartyUInfo.
+  pub apu: Vec<u8>,
+  /// Agreement PartyVInfo.
+  pub apv: Vec<u8>,
+  /// SuppPubInfo, usually the length of the derived key in bits as a big-endian 32-bit unsigned integer.
+  pub pub_info: Vec<u8>,
+  /// SuppPrivInfo.
+  pub priv_info: Vec<u8>,
+}
+
+impl AgreementInfo {
+  /// Creates a new `AgreementInfo` instance.
+  pub fn new(apu: Vec<u8>, apv: Vec<u8>, pub_info: Vec<u8>, priv_info: Vec<u8>) -> Self {
+    Self {
+      apu,
+      apv,
+      pub_info,
+      priv_info,
+    }
+  }
+}
@@ -60,3 +60,29 @@ pub trait JwkStorage: storage_sub_trait::StorageSendSyncMaybe {
   /// Returns `true` if the key with the given `key_id` exists in storage, `false` otherwise.
   async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool>;
 }
+
+/// Forwards to the boxed storage, so a backend selected at runtime can be used as a `Box<dyn JwkStorage>` wherever
+/// a [`JwkStorage`] is expected, e.g. in a [`Storage`](crate::Storage).
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<S: JwkStorage + ?Sized> JwkStorage for Box<S> {
+  async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    (**self).generate(key_type, alg).await
+  }
+
+  async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
+    (**self).insert(jwk).await
+  }
+
+  async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    (**self).sign(key_id, data, public_key).await
+  }
+
+  async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    (**self).delete(key_id).await
+  }
+
+  async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    (**self).exists(key_id).await
+  }
+}
@@ -1,6 +1,8 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_verification::jose::jwk::Jwk;
+
 /// An identifier for a private key stored in a key storage.
 ///
 /// This type is returned by a key storage implementation when
@@ -14,6 +16,15 @@ impl KeyId {
     Self(id.into())
   }
 
+  /// Derives a key identifier from the [RFC 7638](https://tools.ietf.org/html/rfc7638) SHA-256 thumbprint of `jwk`.
+  ///
+  /// The thumbprint only covers the public parameters, so a private JWK and its public counterpart yield the same
+  /// id, which lets independent storages agree on the id of a replicated key. Distinct keys collide only if their
+  /// thumbprints do, but the same key always maps to the same id and the id reveals which public key it belongs to.
+  pub fn from_public_key(jwk: &Jwk) -> Self {
+    Self(jwk.thumbprint_sha256_b64())
+  }
+
   /// Returns string representation of the key id.
   pub fn as_str(&self) -> &str {
     &self.0
@@ -26,6 +26,10 @@ pub enum KeyStorageErrorKind {
   /// Indicates that the key storage implementation is not able to find the requested key.
   KeyNotFound,
 
+  /// Indicates an attempt to use private key material that the key storage does not hold, e.g. signing with the
+  /// public view of a [`Storage`](crate::storage::Storage).
+  PrivateKeyUnavailable,
+
   /// Indicates that the storage is unavailable for an unpredictable amount of time.
   ///
   /// Occurrences of this variant should hopefully be rare, but could occur if hardware fails, or a hosted key store
@@ -59,6 +63,7 @@ impl KeyStorageErrorKind {
       Self::KeyAlgorithmMismatch => "the key type cannot be used with the algorithm",
       Self::UnsupportedSignatureAlgorithm => "signing algorithm parsing failed",
       Self::KeyNotFound => "key not found in storage",
+      Self::PrivateKeyUnavailable => "the key storage does not hold private key material",
       Self::Unavailable => "key storage unavailable",
       Self::Unauthenticated => "authentication with the key storage failed",
       Self::Unspecified => "key storage operation failed",
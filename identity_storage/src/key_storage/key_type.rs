@@ -3,6 +3,8 @@
 
 use std::borrow::Cow;
 
+use identity_verification::jose::jws::JwsAlgorithm;
+
 /// The type or class of a cryptographic key.
 ///
 /// Each storage implementation should expose constants of this type to signal
@@ -27,6 +29,24 @@ impl KeyType {
   pub fn as_str(&self) -> &str {
     &self.0
   }
+
+  /// Returns the canonical [`JwsAlgorithm`] to sign with keys of this type, or `None` if there is no
+  /// well-known default.
+  ///
+  /// Key types are matched against the curve names registered for JWKs, e.g. `Ed25519` maps to
+  /// [`JwsAlgorithm::EdDSA`] and `P-256` to [`JwsAlgorithm::ES256`].
+  ///
+  /// Note that this does not imply that a storage supports the key type or algorithm.
+  pub fn default_jws_algorithm(&self) -> Option<JwsAlgorithm> {
+    match self.as_str() {
+      "Ed25519" | "Ed448" => Some(JwsAlgorithm::EdDSA),
+      "P-256" => Some(JwsAlgorithm::ES256),
+      "P-384" => Some(JwsAlgorithm::ES384),
+      "P-521" => Some(JwsAlgorithm::ES512),
+      "secp256k1" => Some(JwsAlgorithm::ES256K),
+      _ => None,
+    }
+  }
 }
 
 impl From<String> for KeyType {
@@ -52,3 +72,21 @@ impl std::fmt::Display for KeyType {
     f.write_str(&self.0)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use identity_verification::jose::jws::JwsAlgorithm;
+
+  use super::KeyType;
+
+  #[test]
+  fn default_jws_algorithm() {
+    assert_eq!(
+      KeyType::from_static_str("Ed25519").default_jws_algorithm(),
+      Some(JwsAlgorithm::EdDSA)
+    );
+    assert_eq!(KeyType::new("P-256").default_jws_algorithm(), Some(JwsAlgorithm::ES256));
+    assert_eq!(KeyType::new("X25519").default_jws_algorithm(), None);
+    assert_eq!(KeyType::new("ed25519").default_jws_algorithm(), None);
+  }
+}
@@ -7,7 +7,10 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use async_trait::async_trait;
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
 use crypto::signatures::ed25519::SecretKey;
+use identity_verification::jose::jwk::EcCurve;
 use identity_verification::jose::jwk::EdCurve;
 use identity_verification::jose::jwk::Jwk;
 use identity_verification::jose::jwk::JwkType;
@@ -16,8 +19,10 @@ use rand::distributions::DistString;
 use shared::Shared;
 use tokio::sync::RwLockReadGuard;
 use tokio::sync::RwLockWriteGuard;
+use zeroize::Zeroize;
 
 use super::key_gen::JwkGenOutput;
+use super::AgreementInfo;
 use super::KeyId;
 use super::KeyStorageError;
 use super::KeyStorageErrorKind;
@@ -29,9 +34,16 @@ use crate::key_storage::JwkStorage;
 type JwkKeyStore = HashMap<KeyId, Jwk>;
 
 /// An insecure, in-memory [`JwkStorage`] implementation that serves as an example and may be used in tests.
+///
+/// Like [`KeyIdMemstore`](crate::key_id_storage::KeyIdMemstore), the store is guarded by an asynchronous read-write
+/// lock and may be shared between threads.
+///
+/// Keys are held as [`Jwk`]s, which zeroize their parameters when dropped, so private key material is overwritten
+/// when the store itself is dropped. Deleted keys are zeroized explicitly before they are dropped.
 #[derive(Debug)]
 pub struct JwkMemStore {
   jwk_store: Shared<JwkKeyStore>,
+  deterministic_key_ids: bool,
 }
 
 impl JwkMemStore {
@@ -39,6 +51,29 @@ impl JwkMemStore {
   pub fn new() -> Self {
     Self {
       jwk_store: Shared::new(HashMap::new()),
+      deterministic_key_ids: false,
+    }
+  }
+
+  /// Creates a new, empty `JwkMemStore` instance that derives the id of every key from its public key with
+  /// [`KeyId::from_public_key`] instead of generating a random one.
+  ///
+  /// Stores created this way assign the same id to the same key, e.g. when keys are replicated between them.
+  /// Since the id is derived from the public key, it links the key id to the key. Generating a key from the same
+  /// seed twice with [`JwkMemStore::generate_from_seed`] replaces the stored key with an identical one.
+  pub fn new_deterministic() -> Self {
+    Self {
+      jwk_store: Shared::new(HashMap::new()),
+      deterministic_key_ids: true,
+    }
+  }
+
+  /// Returns the id under which `jwk` is stored.
+  fn key_id(&self, jwk: &Jwk) -> KeyId {
+    if self.deterministic_key_ids {
+      KeyId::from_public_key(jwk)
+    } else {
+      random_key_id()
     }
   }
 
@@ -46,6 +81,98 @@ impl JwkMemStore {
   pub async fn count(&self) -> usize {
     self.jwk_store.read().await.keys().count()
   }
+
+  /// Generates a new X25519 key for key agreement and returns its key id and public JWK.
+  ///
+  /// Such keys cannot be used for signing, but only with [`JwkMemStore::key_agreement`].
+  pub async fn generate_x25519(&self) -> KeyStorageResult<JwkGenOutput> {
+    let private_key = x25519::SecretKey::generate()
+      .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::RetryableIOFailure).with_source(err))?;
+    let public_key = private_key.public_key();
+
+    let jwk: Jwk = x25519::encode_jwk(&private_key, &public_key);
+    let kid: KeyId = self.key_id(&jwk);
+    // Unwrapping is OK because the None variant only occurs for kty = oct.
+    let mut public_jwk: Jwk = jwk.to_public().unwrap();
+    public_jwk.set_kid(kid.clone());
+
+    let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = self.jwk_store.write().await;
+    jwk_store.insert(kid.clone(), jwk);
+
+    Ok(JwkGenOutput::new(kid, public_jwk))
+  }
+
+  /// Deterministically generates a new key of the given `key_type` from `seed` and returns its key id.
+  ///
+  /// The same seed always yields the same key pair, which makes this useful for reproducible test fixtures.
+  /// The secret key is the SHA-256 digest of `seed`, so this must not be used as a source of production entropy.
+  pub async fn generate_from_seed(&self, key_type: KeyType, seed: &[u8]) -> KeyStorageResult<KeyId> {
+    let key_type: MemStoreKeyType = MemStoreKeyType::try_from(&key_type)?;
+
+    let (private_key, public_key) = match key_type {
+      MemStoreKeyType::Ed25519 => {
+        let mut secret: [u8; SHA256_LEN] = [0; SHA256_LEN];
+        SHA256(seed, &mut secret);
+        let private_key = SecretKey::from_bytes(secret);
+        secret.zeroize();
+        let public_key = private_key.public_key();
+        (private_key, public_key)
+      }
+      #[cfg(feature = "ed448")]
+      MemStoreKeyType::Ed448 => {
+        return Err(
+          KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+            .with_custom_message(format!("cannot deterministically generate {key_type} keys")),
+        );
+      }
+      MemStoreKeyType::Secp256k1 => {
+        return Err(
+          KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+            .with_custom_message(format!("cannot deterministically generate {key_type} keys")),
+        );
+      }
+    };
+
+    let mut jwk: Jwk = ed25519::encode_jwk(&private_key, &public_key);
+    jwk.set_alg(JwsAlgorithm::EdDSA.name());
+    let kid: KeyId = self.key_id(&jwk);
+
+    let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = self.jwk_store.write().await;
+    jwk_store.insert(kid.clone(), jwk);
+
+    Ok(kid)
+  }
+
+  /// Performs an ECDH-ES key agreement between the X25519 key identified by `key_id` and `peer_public_key`
+  /// and derives a 256-bit key from the shared secret using the Concat KDF with the given `agreement_info`.
+  ///
+  /// Both parties derive the same key when using the same `agreement_info`.
+  ///
+  /// # Errors
+  ///
+  /// Fails with [`KeyStorageErrorKind::UnsupportedKeyType`] if either key is not an X25519 key.
+  pub async fn key_agreement(
+    &self,
+    key_id: &KeyId,
+    peer_public_key: &Jwk,
+    agreement_info: &AgreementInfo,
+  ) -> KeyStorageResult<Vec<u8>> {
+    let jwk_store: RwLockReadGuard<'_, JwkKeyStore> = self.jwk_store.read().await;
+
+    let jwk: &Jwk = jwk_store
+      .get(key_id)
+      .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))?;
+    let secret_key: x25519::SecretKey = x25519::expand_secret_jwk(jwk)?;
+    let peer_public_key: x25519::PublicKey = x25519::expand_public_jwk(peer_public_key)?;
+
+    let shared_secret: [u8; 32] = secret_key.diffie_hellman(&peer_public_key).to_bytes();
+    Ok(x25519::concat_kdf(
+      x25519::ECDH_ES,
+      x25519::DERIVED_KEY_LEN,
+      &shared_secret,
+      agreement_info,
+    ))
+  }
 }
 
 // Refer to the `JwkStorage` interface docs for high-level documentation of the individual methods.
@@ -57,18 +184,26 @@ impl JwkStorage for JwkMemStore {
 
     check_key_alg_compatibility(key_type, alg)?;
 
-    let (private_key, public_key) = match key_type {
+    let mut jwk: Jwk = match key_type {
       MemStoreKeyType::Ed25519 => {
         let private_key = SecretKey::generate()
           .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::RetryableIOFailure).with_source(err))?;
         let public_key = private_key.public_key();
-        (private_key, public_key)
+        ed25519::encode_jwk(&private_key, &public_key)
+      }
+      #[cfg(feature = "ed448")]
+      MemStoreKeyType::Ed448 => {
+        let signing_key = ed448_goldilocks_plus::SigningKey::generate(&mut rand::thread_rng());
+        ed448::encode_jwk(&signing_key)
+      }
+      MemStoreKeyType::Secp256k1 => {
+        let signing_key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        secp256k1::encode_jwk(&signing_key)
       }
     };
 
-    let kid: KeyId = random_key_id();
+    let kid: KeyId = self.key_id(&jwk);
 
-    let mut jwk: Jwk = ed25519::encode_jwk(&private_key, &public_key);
     jwk.set_alg(alg.name());
     // Unwrapping is OK because the None variant only occurs for kty = oct.
     let mut public_jwk: Jwk = jwk.to_public().unwrap();
@@ -111,10 +246,9 @@ impl JwkStorage for JwkMemStore {
       );
     }
 
-    let key_id: KeyId = random_key_id();
+    let key_id: KeyId = self.key_id(&jwk);
 
     let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = self.jwk_store.write().await;
-
     jwk_store.insert(key_id.clone(), jwk);
 
     Ok(key_id)
@@ -131,7 +265,13 @@ impl JwkStorage for JwkMemStore {
         JwsAlgorithm::from_str(alg_str).map_err(|_| KeyStorageErrorKind::UnsupportedSignatureAlgorithm)
       })?;
 
-    // Check that `kty` is `Okp` and `crv = Ed25519`.
+    // Obtain the corresponding private key.
+    let jwk: &Jwk = jwk_store
+      .get(key_id)
+      .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))?;
+    let key_type: MemStoreKeyType = MemStoreKeyType::try_from(jwk)?;
+
+    // Check that `kty` is `Okp` and `crv` matches the curve of the stored key.
     match alg {
       JwsAlgorithm::EdDSA => {
         let okp_params = public_key.try_okp_params().map_err(|err| {
@@ -139,11 +279,24 @@ impl JwkStorage for JwkMemStore {
             .with_custom_message(format!("expected a Jwk with Okp params in order to sign with {alg}"))
             .with_source(err)
         })?;
-        if okp_params.crv != EdCurve::Ed25519.name() {
+        if okp_params.crv != key_type.name() {
+          return Err(
+            KeyStorageError::new(KeyStorageErrorKind::KeyAlgorithmMismatch).with_custom_message(format!(
+              "expected Jwk with Okp {key_type} crv in order to sign with {alg} using this key"
+            )),
+          );
+        }
+      }
+      JwsAlgorithm::ES256K => {
+        let ec_params = public_key.try_ec_params().map_err(|err| {
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message(format!("expected a Jwk with Ec params in order to sign with {alg}"))
+            .with_source(err)
+        })?;
+        if ec_params.crv != key_type.name() {
           return Err(
-            KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message(format!(
-              "expected Jwk with Okp {} crv in order to sign with {alg}",
-              EdCurve::Ed25519
+            KeyStorageError::new(KeyStorageErrorKind::KeyAlgorithmMismatch).with_custom_message(format!(
+              "expected Jwk with Ec {key_type} crv in order to sign with {alg} using this key"
             )),
           );
         }
@@ -156,19 +309,33 @@ impl JwkStorage for JwkMemStore {
       }
     };
 
-    // Obtain the corresponding private key and sign `data`.
-    let jwk: &Jwk = jwk_store
-      .get(key_id)
-      .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))?;
-    let secret_key = ed25519::expand_secret_jwk(jwk)?;
-    Ok(secret_key.sign(data).to_bytes().to_vec())
+    // Sign `data`.
+    match key_type {
+      MemStoreKeyType::Ed25519 => {
+        let secret_key = ed25519::expand_secret_jwk(jwk)?;
+        Ok(secret_key.sign(data).to_bytes().to_vec())
+      }
+      #[cfg(feature = "ed448")]
+      MemStoreKeyType::Ed448 => {
+        let signing_key = ed448::expand_secret_jwk(jwk)?;
+        let signature = signing_key.sign_raw(data).map_err(|err| {
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message("Ed448 signing failed")
+            .with_source(err)
+        })?;
+        Ok(signature.to_bytes().to_vec())
+      }
+      MemStoreKeyType::Secp256k1 => {
+        let signing_key = secp256k1::expand_secret_jwk(jwk)?;
+        Ok(secp256k1::sign(&signing_key, data))
+      }
+    }
   }
 
   async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
     let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = self.jwk_store.write().await;
 
-    jwk_store
-      .remove(key_id)
+    remove_zeroized(&mut jwk_store, key_id)
       .map(|_| ())
       .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))
   }
@@ -239,22 +406,184 @@ pub(crate) mod ed25519 {
   }
 }
 
+#[cfg(feature = "ed448")]
+pub(crate) mod ed448 {
+  use ed448_goldilocks_plus::SigningKey;
+  use identity_verification::jose::jwk::EdCurve;
+  use identity_verification::jose::jwk::Jwk;
+  use identity_verification::jose::jwk::JwkParamsOkp;
+  use identity_verification::jose::jwu;
+
+  use crate::key_storage::KeyStorageError;
+  use crate::key_storage::KeyStorageErrorKind;
+  use crate::key_storage::KeyStorageResult;
+
+  pub(crate) fn expand_secret_jwk(jwk: &Jwk) -> KeyStorageResult<SigningKey> {
+    let params: &JwkParamsOkp = jwk.try_okp_params().map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+        .with_custom_message("expected Okp parameters")
+        .with_source(err)
+    })?;
+
+    if params
+      .try_ed_curve()
+      .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType).with_source(err))?
+      != EdCurve::Ed448
+    {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+          .with_custom_message(format!("expected an {} key", EdCurve::Ed448.name())),
+      );
+    }
+
+    let sk: Vec<u8> = params
+      .d
+      .as_deref()
+      .map(jwu::decode_b64)
+      .ok_or_else(|| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("expected Jwk `d` param to be present")
+      })?
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("unable to decode `d` param")
+          .with_source(err)
+      })?;
+
+    SigningKey::try_from(sk.as_slice()).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message(format!("invalid Ed448 key: {err}"))
+    })
+  }
+
+  pub(crate) fn encode_jwk(signing_key: &SigningKey) -> Jwk {
+    let x = jwu::encode_b64(signing_key.verifying_key().as_bytes());
+    let d = jwu::encode_b64(signing_key.as_bytes());
+    let mut params = JwkParamsOkp::new();
+    params.x = x;
+    params.d = Some(d);
+    params.crv = EdCurve::Ed448.name().to_owned();
+    Jwk::from_params(params)
+  }
+}
+
+pub(crate) mod secp256k1 {
+  use identity_verification::jose::jwk::EcCurve;
+  use identity_verification::jose::jwk::Jwk;
+  use identity_verification::jose::jwk::JwkParamsEc;
+  use identity_verification::jose::jwu;
+  use k256::ecdsa::signature::Signer;
+  use k256::ecdsa::Signature;
+  use k256::ecdsa::SigningKey;
+  use k256::EncodedPoint;
+
+  use crate::key_storage::KeyStorageError;
+  use crate::key_storage::KeyStorageErrorKind;
+  use crate::key_storage::KeyStorageResult;
+
+  pub(crate) fn expand_secret_jwk(jwk: &Jwk) -> KeyStorageResult<SigningKey> {
+    let params: &JwkParamsEc = jwk.try_ec_params().map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+        .with_custom_message("expected Ec parameters")
+        .with_source(err)
+    })?;
+
+    if params
+      .try_ec_curve()
+      .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType).with_source(err))?
+      != EcCurve::Secp256K1
+    {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+          .with_custom_message(format!("expected a {} key", EcCurve::Secp256K1.name())),
+      );
+    }
+
+    let sk: Vec<u8> = params
+      .d
+      .as_deref()
+      .map(jwu::decode_b64)
+      .ok_or_else(|| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("expected Jwk `d` param to be present")
+      })?
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("unable to decode `d` param")
+          .with_source(err)
+      })?;
+
+    SigningKey::from_slice(&sk).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("invalid secp256k1 secret key")
+        .with_source(err)
+    })
+  }
+
+  pub(crate) fn encode_jwk(signing_key: &SigningKey) -> Jwk {
+    let point: EncodedPoint = signing_key.verifying_key().to_encoded_point(false);
+    let mut params = JwkParamsEc::new();
+    // Unwrapping is OK because uncompressed points always contain both coordinates.
+    params.x = jwu::encode_b64(point.x().unwrap());
+    params.y = jwu::encode_b64(point.y().unwrap());
+    params.d = Some(jwu::encode_b64(signing_key.to_bytes()));
+    params.crv = EcCurve::Secp256K1.name().to_owned();
+    Jwk::from_params(params)
+  }
+
+  /// Signs the SHA-256 digest of `data` and returns the signature as the concatenation of `r` and `s`, as expected
+  /// by `ES256K`.
+  ///
+  /// The signature is normalized to a low `s` value to avoid malleability.
+  pub(crate) fn sign(signing_key: &SigningKey, data: &[u8]) -> Vec<u8> {
+    let signature: Signature = signing_key.sign(data);
+    let signature: Signature = signature.normalize_s().unwrap_or(signature);
+    signature.to_bytes().to_vec()
+  }
+}
+
 const ED25519_KEY_TYPE_STR: &str = "Ed25519";
 pub const ED25519_KEY_TYPE: KeyType = KeyType::from_static_str(ED25519_KEY_TYPE_STR);
+#[cfg(feature = "ed448")]
+const ED448_KEY_TYPE_STR: &str = "Ed448";
+#[cfg(feature = "ed448")]
+pub const ED448_KEY_TYPE: KeyType = KeyType::from_static_str(ED448_KEY_TYPE_STR);
+const SECP256K1_KEY_TYPE_STR: &str = "secp256k1";
+pub const SECP256K1_KEY_TYPE: KeyType = KeyType::from_static_str(SECP256K1_KEY_TYPE_STR);
 
 #[derive(Debug, Copy, Clone)]
 enum MemStoreKeyType {
   Ed25519,
+  #[cfg(feature = "ed448")]
+  Ed448,
+  Secp256k1,
 }
 
+/// The key types supported by [`JwkMemStore`].
+#[cfg(feature = "ed448")]
+static SUPPORTED_KEY_TYPES: &[KeyType] = &[ED25519_KEY_TYPE, ED448_KEY_TYPE, SECP256K1_KEY_TYPE];
+/// The key types supported by [`JwkMemStore`].
+#[cfg(not(feature = "ed448"))]
+static SUPPORTED_KEY_TYPES: &[KeyType] = &[ED25519_KEY_TYPE, SECP256K1_KEY_TYPE];
+
 impl JwkMemStore {
   pub const ED25519_KEY_TYPE: KeyType = ED25519_KEY_TYPE;
+  #[cfg(feature = "ed448")]
+  pub const ED448_KEY_TYPE: KeyType = ED448_KEY_TYPE;
+  pub const SECP256K1_KEY_TYPE: KeyType = SECP256K1_KEY_TYPE;
+
+  /// Returns the key types that can be passed to [`JwkStorage::generate`] on this storage.
+  pub fn supported_key_types() -> &'static [KeyType] {
+    SUPPORTED_KEY_TYPES
+  }
 }
 
 impl MemStoreKeyType {
   pub const fn name(&self) -> &'static str {
     match self {
-      MemStoreKeyType::Ed25519 => "Ed25519",
+      MemStoreKeyType::Ed25519 => ED25519_KEY_TYPE_STR,
+      #[cfg(feature = "ed448")]
+      MemStoreKeyType::Ed448 => ED448_KEY_TYPE_STR,
+      MemStoreKeyType::Secp256k1 => SECP256K1_KEY_TYPE_STR,
     }
   }
 }
@@ -271,6 +600,9 @@ impl TryFrom<&KeyType> for MemStoreKeyType {
   fn try_from(value: &KeyType) -> Result<Self, Self::Error> {
     match value.as_str() {
       ED25519_KEY_TYPE_STR => Ok(MemStoreKeyType::Ed25519),
+      #[cfg(feature = "ed448")]
+      ED448_KEY_TYPE_STR => Ok(MemStoreKeyType::Ed448),
+      SECP256K1_KEY_TYPE_STR => Ok(MemStoreKeyType::Secp256k1),
       _ => Err(KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)),
     }
   }
@@ -293,12 +625,26 @@ impl TryFrom<&Jwk> for MemStoreKeyType {
             .with_source(err)
         })? {
           EdCurve::Ed25519 => Ok(MemStoreKeyType::Ed25519),
-          curve => Err(
+          #[cfg(feature = "ed448")]
+          EdCurve::Ed448 => Ok(MemStoreKeyType::Ed448),
+          #[cfg(not(feature = "ed448"))]
+          EdCurve::Ed448 => Err(
             KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
-              .with_custom_message(format!("{curve} not supported")),
+              .with_custom_message("Ed448 keys require the `ed448` feature"),
           ),
         }
       }
+      JwkType::Ec => match jwk.try_ec_curve().map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+          .with_custom_message("expected Ec parameters for a JWK with `kty` Ec")
+          .with_source(err)
+      })? {
+        EcCurve::Secp256K1 => Ok(MemStoreKeyType::Secp256k1),
+        curve => Err(
+          KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+            .with_custom_message(format!("{curve} not supported")),
+        ),
+      },
       other => Err(
         KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
           .with_custom_message(format!("Jwk `kty` {other} not supported")),
@@ -313,6 +659,131 @@ impl Default for JwkMemStore {
   }
 }
 
+/// Removes the key identified by `key_id` from `jwk_store` and overwrites its private components
+/// before handing it back, so no key material outlives the removal.
+fn remove_zeroized(jwk_store: &mut JwkKeyStore, key_id: &KeyId) -> Option<Jwk> {
+  let mut jwk: Jwk = jwk_store.remove(key_id)?;
+  jwk.zeroize();
+  Some(jwk)
+}
+
+pub(crate) mod x25519 {
+  use crypto::hashes::sha::SHA256;
+  use crypto::hashes::sha::SHA256_LEN;
+  use crypto::keys::x25519;
+  pub(crate) use crypto::keys::x25519::PublicKey;
+  pub(crate) use crypto::keys::x25519::SecretKey;
+  use identity_verification::jose::jwk::EcxCurve;
+  use identity_verification::jose::jwk::Jwk;
+  use identity_verification::jose::jwk::JwkParamsOkp;
+  use identity_verification::jose::jwu;
+
+  use crate::key_storage::AgreementInfo;
+  use crate::key_storage::KeyStorageError;
+  use crate::key_storage::KeyStorageErrorKind;
+  use crate::key_storage::KeyStorageResult;
+
+  /// The algorithm identifier used in the Concat KDF for direct key agreement.
+  pub(crate) const ECDH_ES: &str = "ECDH-ES";
+  /// The length in bytes of keys derived by [`JwkMemStore::key_agreement`](super::JwkMemStore::key_agreement).
+  pub(crate) const DERIVED_KEY_LEN: usize = 32;
+
+  pub(crate) fn expand_secret_jwk(jwk: &Jwk) -> KeyStorageResult<SecretKey> {
+    let params: &JwkParamsOkp = okp_params(jwk)?;
+    let sk: [u8; x25519::SECRET_KEY_LENGTH] = params
+      .d
+      .as_deref()
+      .map(jwu::decode_b64)
+      .ok_or_else(|| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("expected Jwk `d` param to be present")
+      })?
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("unable to decode `d` param")
+          .with_source(err)
+      })?
+      .try_into()
+      .map_err(|_| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message(format!("expected key of length {}", x25519::SECRET_KEY_LENGTH))
+      })?;
+
+    Ok(SecretKey::from_bytes(sk))
+  }
+
+  pub(crate) fn expand_public_jwk(jwk: &Jwk) -> KeyStorageResult<PublicKey> {
+    let params: &JwkParamsOkp = okp_params(jwk)?;
+    let pk: [u8; x25519::PUBLIC_KEY_LENGTH] = jwu::decode_b64(&params.x)
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("unable to decode `x` param")
+          .with_source(err)
+      })?
+      .try_into()
+      .map_err(|_| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message(format!("expected key of length {}", x25519::PUBLIC_KEY_LENGTH))
+      })?;
+
+    Ok(PublicKey::from_bytes(pk))
+  }
+
+  pub(crate) fn encode_jwk(private_key: &SecretKey, public_key: &PublicKey) -> Jwk {
+    let x = jwu::encode_b64(public_key.to_bytes());
+    let d = jwu::encode_b64(private_key.to_bytes());
+    let mut params = JwkParamsOkp::new();
+    params.x = x;
+    params.d = Some(d);
+    params.crv = EcxCurve::X25519.name().to_owned();
+    Jwk::from_params(params)
+  }
+
+  /// Derives a key of `len` bytes from the shared secret `z` in accordance with the Concat KDF as defined in
+  /// [RFC 7518 section 4.6.2](https://www.rfc-editor.org/rfc/rfc7518#section-4.6.2), using SHA-256.
+  pub(crate) fn concat_kdf(alg: &str, len: usize, z: &[u8], agreement_info: &AgreementInfo) -> Vec<u8> {
+    let length_prefixed = |data: &[u8]| [&(data.len() as u32).to_be_bytes()[..], data].concat();
+    let other_info: Vec<u8> = [
+      length_prefixed(alg.as_bytes()),
+      length_prefixed(&agreement_info.apu),
+      length_prefixed(&agreement_info.apv),
+      agreement_info.pub_info.clone(),
+      agreement_info.priv_info.clone(),
+    ]
+    .concat();
+
+    let mut output: Vec<u8> = Vec::with_capacity(len + SHA256_LEN);
+    let mut round: u32 = 1;
+    while output.len() < len {
+      let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+      SHA256(&[&round.to_be_bytes()[..], z, &other_info].concat(), &mut digest);
+      output.extend_from_slice(&digest);
+      round += 1;
+    }
+    output.truncate(len);
+    output
+  }
+
+  fn okp_params(jwk: &Jwk) -> KeyStorageResult<&JwkParamsOkp> {
+    let params: &JwkParamsOkp = jwk.try_okp_params().map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+        .with_custom_message("expected Okp parameters for key agreement")
+        .with_source(err)
+    })?;
+    if params
+      .try_ecx_curve()
+      .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType).with_source(err))?
+      != EcxCurve::X25519
+    {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+          .with_custom_message(format!("expected an {} key", EcxCurve::X25519.name())),
+      );
+    }
+    Ok(params)
+  }
+}
+
 /// Generate a random alphanumeric string of len 32.
 fn random_key_id() -> KeyId {
   KeyId::new(rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 32))
@@ -322,6 +793,9 @@ fn random_key_id() -> KeyId {
 fn check_key_alg_compatibility(key_type: MemStoreKeyType, alg: JwsAlgorithm) -> KeyStorageResult<()> {
   match (key_type, alg) {
     (MemStoreKeyType::Ed25519, JwsAlgorithm::EdDSA) => Ok(()),
+    #[cfg(feature = "ed448")]
+    (MemStoreKeyType::Ed448, JwsAlgorithm::EdDSA) => Ok(()),
+    (MemStoreKeyType::Secp256k1, JwsAlgorithm::ES256K) => Ok(()),
     (key_type, alg) => Err(
       KeyStorageError::new(crate::key_storage::KeyStorageErrorKind::KeyAlgorithmMismatch)
         .with_custom_message(format!("`cannot use key type `{key_type}` with algorithm `{alg}`")),
@@ -336,6 +810,8 @@ pub(crate) mod shared {
   use tokio::sync::RwLockReadGuard;
   use tokio::sync::RwLockWriteGuard;
 
+  /// Interior mutability for the in-memory stores. The lock is `Send + Sync` whenever `T` is, which makes the stores
+  /// usable from multiple threads.
   #[derive(Default)]
   pub struct Shared<T>(RwLock<T>);
 
@@ -366,12 +842,36 @@ mod tests {
   use crypto::signatures::ed25519::Signature;
   use crypto::signatures::ed25519::{self};
   use identity_verification::jose::jwk::EcCurve;
+  use identity_verification::jose::jwk::JwkParams;
   use identity_verification::jose::jwk::JwkParamsEc;
   use identity_verification::jose::jwk::JwkParamsOkp;
   use identity_verification::jose::jwu;
+  use static_assertions::assert_impl_all;
+  use subtle::ConstantTimeEq;
 
   use super::*;
 
+  assert_impl_all!(JwkMemStore: Send, Sync);
+
+  /// Compares the private key material of two JWKs in constant time.
+  ///
+  /// Public parameters are compared with ordinary equality. Returns `false` if either JWK is not a private OKP or EC
+  /// key. Private JWKs must be compared with this function rather than `==`.
+  fn private_jwk_eq(a: &Jwk, b: &Jwk) -> bool {
+    fn private_component(jwk: &Jwk) -> Option<&str> {
+      match jwk.params() {
+        JwkParams::Okp(params) => params.d.as_deref(),
+        JwkParams::Ec(params) => params.d.as_deref(),
+        JwkParams::Rsa(_) | JwkParams::Oct(_) => None,
+      }
+    }
+    let (Some(d_a), Some(d_b)) = (private_component(a), private_component(b)) else {
+      return false;
+    };
+    // The lengths of the private components are not secret, `ct_eq` returns early on a mismatch.
+    a.params().to_public() == b.params().to_public() && bool::from(d_a.as_bytes().ct_eq(d_b.as_bytes()))
+  }
+
   #[tokio::test]
   async fn generate_and_sign() {
     let test_msg: &[u8] = b"test";
@@ -409,6 +909,301 @@ mod tests {
     assert!(matches!(err.kind(), KeyStorageErrorKind::Unspecified))
   }
 
+  #[test]
+  fn compare_private_jwks() {
+    let (private_key, public_key) = generate_ed25519();
+    let jwk: Jwk = crate::key_storage::ed25519::encode_jwk(&private_key, &public_key);
+    let (other_private_key, other_public_key) = generate_ed25519();
+    let other_jwk: Jwk = crate::key_storage::ed25519::encode_jwk(&other_private_key, &other_public_key);
+
+    assert!(private_jwk_eq(&jwk, &jwk.clone()));
+    assert!(!private_jwk_eq(&jwk, &other_jwk));
+
+    // The same private component with different public parameters does not match.
+    let mut mixed_jwk: Jwk = other_jwk.clone();
+    let JwkParams::Okp(params) = mixed_jwk.params_mut() else {
+      unreachable!()
+    };
+    params.d = jwk.try_okp_params().unwrap().d.clone();
+    assert!(!private_jwk_eq(&jwk, &mixed_jwk));
+
+    // Public keys have no private component to compare.
+    assert!(!private_jwk_eq(&jwk.to_public().unwrap(), &jwk.to_public().unwrap()));
+  }
+
+  #[tokio::test]
+  async fn deterministic_key_ids() {
+    let (private_key, public_key) = generate_ed25519();
+    let mut jwk: Jwk = crate::key_storage::ed25519::encode_jwk(&private_key, &public_key);
+    jwk.set_alg(JwsAlgorithm::EdDSA.name());
+
+    let store_a: JwkMemStore = JwkMemStore::new_deterministic();
+    let store_b: JwkMemStore = JwkMemStore::new_deterministic();
+    let key_id: KeyId = store_a.insert(jwk.clone()).await.unwrap();
+    assert_eq!(store_b.insert(jwk.clone()).await.unwrap(), key_id);
+    assert_eq!(key_id, KeyId::from_public_key(&jwk.to_public().unwrap()));
+
+    let JwkGenOutput { key_id, jwk } = store_a.generate(ED25519_KEY_TYPE, JwsAlgorithm::EdDSA).await.unwrap();
+    assert_eq!(key_id, KeyId::from_public_key(&jwk));
+
+    // Random ids are used by default.
+    let store: JwkMemStore = JwkMemStore::new();
+    let (private_key, public_key) = generate_ed25519();
+    let mut jwk: Jwk = crate::key_storage::ed25519::encode_jwk(&private_key, &public_key);
+    jwk.set_alg(JwsAlgorithm::EdDSA.name());
+    assert_ne!(store.insert(jwk.clone()).await.unwrap(), KeyId::from_public_key(&jwk));
+  }
+
+  #[tokio::test]
+  async fn delete_zeroizes_private_key() {
+    let store: JwkMemStore = JwkMemStore::new();
+    let JwkGenOutput { key_id, .. } = store.generate(ED25519_KEY_TYPE, JwsAlgorithm::EdDSA).await.unwrap();
+
+    let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = store.jwk_store.write().await;
+    assert!(jwk_store.get(&key_id).unwrap().is_private());
+
+    // The removed key is handed back by the helper `delete` uses, which exposes its buffer after zeroization.
+    let removed: Jwk = remove_zeroized(&mut jwk_store, &key_id).unwrap();
+    let JwkParams::Okp(params) = removed.params() else {
+      panic!("expected Okp params");
+    };
+    assert!(params.d.is_none());
+    assert!(params.x.is_empty());
+    assert!(!jwk_store.contains_key(&key_id));
+    assert!(remove_zeroized(&mut jwk_store, &key_id).is_none());
+  }
+
+  #[tokio::test]
+  async fn supported_key_types() {
+    let key_types: &[KeyType] = JwkMemStore::supported_key_types();
+    assert!(key_types.contains(&JwkMemStore::ED25519_KEY_TYPE));
+
+    // Every advertised key type can be used for key generation.
+    let store: JwkMemStore = JwkMemStore::new();
+    for key_type in key_types {
+      assert!(MemStoreKeyType::try_from(key_type).is_ok());
+      let alg: JwsAlgorithm = key_type.default_jws_algorithm().unwrap();
+      store.generate(key_type.clone(), alg).await.unwrap();
+    }
+  }
+
+  #[tokio::test]
+  async fn x25519_key_agreement() {
+    let alice_store: JwkMemStore = JwkMemStore::new();
+    let bob_store: JwkMemStore = JwkMemStore::new();
+    let alice: JwkGenOutput = alice_store.generate_x25519().await.unwrap();
+    let bob: JwkGenOutput = bob_store.generate_x25519().await.unwrap();
+    assert!(!alice.jwk.is_private());
+
+    let agreement_info: AgreementInfo = AgreementInfo::new(b"Alice".to_vec(), b"Bob".to_vec(), Vec::new(), Vec::new());
+    let alice_key: Vec<u8> = alice_store
+      .key_agreement(&alice.key_id, &bob.jwk, &agreement_info)
+      .await
+      .unwrap();
+    let bob_key: Vec<u8> = bob_store
+      .key_agreement(&bob.key_id, &alice.jwk, &agreement_info)
+      .await
+      .unwrap();
+    assert_eq!(alice_key.len(), 32);
+    assert_eq!(alice_key, bob_key);
+
+    // Different agreement info results in a different key.
+    let other_info: AgreementInfo = AgreementInfo::new(b"Alice".to_vec(), b"Carol".to_vec(), Vec::new(), Vec::new());
+    let other_key: Vec<u8> = alice_store
+      .key_agreement(&alice.key_id, &bob.jwk, &other_info)
+      .await
+      .unwrap();
+    assert_ne!(alice_key, other_key);
+
+    // X25519 keys cannot be used for signing.
+    let mut public_jwk: Jwk = alice.jwk.clone();
+    public_jwk.set_alg(JwsAlgorithm::EdDSA.name());
+    let err = alice_store.sign(&alice.key_id, b"test", &public_jwk).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::UnsupportedKeyType));
+  }
+
+  #[tokio::test]
+  async fn key_agreement_rejects_non_x25519_keys() {
+    let store: JwkMemStore = JwkMemStore::new();
+    let x25519: JwkGenOutput = store.generate_x25519().await.unwrap();
+    let ed25519: JwkGenOutput = store.generate(ED25519_KEY_TYPE, JwsAlgorithm::EdDSA).await.unwrap();
+    let agreement_info: AgreementInfo = AgreementInfo::new(Vec::new(), Vec::new(), Vec::new(), Vec::new());
+
+    // INVALID: the stored key is an Ed25519 key.
+    let err = store
+      .key_agreement(&ed25519.key_id, &x25519.jwk, &agreement_info)
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::UnsupportedKeyType));
+
+    // INVALID: the peer key is an Ed25519 key.
+    let err = store
+      .key_agreement(&x25519.key_id, &ed25519.jwk, &agreement_info)
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::UnsupportedKeyType));
+
+    // INVALID: unknown key id.
+    let err = store
+      .key_agreement(&KeyId::new("non-existent-id"), &x25519.jwk, &agreement_info)
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::KeyNotFound));
+  }
+
+  #[test]
+  fn concat_kdf_test_vector() {
+    // Test vector from https://www.rfc-editor.org/rfc/rfc7518#appendix-C.
+    let z: [u8; 32] = [
+      158, 86, 217, 29, 129, 113, 53, 211, 114, 131, 66, 131, 191, 132, 38, 156, 251, 49, 110, 163, 218, 128, 106, 72,
+      246, 218, 167, 121, 140, 254, 144, 196,
+    ];
+    let agreement_info: AgreementInfo = AgreementInfo::new(
+      b"Alice".to_vec(),
+      b"Bob".to_vec(),
+      128u32.to_be_bytes().to_vec(),
+      Vec::new(),
+    );
+    let derived_key: Vec<u8> = x25519::concat_kdf("A128GCM", 16, &z, &agreement_info);
+    assert_eq!(jwu::encode_b64(derived_key), "VqqN6vgjbSBcIijNcacQGg");
+  }
+
+  #[cfg(feature = "ed448")]
+  #[tokio::test]
+  async fn ed448_generate_and_sign() {
+    use identity_verification::jose::jws::EdDSAJwsVerifier;
+    use identity_verification::jose::jws::JwsVerifier;
+    use identity_verification::jose::jws::VerificationInput;
+
+    let test_msg: &[u8] = b"test";
+    let store: JwkMemStore = JwkMemStore::new();
+
+    let JwkGenOutput { key_id, jwk } = store.generate(ED448_KEY_TYPE, JwsAlgorithm::EdDSA).await.unwrap();
+    assert_eq!(jwk.try_okp_params().unwrap().crv, EdCurve::Ed448.name());
+
+    let signature: Vec<u8> = store.sign(&key_id, test_msg, &jwk).await.unwrap();
+
+    let input = |signing_input: &[u8]| VerificationInput {
+      alg: JwsAlgorithm::EdDSA,
+      signing_input: signing_input.into(),
+      decoded_signature: signature.clone().into_boxed_slice(),
+    };
+    EdDSAJwsVerifier::default().verify(input(test_msg), &jwk).unwrap();
+    assert!(EdDSAJwsVerifier::default()
+      .verify(input(b"other message"), &jwk)
+      .is_err());
+  }
+
+  #[cfg(feature = "ed448")]
+  #[tokio::test]
+  async fn sign_rejects_mismatched_curve() {
+    let store: JwkMemStore = JwkMemStore::new();
+    let ed25519: JwkGenOutput = store.generate(ED25519_KEY_TYPE, JwsAlgorithm::EdDSA).await.unwrap();
+    let ed448: JwkGenOutput = store.generate(ED448_KEY_TYPE, JwsAlgorithm::EdDSA).await.unwrap();
+
+    let err = store.sign(&ed448.key_id, b"test", &ed25519.jwk).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::KeyAlgorithmMismatch));
+    let err = store.sign(&ed25519.key_id, b"test", &ed448.jwk).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::KeyAlgorithmMismatch));
+  }
+
+  #[tokio::test]
+  async fn es256k_sign_and_verify() {
+    use identity_verification::jose::jws::ES256KJwsVerifier;
+    use identity_verification::jose::jws::JwsVerifier;
+    use identity_verification::jose::jws::VerificationInput;
+
+    let test_msg: &[u8] = b"test";
+    let store: JwkMemStore = JwkMemStore::new();
+
+    let JwkGenOutput { key_id, jwk } = store.generate(SECP256K1_KEY_TYPE, JwsAlgorithm::ES256K).await.unwrap();
+    assert_eq!(jwk.try_ec_params().unwrap().crv, EcCurve::Secp256K1.name());
+
+    let signature: Vec<u8> = store.sign(&key_id, test_msg, &jwk).await.unwrap();
+    assert_eq!(signature.len(), 64);
+    // The signature is normalized to a low `s` value.
+    assert!(k256::ecdsa::Signature::from_slice(&signature)
+      .unwrap()
+      .normalize_s()
+      .is_none());
+
+    let input = |signing_input: &[u8]| VerificationInput {
+      alg: JwsAlgorithm::ES256K,
+      signing_input: signing_input.into(),
+      decoded_signature: signature.clone().into_boxed_slice(),
+    };
+    ES256KJwsVerifier::default().verify(input(test_msg), &jwk).unwrap();
+    assert!(ES256KJwsVerifier::default()
+      .verify(input(b"other message"), &jwk)
+      .is_err());
+
+    // Signing with a mismatched algorithm fails.
+    let ed25519: JwkGenOutput = store.generate(ED25519_KEY_TYPE, JwsAlgorithm::EdDSA).await.unwrap();
+    let err = store.sign(&key_id, test_msg, &ed25519.jwk).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::KeyAlgorithmMismatch));
+  }
+
+  #[tokio::test]
+  async fn es256k_rejects_high_s_signature() {
+    use identity_verification::jose::jws::ES256KJwsVerifier;
+    use identity_verification::jose::jws::JwsVerifier;
+    use identity_verification::jose::jws::SignatureVerificationErrorKind;
+    use identity_verification::jose::jws::VerificationInput;
+    use k256::ecdsa::Signature;
+
+    let test_msg: &[u8] = b"test";
+    let store: JwkMemStore = JwkMemStore::new();
+    let JwkGenOutput { key_id, jwk } = store.generate(SECP256K1_KEY_TYPE, JwsAlgorithm::ES256K).await.unwrap();
+    let signature: Signature = Signature::from_slice(&store.sign(&key_id, test_msg, &jwk).await.unwrap()).unwrap();
+
+    // (r, n - s) is an equally valid ECDSA signature, but with a high `s` value.
+    let (r, s) = signature.split_scalars();
+    let high_s_signature: Signature = Signature::from_scalars(r.to_bytes(), (-*s).to_bytes()).unwrap();
+    assert!(high_s_signature.normalize_s().is_some());
+
+    let input = |signature: Signature| VerificationInput {
+      alg: JwsAlgorithm::ES256K,
+      signing_input: test_msg.into(),
+      decoded_signature: signature.to_vec().into_boxed_slice(),
+    };
+    ES256KJwsVerifier::default().verify(input(signature), &jwk).unwrap();
+    let err = ES256KJwsVerifier::default()
+      .verify(input(high_s_signature), &jwk)
+      .unwrap_err();
+    assert!(matches!(err.kind(), SignatureVerificationErrorKind::InvalidSignature));
+    // Rejected for its `s` value, before the signature itself is verified.
+    assert_eq!(
+      err.custom_message(),
+      Some("signatures with a high s value are not accepted")
+    );
+  }
+
+  #[tokio::test]
+  async fn generate_from_seed() {
+    let store: JwkMemStore = JwkMemStore::new();
+    let seed: &[u8] = b"reproducible test fixture";
+
+    let key_id_1: KeyId = store.generate_from_seed(ED25519_KEY_TYPE, seed).await.unwrap();
+    let key_id_2: KeyId = store.generate_from_seed(ED25519_KEY_TYPE, seed).await.unwrap();
+    let key_id_3: KeyId = store.generate_from_seed(ED25519_KEY_TYPE, b"other seed").await.unwrap();
+    assert_ne!(key_id_1, key_id_2);
+
+    let jwk_store = store.jwk_store.read().await;
+    let public_key_1: Jwk = jwk_store.get(&key_id_1).unwrap().to_public().unwrap();
+    let public_key_2: Jwk = jwk_store.get(&key_id_2).unwrap().to_public().unwrap();
+    let public_key_3: Jwk = jwk_store.get(&key_id_3).unwrap().to_public().unwrap();
+    assert_eq!(public_key_1, public_key_2);
+    assert_ne!(public_key_1, public_key_3);
+    drop(jwk_store);
+
+    // The generated key can be used for signing.
+    let test_msg: &[u8] = b"test";
+    let signature = store.sign(&key_id_1, test_msg, &public_key_1).await.unwrap();
+    let public_key: PublicKey = expand_public_jwk(&public_key_1);
+    let signature: Signature = Signature::from_bytes(signature.try_into().unwrap());
+    assert!(public_key.verify(&signature, test_msg));
+  }
+
   #[tokio::test]
   async fn exists() {
     let store: JwkMemStore = JwkMemStore::new();
@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod agreement_info;
 mod jwk_storage;
 mod key_gen;
 mod key_id;
@@ -8,7 +9,9 @@ mod key_storage_error;
 mod key_type;
 #[cfg(feature = "memstore")]
 mod memstore;
+mod public_key_storage;
 
+pub use agreement_info::*;
 pub use jwk_storage::*;
 pub use key_gen::*;
 pub use key_id::*;
@@ -16,3 +19,4 @@ pub use key_storage_error::*;
 pub use key_type::*;
 #[cfg(feature = "memstore")]
 pub use memstore::*;
+pub use public_key_storage::*;
@@ -0,0 +1,58 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jws::JwsAlgorithm;
+
+use super::JwkGenOutput;
+use super::JwkStorage;
+use super::KeyId;
+use super::KeyStorageError;
+use super::KeyStorageErrorKind;
+use super::KeyStorageResult;
+use super::KeyType;
+
+/// A [`JwkStorage`] without any private key material, used by the public view of a
+/// [`Storage`](crate::storage::Storage) (see [`Storage::public_view`](crate::storage::Storage::public_view)).
+///
+/// Every operation requiring a private key fails with [`KeyStorageErrorKind::PrivateKeyUnavailable`],
+/// verification only requires the public keys contained in a DID document.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct PublicKeyStorage;
+
+impl PublicKeyStorage {
+  /// Creates a new [`PublicKeyStorage`].
+  pub fn new() -> Self {
+    Self
+  }
+
+  fn unavailable() -> KeyStorageError {
+    KeyStorageError::new(KeyStorageErrorKind::PrivateKeyUnavailable)
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl JwkStorage for PublicKeyStorage {
+  async fn generate(&self, _key_type: KeyType, _alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    Err(Self::unavailable())
+  }
+
+  async fn insert(&self, _jwk: Jwk) -> KeyStorageResult<KeyId> {
+    Err(Self::unavailable())
+  }
+
+  async fn sign(&self, _key_id: &KeyId, _data: &[u8], _public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    Err(Self::unavailable())
+  }
+
+  async fn delete(&self, _key_id: &KeyId) -> KeyStorageResult<()> {
+    Err(Self::unavailable())
+  }
+
+  async fn exists(&self, _key_id: &KeyId) -> KeyStorageResult<bool> {
+    Ok(false)
+  }
+}
@@ -0,0 +1,145 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Object;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::Storage;
+use super::StorageResult;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyType;
+
+/// Describes a verification method to be generated by [`CoreDocumentStorageExt::new_with_storage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MethodSpec {
+  /// The type of the key to generate, which must be supported by the storage.
+  pub key_type: KeyType,
+  /// The algorithm the generated key is used with.
+  pub alg: JwsAlgorithm,
+  /// The fragment of the method, the `kid` of the generated JWK is used if not set.
+  pub fragment: Option<String>,
+  /// The scope under which the method is inserted into the document.
+  pub scope: MethodScope,
+}
+
+impl MethodSpec {
+  /// Creates a new [`MethodSpec`] without a fragment.
+  pub fn new(key_type: KeyType, alg: JwsAlgorithm, scope: MethodScope) -> Self {
+    Self {
+      key_type,
+      alg,
+      fragment: None,
+      scope,
+    }
+  }
+
+  /// Sets the fragment of the method.
+  pub fn fragment(mut self, fragment: impl Into<String>) -> Self {
+    self.fragment = Some(fragment.into());
+    self
+  }
+}
+
+/// Extension trait to bootstrap a [`CoreDocument`] whose methods are backed by a [`Storage`].
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait CoreDocumentStorageExt: private::Sealed + Sized {
+  /// Creates a new document identified by `did` and generates a verification method in the given `storage` for each
+  /// of the given `methods`, in order.
+  ///
+  /// # Errors
+  ///
+  /// Fails if a method cannot be generated, see [`JwkDocumentExt::generate_method`]. The methods generated up to that
+  /// point are purged from the `storage` again, if that fails as well
+  /// [`JwkStorageDocumentError::UndoOperationFailed`](Error::UndoOperationFailed) is returned.
+  async fn new_with_storage<K, I>(did: CoreDID, storage: &Storage<K, I>, methods: &[MethodSpec]) -> StorageResult<Self>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+}
+
+mod private {
+  pub trait Sealed {}
+  impl Sealed for identity_document::document::CoreDocument {}
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl CoreDocumentStorageExt for CoreDocument {
+  async fn new_with_storage<K, I>(did: CoreDID, storage: &Storage<K, I>, methods: &[MethodSpec]) -> StorageResult<Self>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let mut document: CoreDocument = CoreDocument::builder(Object::new())
+      .id(did)
+      .build()
+      .expect("a document with an id should be valid");
+
+    let mut generated: Vec<DIDUrl> = Vec::with_capacity(methods.len());
+    for spec in methods {
+      let result: StorageResult<String> = document
+        .generate_method(
+          storage,
+          spec.key_type.clone(),
+          spec.alg,
+          spec.fragment.as_deref(),
+          spec.scope,
+        )
+        .await;
+
+      match result {
+        Ok(fragment) => {
+          let method_id: DIDUrl = document
+            .resolve_method(fragment.as_str(), None)
+            .expect("the method was just generated")
+            .id()
+            .clone();
+          generated.push(method_id);
+        }
+        Err(source) => return Err(try_undo_generation(&mut document, storage, &generated, source).await),
+      }
+    }
+
+    Ok(document)
+  }
+}
+
+/// Attempt to purge the `generated` methods, if this succeeds the original `source_error` is returned,
+/// otherwise [`JwkStorageDocumentError::UndoOperationFailed`](Error::UndoOperationFailed) is returned with the
+/// `source_error` attached as `source`.
+async fn try_undo_generation<K, I>(
+  document: &mut CoreDocument,
+  storage: &Storage<K, I>,
+  generated: &[DIDUrl],
+  source_error: Error,
+) -> Error
+where
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  let mut undo_error: Option<Error> = None;
+  for method_id in generated {
+    if let Err(err) = document.purge_method(storage, method_id).await {
+      undo_error.get_or_insert(err);
+    }
+  }
+
+  match undo_error {
+    Some(undo_error) => Error::UndoOperationFailed {
+      message: "unable to purge the generated methods".to_owned(),
+      source: Box::new(source_error),
+      undo_error: Some(Box::new(undo_error)),
+    },
+    None => source_error,
+  }
+}
@@ -30,6 +30,15 @@ pub enum JwkStorageDocumentError {
   MethodDigestConstructionError(#[source] MethodDigestConstructionError),
   #[error("could not produce JWS payload from the given claims: serialization failed")]
   ClaimsSerializationError(#[source] identity_credential::Error),
+  #[error("cannot make `{0}` selectively disclosable: no such claim in the credential subject")]
+  UndisclosableClaim(String),
+  #[error("SD-JWT verification failed: {0}")]
+  SdJwtVerificationError(
+    &'static str,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
+  #[error("presentation verification failed")]
+  PresentationVerificationError(#[source] identity_credential::validator::ValidationError),
   #[error("storage operation failed after altering state. Unable to undo operation(s): {message}")]
   UndoOperationFailed {
     message: String,
@@ -15,25 +15,43 @@ use super::Storage;
 
 use super::JwsSignatureOptions;
 use async_trait::async_trait;
+use identity_core::common::OrderedSet;
 use identity_credential::credential::Credential;
 use identity_credential::credential::Jws;
 use identity_credential::credential::Jwt;
 use identity_credential::presentation::JwtPresentation;
 use identity_credential::presentation::JwtPresentationOptions;
+use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_document::document::CoreDocument;
+use identity_verification::jose::jwk::Jwk;
 use identity_verification::jose::jws::CompactJwsEncoder;
 use identity_verification::jose::jws::CompactJwsEncodingOptions;
 use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::jose::jws::JwsHeader;
 use identity_verification::jws::CharSet;
 use identity_verification::MethodData;
+use identity_verification::MethodRef;
+use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
 use identity_verification::VerificationMethod;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 pub type StorageResult<T> = Result<T, Error>;
 
+/// Declares how [`JwkDocumentExt::merge_methods`] handles a method whose fragment is already in use by a method of
+/// the receiving document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConflictPolicy {
+  /// Keep the existing method and do not merge the conflicting one.
+  Skip,
+  /// Replace the existing method, and all references to it, with the conflicting one.
+  Overwrite,
+  /// Fail with [`JwkStorageDocumentError::FragmentAlreadyExists`](Error::FragmentAlreadyExists) without merging any
+  /// method.
+  Error,
+}
+
 #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync-storage", async_trait)]
 pub trait JwkDocumentExt: private::Sealed {
@@ -65,6 +83,30 @@ pub trait JwkDocumentExt: private::Sealed {
     K: JwkStorage,
     I: KeyIdStorage;
 
+  /// Replace the key material of the method identified by the given `fragment` with a newly generated key in the given
+  /// `storage`.
+  ///
+  /// The method keeps its fragment, scope and the verification relationships referencing it, only its public key
+  /// material changes. The key of the rotated method, and its key identifier, are deleted from the `storage` only
+  /// after the new key has been stored and the method has been updated.
+  ///
+  /// # Errors
+  ///
+  /// - [`JwkStorageDocumentError::MethodNotFound`](Error::MethodNotFound) if no method is identified by `fragment`.
+  /// - [`JwkStorageDocumentError::NotPublicKeyJwk`](Error::NotPublicKeyJwk) if the method is not backed by a JWK.
+  /// - [`JwkStorageDocumentError::UndoOperationFailed`](Error::UndoOperationFailed) if the method has been rotated,
+  ///   but the previous key could not be deleted from the `storage`.
+  async fn rotate_method<K, I>(
+    &mut self,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+  ) -> StorageResult<()>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+
   /// Sign the `payload` according to `options` with the storage backed private key corresponding to the public key
   /// material in the verification method identified by the given `fragment.
   ///
@@ -86,6 +128,13 @@ pub trait JwkDocumentExt: private::Sealed {
   ///
   /// The `kid` in the protected header is the `id` of the method identified by `fragment` and the JWS signature will be
   /// produced by the corresponding private key backed by the `storage` in accordance with the passed `options`.
+  ///
+  /// # Errors
+  ///
+  /// - [`JwkStorageDocumentError::MethodNotFound`](Error::MethodNotFound) if no method is identified by `fragment`.
+  /// - [`JwkStorageDocumentError::KeyIdStorageError`](Error::KeyIdStorageError) or
+  ///   [`JwkStorageDocumentError::KeyStorageError`](Error::KeyStorageError) if the key of the method is not present in
+  ///   the `storage`.
   async fn sign_credential<K, I, T>(
     &self,
     credential: &Credential<T>,
@@ -98,11 +147,68 @@ pub trait JwkDocumentExt: private::Sealed {
     I: KeyIdStorage,
     T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync;
 
+  /// Signs each of the given `credentials` into a JWT with the storage backed private key corresponding to the public
+  /// key material in the verification method identified by `fragment`.
+  ///
+  /// The method and its key identifier are only looked up once for the whole batch. The returned JWTs are in the same
+  /// order as `credentials`.
+  ///
+  /// # Errors
+  ///
+  /// Fails with the first error encountered, see [`JwkDocumentExt::sign_credential`].
+  async fn create_credential_jwts<K, I, T>(
+    &self,
+    credentials: &[Credential<T>],
+    storage: &Storage<K, I>,
+    fragment: &str,
+    options: &JwsSignatureOptions,
+  ) -> StorageResult<Vec<Jwt>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync;
+
+  /// Produces an [SD-JWT](https://datatracker.ietf.org/doc/draft-ietf-oauth-selective-disclosure-jwt/) from the given
+  /// `credential`, in which the claims of the first credential subject identified by the JSON Pointers in
+  /// `disclosable`, e.g. `/degree/name`, are replaced by digests of their [`Disclosure`](super::Disclosure)s.
+  ///
+  /// Apart from the concealed claims, the JWT is produced as in [`JwkDocumentExt::sign_credential`].
+  ///
+  /// # Errors
+  ///
+  /// Besides the errors of [`JwkDocumentExt::sign_credential`],
+  /// [`JwkStorageDocumentError::UndisclosableClaim`](Error::UndisclosableClaim) is returned if a pointer does not
+  /// identify a claim of the first credential subject.
+  #[cfg(feature = "sd-jwt")]
+  async fn create_sd_jwt<K, I, T>(
+    &self,
+    credential: &Credential<T>,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    disclosable: &[&str],
+    options: &JwsSignatureOptions,
+  ) -> StorageResult<super::SdJwt>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+  {
+    check_credential_jwt_options(options)?;
+
+    let payload: String = credential.serialize_jwt().map_err(Error::ClaimsSerializationError)?;
+    let (payload, disclosures) = super::sd_jwt::conceal_claims(&payload, disclosable)?;
+    let jws: Jws = self.sign_bytes(storage, fragment, payload.as_bytes(), options).await?;
+    Ok(super::SdJwt::new(Jwt::new(jws.into()), disclosures))
+  }
+
   /// Produces a JWT where the payload is produced from the given `presentation`
   /// in accordance with [VC-JWT version 1.1](https://w3c.github.io/vc-jwt/#version-1.1).
   ///
   /// The `kid` in the protected header is the `id` of the method identified by `fragment` and the JWS signature will be
   /// produced by the corresponding private key backed by the `storage` in accordance with the passed `options`.
+  ///
+  /// If the `presentation` has a [`holder_binding`](JwtPresentation::holder_binding), the referenced method must
+  /// exist in this document, otherwise [`MethodNotFound`](Error::MethodNotFound) is returned.
   async fn sign_presentation<K, I, T>(
     &self,
     presentation: &JwtPresentation<T>,
@@ -115,6 +221,28 @@ pub trait JwkDocumentExt: private::Sealed {
     K: JwkStorage,
     I: KeyIdStorage,
     T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync;
+
+  /// Returns the verification methods with the given `scope` whose public key material is a JWK, i.e. the methods
+  /// that may be used with [`Storage`].
+  ///
+  /// Methods referenced by a verification relationship are resolved to their embedded definition in this document.
+  /// References that cannot be resolved are skipped.
+  fn methods_with_scope(&self, scope: MethodScope) -> Vec<&VerificationMethod>;
+
+  /// Copies the verification methods of `other` into this document, e.g. when migrating methods between storages.
+  ///
+  /// The copied methods are identified by this document's DID and keep their fragment, scope and the verification
+  /// relationships under which `other` references them. Since the [`MethodDigest`] of a method only depends on its
+  /// fragment and public key material, key identifiers already stored for the methods of `other` remain usable.
+  ///
+  /// Methods whose fragment is already in use by a method of this document are handled according to `on_conflict`.
+  ///
+  /// # Errors
+  ///
+  /// [`JwkStorageDocumentError::FragmentAlreadyExists`](Error::FragmentAlreadyExists) if `on_conflict` is
+  /// [`ConflictPolicy::Error`] and a fragment is already in use, in which case this document is left unchanged, or if
+  /// a fragment is in use by a service.
+  fn merge_methods(&mut self, other: &CoreDocument, on_conflict: ConflictPolicy) -> StorageResult<()>;
 }
 mod private {
   pub trait Sealed {}
@@ -277,12 +405,141 @@ macro_rules! purge_method_for_document_type {
   };
 }
 
+macro_rules! rotate_method_for_document_type {
+  ($t:ty, $name:ident) => {
+    async fn $name<K, I>(
+      document: &mut $t,
+      storage: &Storage<K, I>,
+      fragment: &str,
+      key_type: KeyType,
+      alg: JwsAlgorithm,
+    ) -> StorageResult<()>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+    {
+      let method: VerificationMethod = document
+        .resolve_method(fragment, None)
+        .ok_or(Error::MethodNotFound)?
+        .clone();
+      if !matches!(method.data(), MethodData::PublicKeyJwk(_)) {
+        return Err(Error::NotPublicKeyJwk);
+      }
+      let old_method_digest: MethodDigest = MethodDigest::new(&method).map_err(Error::MethodDigestConstructionError)?;
+      let old_key_id: KeyId = <I as KeyIdStorage>::get_key_id(&storage.key_id_storage(), &old_method_digest)
+        .await
+        .map_err(Error::KeyIdStorageError)?;
+
+      let JwkGenOutput { key_id, jwk } = <K as JwkStorage>::generate(&storage.key_storage(), key_type, alg)
+        .await
+        .map_err(Error::KeyStorageError)?;
+      if !jwk.is_public() {
+        let error = Error::VerificationMethodConstructionError(identity_verification::Error::PrivateKeyMaterialExposed);
+        return Err(try_undo_key_generation(storage, &key_id, error).await);
+      }
+
+      // The rotated method only differs in its public key material.
+      let mut rotated_method: VerificationMethod = method;
+      *rotated_method.data_mut() = MethodData::PublicKeyJwk(jwk);
+      let method_digest: MethodDigest =
+        match MethodDigest::new(&rotated_method).map_err(Error::MethodDigestConstructionError) {
+          Ok(digest) => digest,
+          Err(error) => {
+            return Err(try_undo_key_generation(storage, &key_id, error).await);
+          }
+        };
+
+      if let Err(error) = <I as KeyIdStorage>::insert_key_id(&storage.key_id_storage(), method_digest, key_id.clone())
+        .await
+        .map_err(Error::KeyIdStorageError)
+      {
+        return Err(try_undo_key_generation(storage, &key_id, error).await);
+      }
+
+      // Commit the rotation. The method was resolved above and the document has not been modified since.
+      *document
+        .resolve_method_mut(fragment, None)
+        .expect("the rotated method should still be contained in the document") = rotated_method;
+
+      // Delete the previous key and key id concurrently, now that the new key is in use.
+      let key_deletion_fut = <K as JwkStorage>::delete(&storage.key_storage(), &old_key_id);
+      let key_id_deletion_fut = <I as KeyIdStorage>::delete_key_id(&storage.key_id_storage(), &old_method_digest);
+      let (key_deletion_result, key_id_deletion_result): (KeyStorageResult<()>, KeyIdStorageResult<()>) =
+        futures::join!(key_deletion_fut, key_id_deletion_fut);
+      match (key_deletion_result, key_id_deletion_result) {
+        (Ok(_), Ok(_)) => Ok(()),
+        (Err(key_deletion_error), _) => Err(Error::UndoOperationFailed {
+          message: format!("the method was rotated, but this results in a stray key with key id: {old_key_id}"),
+          source: Box::new(Error::KeyStorageError(key_deletion_error)),
+          undo_error: None,
+        }),
+        (Ok(_), Err(key_id_deletion_error)) => Err(Error::UndoOperationFailed {
+          message: format!(
+            "the method was rotated, but this results in a stray key id stored under packed method digest: {:?}",
+            &old_method_digest.pack()
+          ),
+          source: Box::new(Error::KeyIdStorageError(key_id_deletion_error)),
+          undo_error: None,
+        }),
+      }
+    }
+  };
+}
+
+macro_rules! merge_methods_for_document_type {
+  ($t:ty, $name:ident) => {
+    fn $name(document: &mut $t, other: &CoreDocument, on_conflict: ConflictPolicy) -> StorageResult<()> {
+      let did: CoreDID = <$t as AsRef<CoreDocument>>::as_ref(document).id().clone();
+      let methods: Vec<MergedMethod> = methods_to_merge(&did, other)?;
+
+      // Check all fragments upfront so the document is left unchanged on error.
+      if on_conflict == ConflictPolicy::Error
+        && methods
+          .iter()
+          .any(|merged| document.resolve_method(merged.method.id(), None).is_some())
+      {
+        return Err(Error::FragmentAlreadyExists);
+      }
+
+      for MergedMethod {
+        method,
+        scope,
+        references,
+      } in methods
+      {
+        if document.resolve_method(method.id(), None).is_some() {
+          match on_conflict {
+            ConflictPolicy::Skip => continue,
+            ConflictPolicy::Overwrite => {
+              let _ = document.remove_method(method.id());
+            }
+            ConflictPolicy::Error => return Err(Error::FragmentAlreadyExists),
+          }
+        }
+
+        let method_id: DIDUrl = method.id().clone();
+        document
+          .insert_method(method, scope)
+          .map_err(|_| Error::FragmentAlreadyExists)?;
+        for relationship in references {
+          // Cannot fail since the method was just inserted as a general-purpose method.
+          let _ = document.attach_method_relationship(&method_id, relationship);
+        }
+      }
+
+      Ok(())
+    }
+  };
+}
+
 // ====================================================================================================================
 // CoreDocument
 // ====================================================================================================================
 
 generate_method_for_document_type!(CoreDocument, generate_method_core_document);
 purge_method_for_document_type!(CoreDocument, purge_method_core_document);
+rotate_method_for_document_type!(CoreDocument, rotate_method_core_document);
+merge_methods_for_document_type!(CoreDocument, merge_methods_core_document);
 
 #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync-storage", async_trait)]
@@ -310,6 +567,20 @@ impl JwkDocumentExt for CoreDocument {
     purge_method_core_document(self, storage, id).await
   }
 
+  async fn rotate_method<K, I>(
+    &mut self,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+  ) -> StorageResult<()>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    rotate_method_core_document(self, storage, fragment, key_type, alg).await
+  }
+
   async fn sign_bytes<K, I>(
     &self,
     storage: &Storage<K, I>,
@@ -326,74 +597,9 @@ impl JwkDocumentExt for CoreDocument {
     let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
       return Err(Error::NotPublicKeyJwk)
     };
-    // Extract JwsAlgorithm
-    let alg: JwsAlgorithm = jwk
-      .alg()
-      .unwrap_or("")
-      .parse()
-      .map_err(|_| Error::InvalidJwsAlgorithm)?;
-
-    // create JWS header in accordance with options
-    let header: JwsHeader = {
-      let mut header = JwsHeader::new();
-
-      header.set_alg(alg);
-
-      header.set_kid(method.id().to_string());
-
-      if options.attach_jwk {
-        header.set_jwk(jwk.clone())
-      };
-
-      if let Some(b64) = options.b64 {
-        // Follow recommendation in https://datatracker.ietf.org/doc/html/rfc7797#section-7.
-        if !b64 {
-          header.set_b64(b64);
-          header.set_crit(["b64"]);
-        }
-      };
-
-      if let Some(typ) = &options.typ {
-        header.set_typ(typ.clone())
-      };
-
-      if let Some(cty) = &options.cty {
-        header.set_cty(cty.clone())
-      };
-
-      if let Some(url) = &options.url {
-        header.set_url(url.clone())
-      };
-
-      if let Some(nonce) = &options.nonce {
-        header.set_nonce(nonce.clone())
-      };
-      header
-    };
-
-    // Get the key identifier corresponding to the given method from the KeyId storage.
-    let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
-    let key_id = <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest)
-      .await
-      .map_err(Error::KeyIdStorageError)?;
-
-    // Extract Compact JWS encoding options.
-    let encoding_options: CompactJwsEncodingOptions = if !options.detached_payload {
-      // We use this as a default and don't provide the extra UrlSafe check for now.
-      // Applications that require such checks can easily do so after JWS creation.
-      CompactJwsEncodingOptions::NonDetached {
-        charset_requirements: CharSet::Default,
-      }
-    } else {
-      CompactJwsEncodingOptions::Detached
-    };
-
-    let jws_encoder: CompactJwsEncoder = CompactJwsEncoder::new_with_options(payload, &header, encoding_options)
-      .map_err(|err| Error::EncodingError(err.into()))?;
-    let signature = <K as JwkStorage>::sign(storage.key_storage(), &key_id, jws_encoder.signing_input(), jwk)
-      .await
-      .map_err(Error::KeyStorageError)?;
-    Ok(Jws::new(jws_encoder.into_jws(&signature)))
+    let header: JwsHeader = jws_header(method, jwk, options)?;
+    let key_id: KeyId = method_key_id(storage, method).await?;
+    sign_with_key(storage, &key_id, jwk, &header, payload, options).await
   }
 
   async fn sign_credential<K, I, T>(
@@ -408,18 +614,7 @@ impl JwkDocumentExt for CoreDocument {
     I: KeyIdStorage,
     T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
   {
-    if options.detached_payload {
-      return Err(Error::EncodingError(Box::<dyn std::error::Error + Send + Sync>::from(
-        "cannot use detached payload for credential signing",
-      )));
-    }
-
-    if !options.b64.unwrap_or(true) {
-      // JWTs should not have `b64` set per https://datatracker.ietf.org/doc/html/rfc7797#section-7.
-      return Err(Error::EncodingError(Box::<dyn std::error::Error + Send + Sync>::from(
-        "cannot use `b64 = false` with JWTs",
-      )));
-    }
+    check_credential_jwt_options(options)?;
 
     let payload = credential.serialize_jwt().map_err(Error::ClaimsSerializationError)?;
     self
@@ -428,6 +623,37 @@ impl JwkDocumentExt for CoreDocument {
       .map(|jws| Jwt::new(jws.into()))
   }
 
+  async fn create_credential_jwts<K, I, T>(
+    &self,
+    credentials: &[Credential<T>],
+    storage: &Storage<K, I>,
+    fragment: &str,
+    options: &JwsSignatureOptions,
+  ) -> StorageResult<Vec<Jwt>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+  {
+    check_credential_jwt_options(options)?;
+
+    // Resolve the method, header and key identifier once and reuse them for every credential.
+    let method: &VerificationMethod = self.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
+    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+      return Err(Error::NotPublicKeyJwk)
+    };
+    let header: JwsHeader = jws_header(method, jwk, options)?;
+    let key_id: KeyId = method_key_id(storage, method).await?;
+
+    let mut jwts: Vec<Jwt> = Vec::with_capacity(credentials.len());
+    for credential in credentials {
+      let payload = credential.serialize_jwt().map_err(Error::ClaimsSerializationError)?;
+      let jws: Jws = sign_with_key(storage, &key_id, jwk, &header, payload.as_bytes(), options).await?;
+      jwts.push(Jwt::new(jws.into()));
+    }
+    Ok(jwts)
+  }
+
   async fn sign_presentation<K, I, T>(
     &self,
     presentation: &JwtPresentation<T>,
@@ -453,6 +679,14 @@ impl JwkDocumentExt for CoreDocument {
         "cannot use `b64 = false` with JWTs",
       )));
     }
+
+    // Ensure the key referenced by the holder binding exists.
+    if let Some(holder_binding) = presentation.holder_binding.as_ref() {
+      self
+        .resolve_method(holder_binding.kid.as_str(), None)
+        .ok_or(Error::MethodNotFound)?;
+    }
+
     let payload = presentation
       .serialize_jwt(jwt_options)
       .map_err(Error::ClaimsSerializationError)?;
@@ -461,6 +695,18 @@ impl JwkDocumentExt for CoreDocument {
       .await
       .map(|jws| Jwt::new(jws.into()))
   }
+
+  fn methods_with_scope(&self, scope: MethodScope) -> Vec<&VerificationMethod> {
+    self
+      .methods(Some(scope))
+      .into_iter()
+      .filter(|method| matches!(method.data(), MethodData::PublicKeyJwk(_)))
+      .collect()
+  }
+
+  fn merge_methods(&mut self, other: &CoreDocument, on_conflict: ConflictPolicy) -> StorageResult<()> {
+    merge_methods_core_document(self, other, on_conflict)
+  }
 }
 
 /// Attempt to revert key generation if this succeeds the original `source_error` is returned,
@@ -483,6 +729,163 @@ where
   }
 }
 
+/// A method of another document to be merged by [`JwkDocumentExt::merge_methods`].
+struct MergedMethod {
+  method: VerificationMethod,
+  scope: MethodScope,
+  /// The relationships under which the other document references the method.
+  references: Vec<MethodRelationship>,
+}
+
+/// Collects the methods of `other`, identified by `did` instead of the DID of `other`.
+fn methods_to_merge(did: &CoreDID, other: &CoreDocument) -> StorageResult<Vec<MergedMethod>> {
+  let relationships: [(MethodRelationship, &OrderedSet<MethodRef>); 5] = [
+    (MethodRelationship::Authentication, other.authentication()),
+    (MethodRelationship::AssertionMethod, other.assertion_method()),
+    (MethodRelationship::KeyAgreement, other.key_agreement()),
+    (MethodRelationship::CapabilityDelegation, other.capability_delegation()),
+    (MethodRelationship::CapabilityInvocation, other.capability_invocation()),
+  ];
+
+  let general_methods = other.verification_method().iter().map(|method| {
+    let references: Vec<MethodRelationship> = relationships
+      .iter()
+      .filter(|(_, method_refs)| {
+        method_refs
+          .iter()
+          .any(|method_ref| matches!(method_ref, MethodRef::Refer(id) if id == method.id()))
+      })
+      .map(|(relationship, _)| *relationship)
+      .collect();
+    (method, MethodScope::VerificationMethod, references)
+  });
+  let embedded_methods = relationships.iter().flat_map(|(relationship, method_refs)| {
+    method_refs.iter().filter_map(move |method_ref| match method_ref {
+      MethodRef::Embed(method) => Some((method, MethodScope::VerificationRelationship(*relationship), Vec::new())),
+      MethodRef::Refer(_) => None,
+    })
+  });
+
+  general_methods
+    .chain(embedded_methods)
+    .map(|(method, scope, references)| {
+      let mut method: VerificationMethod = method.clone();
+      method
+        .set_id(DIDUrl::new(did.clone(), Some(method.id().url().clone())))
+        .map_err(Error::VerificationMethodConstructionError)?;
+      Ok(MergedMethod {
+        method,
+        scope,
+        references,
+      })
+    })
+    .collect()
+}
+
+/// Rejects signature options that cannot be used to produce a credential JWT.
+fn check_credential_jwt_options(options: &JwsSignatureOptions) -> StorageResult<()> {
+  if options.detached_payload {
+    return Err(Error::EncodingError(Box::<dyn std::error::Error + Send + Sync>::from(
+      "cannot use detached payload for credential signing",
+    )));
+  }
+
+  if !options.b64.unwrap_or(true) {
+    // JWTs should not have `b64` set per https://datatracker.ietf.org/doc/html/rfc7797#section-7.
+    return Err(Error::EncodingError(Box::<dyn std::error::Error + Send + Sync>::from(
+      "cannot use `b64 = false` with JWTs",
+    )));
+  }
+  Ok(())
+}
+
+/// Creates the protected header of a JWS signed with `jwk`, the public key of `method`, in accordance with `options`.
+fn jws_header(method: &VerificationMethod, jwk: &Jwk, options: &JwsSignatureOptions) -> StorageResult<JwsHeader> {
+  // Extract JwsAlgorithm
+  let alg: JwsAlgorithm = method.jws_algorithm().ok_or(Error::InvalidJwsAlgorithm)?;
+
+  // create JWS header in accordance with options
+  let mut header: JwsHeader = JwsHeader::new();
+
+  header.set_alg(alg);
+
+  header.set_kid(method.id().to_string());
+
+  if options.attach_jwk {
+    header.set_jwk(jwk.clone())
+  };
+
+  if let Some(b64) = options.b64 {
+    // Follow recommendation in https://datatracker.ietf.org/doc/html/rfc7797#section-7.
+    if !b64 {
+      header.set_b64(b64);
+      header.set_crit(["b64"]);
+    }
+  };
+
+  if let Some(typ) = &options.typ {
+    header.set_typ(typ.clone())
+  };
+
+  if let Some(cty) = &options.cty {
+    header.set_cty(cty.clone())
+  };
+
+  if let Some(url) = &options.url {
+    header.set_url(url.clone())
+  };
+
+  if let Some(nonce) = &options.nonce {
+    header.set_nonce(nonce.clone())
+  };
+  Ok(header)
+}
+
+/// Looks up the identifier of the key backing `method` in the key id storage.
+async fn method_key_id<K, I>(storage: &Storage<K, I>, method: &VerificationMethod) -> StorageResult<KeyId>
+where
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+  <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest)
+    .await
+    .map_err(Error::KeyIdStorageError)
+}
+
+/// Signs `payload` with the key identified by `key_id`, whose public key is `jwk`, producing a JWS with the given
+/// `header`.
+async fn sign_with_key<K, I>(
+  storage: &Storage<K, I>,
+  key_id: &KeyId,
+  jwk: &Jwk,
+  header: &JwsHeader,
+  payload: &[u8],
+  options: &JwsSignatureOptions,
+) -> StorageResult<Jws>
+where
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  // Extract Compact JWS encoding options.
+  let encoding_options: CompactJwsEncodingOptions = if !options.detached_payload {
+    // We use this as a default and don't provide the extra UrlSafe check for now.
+    // Applications that require such checks can easily do so after JWS creation.
+    CompactJwsEncodingOptions::NonDetached {
+      charset_requirements: CharSet::Default,
+    }
+  } else {
+    CompactJwsEncodingOptions::Detached
+  };
+
+  let jws_encoder: CompactJwsEncoder = CompactJwsEncoder::new_with_options(payload, header, encoding_options)
+    .map_err(|err| Error::EncodingError(err.into()))?;
+  let signature = <K as JwkStorage>::sign(storage.key_storage(), key_id, jws_encoder.signing_input(), jwk)
+    .await
+    .map_err(Error::KeyStorageError)?;
+  Ok(Jws::new(jws_encoder.into_jws(&signature)))
+}
+
 // ====================================================================================================================
 // IotaDocument
 // ====================================================================================================================
@@ -493,6 +896,8 @@ mod iota_document {
   use identity_iota_core::IotaDocument;
   generate_method_for_document_type!(IotaDocument, generate_method_iota_document);
   purge_method_for_document_type!(IotaDocument, purge_method_iota_document);
+  rotate_method_for_document_type!(IotaDocument, rotate_method_iota_document);
+  merge_methods_for_document_type!(IotaDocument, merge_methods_iota_document);
 
   #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
   #[cfg_attr(feature = "send-sync-storage", async_trait)]
@@ -520,6 +925,20 @@ mod iota_document {
       purge_method_iota_document(self, storage, id).await
     }
 
+    async fn rotate_method<K, I>(
+      &mut self,
+      storage: &Storage<K, I>,
+      fragment: &str,
+      key_type: KeyType,
+      alg: JwsAlgorithm,
+    ) -> StorageResult<()>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+    {
+      rotate_method_iota_document(self, storage, fragment, key_type, alg).await
+    }
+
     async fn sign_bytes<K, I>(
       &self,
       storage: &Storage<K, I>,
@@ -554,6 +973,24 @@ mod iota_document {
         .sign_credential(credential, storage, fragment, options)
         .await
     }
+
+    async fn create_credential_jwts<K, I, T>(
+      &self,
+      credentials: &[Credential<T>],
+      storage: &Storage<K, I>,
+      fragment: &str,
+      options: &JwsSignatureOptions,
+    ) -> StorageResult<Vec<Jwt>>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+      T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+    {
+      self
+        .core_document()
+        .create_credential_jwts(credentials, storage, fragment, options)
+        .await
+    }
     async fn sign_presentation<K, I, T>(
       &self,
       presentation: &JwtPresentation<T>,
@@ -572,5 +1009,13 @@ mod iota_document {
         .sign_presentation(presentation, storage, fragment, options, jwt_options)
         .await
     }
+
+    fn methods_with_scope(&self, scope: MethodScope) -> Vec<&VerificationMethod> {
+      self.core_document().methods_with_scope(scope)
+    }
+
+    fn merge_methods(&mut self, other: &CoreDocument, on_conflict: ConflictPolicy) -> StorageResult<()> {
+      merge_methods_iota_document(self, other, on_conflict)
+    }
   }
 }
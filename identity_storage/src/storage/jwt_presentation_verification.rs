@@ -0,0 +1,37 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_credential::credential::Jwt;
+use identity_credential::presentation::JwtPresentation;
+use identity_credential::validator::JwtPresentationValidationOptions;
+use identity_credential::validator::JwtPresentationValidator;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::JwkStorageDocumentError as Error;
+use super::StorageResult;
+
+/// Verifies the JWS of a presentation issued as a JWT, for instance one produced by
+/// [`JwkDocumentExt::sign_presentation`](crate::storage::JwkDocumentExt::sign_presentation), against the resolved
+/// DID Document of its `holder`.
+///
+/// The signature is verified with `signature_verifier` according to the
+/// [`presentation_verifier_options`](JwtPresentationValidationOptions::presentation_verifier_options) of `options`.
+/// The included credentials are not validated, see [`JwtPresentationValidator::validate`] for full validation.
+pub fn verify_jwt_presentation<HDOC, V, T>(
+  presentation: &Jwt,
+  holder: &HDOC,
+  signature_verifier: V,
+  options: &JwtPresentationValidationOptions,
+) -> StorageResult<JwtPresentation<T>>
+where
+  HDOC: AsRef<CoreDocument> + ?Sized,
+  V: JwsVerifier,
+  T: ToOwned<Owned = T> + Serialize + DeserializeOwned,
+{
+  JwtPresentationValidator::with_signature_verifier(signature_verifier)
+    .verify_signature(presentation, holder, &options.presentation_verifier_options)
+    .map_err(Error::PresentationVerificationError)
+}
@@ -1,15 +1,42 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
+mod document_template;
 mod error;
 mod jwk_document_ext;
+mod jwt_presentation_verification;
+#[cfg(feature = "sd-jwt")]
+mod sd_jwt;
 mod signature_options;
 #[cfg(all(test, feature = "memstore"))]
 mod tests;
 
+pub use document_template::*;
 pub use error::*;
 pub use jwk_document_ext::*;
+pub use jwt_presentation_verification::*;
+#[cfg(feature = "sd-jwt")]
+pub use sd_jwt::Disclosure;
+#[cfg(feature = "sd-jwt")]
+pub use sd_jwt::verify_sd_jwt;
+#[cfg(feature = "sd-jwt")]
+pub use sd_jwt::SdJwt;
 pub use signature_options::*;
 
+use std::collections::HashSet;
+
+use identity_document::document::CoreDocument;
+
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::KeyIdStorageErrorKind;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::PublicKeyStorage;
+
+/// A view of a [`Storage`] without access to private key material, obtained from [`Storage::public_view`].
+pub type PublicStorage<'storage, I> = Storage<PublicKeyStorage, &'storage I>;
+
 /// A type wrapping a [`JwkStorage`](crate::key_storage::JwkStorage) and
 /// [`KeyIdStorage`](crate::key_id_storage::KeyIdStorage) that should always be used together when calling methods from
 /// [`JwkDocumentExt`](crate::storage::JwkDocumentExt).
@@ -36,4 +63,78 @@ impl<K, I> Storage<K, I> {
   pub fn key_id_storage(&self) -> &I {
     &self.key_id_storage
   }
+
+  /// Returns a view of this storage which keeps the [`KeyIdStorage`](crate::key_id_storage::KeyIdStorage) but has
+  /// no access to the private keys of the wrapped [`JwkStorage`](crate::key_storage::JwkStorage).
+  ///
+  /// The view can be handed out along with a DID document for verification, any attempt to sign or generate keys with
+  /// it fails with [`KeyStorageErrorKind::PrivateKeyUnavailable`](crate::key_storage::KeyStorageErrorKind).
+  pub fn public_view(&self) -> PublicStorage<'_, I> {
+    Storage::new(PublicKeyStorage::new(), &self.key_id_storage)
+  }
+}
+
+impl<K, I> Storage<K, I>
+where
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  /// Deletes the key id mappings, and the keys they map to, whose [`MethodDigest`] does not belong to any
+  /// verification method of `document`, e.g. after methods have been removed from the document without
+  /// [`JwkDocumentExt::purge_method`].
+  ///
+  /// The purged digests are returned.
+  ///
+  /// # Warning
+  ///
+  /// Every entry of the [`KeyIdStorage`] that does not belong to `document` is purged, so this must only be used with
+  /// storages dedicated to a single document whose digests were created with [`MethodDigest::new`].
+  ///
+  /// # Errors
+  ///
+  /// Fails if the entries of the [`KeyIdStorage`] cannot be enumerated, see [`KeyIdStorage::method_digests`], or if
+  /// deleting an entry fails. Entries purged before the failure remain deleted.
+  pub async fn purge_orphans<D>(&self, document: &D) -> StorageResult<Vec<MethodDigest>>
+  where
+    D: AsRef<CoreDocument>,
+  {
+    // Methods whose digest cannot be computed cannot be backed by this storage either.
+    let document_digests: HashSet<MethodDigest> = document
+      .as_ref()
+      .methods(None)
+      .into_iter()
+      .filter_map(|method| MethodDigest::new(method).ok())
+      .collect();
+
+    let orphans: Vec<MethodDigest> = self
+      .key_id_storage
+      .method_digests()
+      .await
+      .map_err(JwkStorageDocumentError::KeyIdStorageError)?
+      .into_iter()
+      .filter(|digest| !document_digests.contains(digest))
+      .collect();
+
+    for digest in &orphans {
+      let key_id: KeyId = match self.key_id_storage.get_key_id(digest).await {
+        Ok(key_id) => key_id,
+        // The entry was removed concurrently.
+        Err(error) if matches!(error.kind(), KeyIdStorageErrorKind::KeyIdNotFound) => continue,
+        Err(error) => return Err(JwkStorageDocumentError::KeyIdStorageError(error)),
+      };
+      match self.key_storage.delete(&key_id).await {
+        Ok(()) => (),
+        // The key is already gone, only the mapping is left.
+        Err(error) if matches!(error.kind(), KeyStorageErrorKind::KeyNotFound) => (),
+        Err(error) => return Err(JwkStorageDocumentError::KeyStorageError(error)),
+      }
+      self
+        .key_id_storage
+        .delete_key_id(digest)
+        .await
+        .map_err(JwkStorageDocumentError::KeyIdStorageError)?;
+    }
+
+    Ok(orphans)
+  }
 }
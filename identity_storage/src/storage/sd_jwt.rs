@@ -0,0 +1,321 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cmp::Reverse;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+use std::collections::HashMap;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_credential::credential::Jwt;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jose::jwu;
+use identity_verification::jws::DecodedJws;
+use identity_verification::jws::JwsVerifier;
+use serde_json::Map;
+
+use super::JwkStorageDocumentError as Error;
+use super::StorageResult;
+
+/// The name of the hash algorithm used for the digests of an [`SdJwt`], as defined by the
+/// [IANA Named Information Hash Algorithm Registry](https://www.iana.org/assignments/named-information/named-information.xhtml).
+const SD_ALG: &str = "sha-256";
+/// Number of random bytes in the salt of a [`Disclosure`].
+const SALT_LEN: usize = 16;
+
+/// A disclosure of a single claim of an [`SdJwt`], the base64url encoded JSON array `[salt, claim name, claim value]`.
+///
+/// See the [SD-JWT specification](https://datatracker.ietf.org/doc/draft-ietf-oauth-selective-disclosure-jwt/).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disclosure {
+  salt: String,
+  claim_name: String,
+  claim_value: Value,
+  encoded: String,
+}
+
+impl Disclosure {
+  /// Creates a new [`Disclosure`] of the claim `claim_name` with the given `claim_value`.
+  pub fn new(salt: String, claim_name: String, claim_value: Value) -> Self {
+    let array: Value = Value::Array(vec![
+      Value::String(salt.clone()),
+      Value::String(claim_name.clone()),
+      claim_value.clone(),
+    ]);
+    let encoded: String = jwu::encode_b64(array.to_string());
+    Self {
+      salt,
+      claim_name,
+      claim_value,
+      encoded,
+    }
+  }
+
+  /// Parses a base64url encoded disclosure.
+  ///
+  /// # Errors
+  ///
+  /// Fails if `encoded` is not the base64url encoding of a JSON array `[salt, claim name, claim value]`.
+  pub fn parse(encoded: &str) -> StorageResult<Self> {
+    let invalid = |source: Option<Box<dyn std::error::Error + Send + Sync>>| {
+      Error::SdJwtVerificationError("malformed disclosure", source)
+    };
+    let decoded: Vec<u8> = jwu::decode_b64(encoded).map_err(|err| invalid(Some(err.into())))?;
+    let (salt, claim_name, claim_value): (String, String, Value) =
+      serde_json::from_slice(&decoded).map_err(|err| invalid(Some(err.into())))?;
+    Ok(Self {
+      salt,
+      claim_name,
+      claim_value,
+      // The digest is computed over the encoding as issued, which need not match a re-encoding.
+      encoded: encoded.to_owned(),
+    })
+  }
+
+  /// Returns the salt of the disclosure.
+  pub fn salt(&self) -> &str {
+    &self.salt
+  }
+
+  /// Returns the name of the disclosed claim.
+  pub fn claim_name(&self) -> &str {
+    &self.claim_name
+  }
+
+  /// Returns the value of the disclosed claim.
+  pub fn claim_value(&self) -> &Value {
+    &self.claim_value
+  }
+
+  /// Returns the base64url encoded disclosure.
+  pub fn as_str(&self) -> &str {
+    &self.encoded
+  }
+
+  /// Returns the base64url encoded SHA-256 digest of the encoded disclosure, as it is embedded in the `_sd` claim of
+  /// the JWT.
+  pub fn digest(&self) -> String {
+    let mut digest: [u8; SHA256_LEN] = [0; SHA256_LEN];
+    SHA256(self.encoded.as_bytes(), &mut digest);
+    jwu::encode_b64(digest)
+  }
+}
+
+impl Display for Disclosure {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(&self.encoded)
+  }
+}
+
+/// A JWT whose selectively disclosable claims are replaced by digests, together with the [`Disclosure`]s of these
+/// claims.
+///
+/// The [`Display`] implementation produces the combined format `<JWT>~<Disclosure 1>~...~<Disclosure N>~` to be
+/// handed to the holder, who may remove any of the disclosures before presenting it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SdJwt {
+  jwt: Jwt,
+  disclosures: Vec<Disclosure>,
+}
+
+impl SdJwt {
+  /// Creates a new [`SdJwt`] from the issuer signed `jwt` and its `disclosures`.
+  pub fn new(jwt: Jwt, disclosures: Vec<Disclosure>) -> Self {
+    Self { jwt, disclosures }
+  }
+
+  /// Returns the issuer signed JWT.
+  pub fn jwt(&self) -> &Jwt {
+    &self.jwt
+  }
+
+  /// Returns the disclosures of the selectively disclosable claims.
+  pub fn disclosures(&self) -> &[Disclosure] {
+    &self.disclosures
+  }
+
+  /// Consumes the [`SdJwt`], returning the issuer signed JWT and the disclosures.
+  pub fn into_parts(self) -> (Jwt, Vec<Disclosure>) {
+    (self.jwt, self.disclosures)
+  }
+}
+
+/// Parses the combined format `<JWT>~<Disclosure 1>~...~<Disclosure N>~`.
+///
+/// SD-JWTs with a key binding JWT following the last disclosure are not supported.
+impl FromStr for SdJwt {
+  type Err = Error;
+
+  fn from_str(s: &str) -> StorageResult<Self> {
+    let mut parts = s.split('~');
+    let jwt: &str = parts.next().unwrap_or_default();
+    let key_binding: Option<&str> = parts.next_back();
+    if jwt.is_empty() || key_binding != Some("") {
+      return Err(Error::SdJwtVerificationError(
+        "expected the combined format `<JWT>~<Disclosure 1>~...~<Disclosure N>~`",
+        None,
+      ));
+    }
+    let disclosures: Vec<Disclosure> = parts.map(Disclosure::parse).collect::<StorageResult<_>>()?;
+    Ok(Self::new(Jwt::new(jwt.to_owned()), disclosures))
+  }
+}
+
+impl Display for SdJwt {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.jwt.as_str())?;
+    for disclosure in &self.disclosures {
+      write!(f, "~{disclosure}")?;
+    }
+    f.write_str("~")
+  }
+}
+
+/// Replaces the claims of the first credential subject identified by the JSON Pointers in `disclosable` with digests
+/// of their [`Disclosure`]s in the serialized credential JWT `claims`.
+pub(crate) fn conceal_claims(claims: &str, disclosable: &[&str]) -> StorageResult<(String, Vec<Disclosure>)> {
+  let mut claims: Value = serde_json::from_str(claims).map_err(|err| Error::EncodingError(err.into()))?;
+  let subject: &mut Value = match claims.pointer_mut("/vc/credentialSubject") {
+    Some(Value::Array(subjects)) => subjects.first_mut(),
+    subject => subject,
+  }
+  .ok_or(Error::Custom("missing credential subject", None))?;
+
+  // Conceal nested claims before their parents, so their digests end up in the disclosed value of the parent.
+  let mut pointers: Vec<&str> = disclosable.to_vec();
+  pointers.sort_by_key(|pointer| Reverse(pointer.matches('/').count()));
+
+  let mut disclosures: Vec<Disclosure> = Vec::with_capacity(pointers.len());
+  for pointer in pointers {
+    let invalid_pointer = || Error::UndisclosableClaim(pointer.to_owned());
+    let (parent_pointer, claim_name) = pointer.rsplit_once('/').ok_or_else(invalid_pointer)?;
+    let claim_name: String = claim_name.replace("~1", "/").replace("~0", "~");
+    let Some(Value::Object(parent)) = subject.pointer_mut(parent_pointer) else {
+      return Err(invalid_pointer());
+    };
+    if claim_name == "_sd" {
+      return Err(invalid_pointer());
+    }
+    let claim_value: Value = parent.remove(&claim_name).ok_or_else(invalid_pointer)?;
+
+    let disclosure: Disclosure = Disclosure::new(random_salt()?, claim_name, claim_value);
+    insert_digest(parent, disclosure.digest()).ok_or_else(invalid_pointer)?;
+    disclosures.push(disclosure);
+  }
+
+  if let Value::Object(claims) = &mut claims {
+    claims.insert("_sd_alg".to_owned(), Value::String(SD_ALG.to_owned()));
+  }
+  Ok((claims.to_string(), disclosures))
+}
+
+/// Adds `digest` to the `_sd` array of `object`, keeping the digests sorted so their order does not reveal the order
+/// of the concealed claims. Returns `None` if `_sd` is not an array.
+fn insert_digest(object: &mut Map<String, Value>, digest: String) -> Option<()> {
+  let Value::Array(digests) = object.entry("_sd").or_insert_with(|| Value::Array(Vec::new())) else {
+    return None;
+  };
+  digests.push(Value::String(digest));
+  digests.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+  Some(())
+}
+
+fn random_salt() -> StorageResult<String> {
+  let mut salt: [u8; SALT_LEN] = [0; SALT_LEN];
+  crypto::utils::rand::fill(&mut salt).map_err(|err| Error::EncodingError(err.into()))?;
+  Ok(jwu::encode_b64(salt))
+}
+
+/// Verifies the JWS of an SD-JWT, for instance one produced by
+/// [`JwkDocumentExt::create_sd_jwt`](crate::storage::JwkDocumentExt::create_sd_jwt), against the resolved DID Document
+/// of its `issuer` and returns the claim set with the claims of the contained disclosures restored.
+///
+/// The holder may have removed any of the disclosures issued with the SD-JWT, the claims of these remain concealed.
+/// The `_sd` and `_sd_alg` claims are removed from the returned claim set.
+///
+/// # Errors
+///
+/// Fails with [`JwkStorageDocumentError::SdJwtVerificationError`](Error::SdJwtVerificationError) if the signature is
+/// invalid, or a disclosure is contained more than once or its digest is not part of the SD-JWT.
+pub fn verify_sd_jwt<DOC, V>(
+  sd_jwt: &SdJwt,
+  issuer: &DOC,
+  signature_verifier: &V,
+  options: &JwsVerificationOptions,
+) -> StorageResult<Object>
+where
+  DOC: AsRef<CoreDocument> + ?Sized,
+  V: JwsVerifier,
+{
+  let decoded: DecodedJws<'_> = issuer
+    .as_ref()
+    .verify_jws(sd_jwt.jwt().as_str(), None, signature_verifier, options)
+    .map_err(|err| Error::SdJwtVerificationError("invalid signature", Some(err.into())))?;
+  let mut claims: Map<String, Value> = serde_json::from_slice(&decoded.claims)
+    .map_err(|err| Error::SdJwtVerificationError("malformed claims", Some(err.into())))?;
+
+  match claims.remove("_sd_alg") {
+    None => (),
+    Some(Value::String(alg)) if alg == SD_ALG => (),
+    Some(_) => return Err(Error::SdJwtVerificationError("unsupported `_sd_alg`", None)),
+  }
+
+  let mut disclosures: HashMap<String, &Disclosure> = HashMap::with_capacity(sd_jwt.disclosures().len());
+  for disclosure in sd_jwt.disclosures() {
+    if disclosures.insert(disclosure.digest(), disclosure).is_some() {
+      return Err(Error::SdJwtVerificationError("duplicate disclosure", None));
+    }
+  }
+
+  reveal_claims(&mut claims, &mut disclosures)?;
+  if !disclosures.is_empty() {
+    return Err(Error::SdJwtVerificationError(
+      "the digest of a disclosure is not part of the SD-JWT",
+      None,
+    ));
+  }
+
+  Ok(claims.into_iter().collect())
+}
+
+/// Replaces the digests in the `_sd` array of `object` and of all nested objects by the claims of the matching
+/// `disclosures`, removing every used disclosure from `disclosures`.
+fn reveal_claims(object: &mut Map<String, Value>, disclosures: &mut HashMap<String, &Disclosure>) -> StorageResult<()> {
+  if let Some(digests) = object.remove("_sd") {
+    let Value::Array(digests) = digests else {
+      return Err(Error::SdJwtVerificationError("`_sd` must be an array", None));
+    };
+    for digest in digests {
+      let Some(disclosure) = digest.as_str().and_then(|digest| disclosures.remove(digest)) else {
+        continue;
+      };
+      if object.contains_key(disclosure.claim_name()) {
+        return Err(Error::SdJwtVerificationError(
+          "disclosed claim conflicts with an existing claim",
+          None,
+        ));
+      }
+      object.insert(disclosure.claim_name().to_owned(), disclosure.claim_value().clone());
+    }
+  }
+
+  // Disclosed values may themselves contain digests.
+  object
+    .values_mut()
+    .try_for_each(|value| reveal_nested_claims(value, disclosures))
+}
+
+fn reveal_nested_claims(value: &mut Value, disclosures: &mut HashMap<String, &Disclosure>) -> StorageResult<()> {
+  match value {
+    Value::Object(object) => reveal_claims(object, disclosures),
+    Value::Array(values) => values
+      .iter_mut()
+      .try_for_each(|value| reveal_nested_claims(value, disclosures)),
+    _ => Ok(()),
+  }
+}
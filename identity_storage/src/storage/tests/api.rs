@@ -6,19 +6,33 @@ use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
 
 use identity_credential::validator::CredentialValidationOptions;
+use identity_did::CoreDID;
 use identity_did::DIDUrl;
+use identity_did::DID;
 use identity_document::document::CoreDocument;
+use identity_document::verifiable::verify_jws;
 use identity_document::verifiable::JwsVerificationOptions;
+use identity_document::verifiable::VerifiedJws;
 use identity_verification::jose::jws::EdDSAJwsVerifier;
 use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jose::jwu::encode_b64;
 use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
 
 use crate::key_id_storage::KeyIdMemstore;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::MethodDigest;
 use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyStorageErrorKind;
 use crate::storage::JwsSignatureOptions;
 
+use crate::storage::ConflictPolicy;
+use crate::storage::CoreDocumentStorageExt;
 use crate::storage::JwkDocumentExt;
+use crate::storage::JwkStorageDocumentError;
+use crate::storage::MethodSpec;
 use crate::Storage;
 
 type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
@@ -82,6 +96,27 @@ async fn generation() {
     .is_some());
 }
 
+#[tokio::test]
+async fn generated_method_jws_algorithm() {
+  let (mut document, storage) = setup();
+  let fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+
+  let method: &VerificationMethod = document.resolve_method(&fragment, None).unwrap();
+  assert_eq!(method.jws_algorithm(), Some(JwsAlgorithm::EdDSA));
+  // Methods without a JWK carry no algorithm.
+  let method: &VerificationMethod = document.resolve_method("#root", None).unwrap();
+  assert_eq!(method.jws_algorithm(), None);
+}
+
 #[tokio::test]
 async fn signing_bytes() {
   let (mut document, storage) = setup();
@@ -115,6 +150,110 @@ async fn signing_bytes() {
     .is_ok());
 }
 
+#[cfg(feature = "ed448")]
+#[tokio::test]
+async fn signing_bytes_ed448() {
+  use identity_verification::MethodData;
+
+  use crate::key_storage::JwkGenOutput;
+
+  let (mut document, storage) = setup();
+  let method_fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED448_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let jws = document
+    .sign_bytes(&storage, &method_fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default()
+    )
+    .is_ok());
+
+  // INVALID: the method referenced by the `kid` holds a key on a different curve.
+  let JwkGenOutput { jwk: ed25519_jwk, .. } = storage
+    .key_storage()
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+  let method: &mut VerificationMethod = document.resolve_method_mut(&method_fragment, None).unwrap();
+  *method.data_mut() = MethodData::PublicKeyJwk(ed25519_jwk);
+
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default()
+    )
+    .is_err());
+}
+
+#[tokio::test]
+async fn signing_with_public_view() {
+  let (mut document, storage) = setup();
+  let method_fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let public_view = storage.public_view();
+
+  // The key id of the method is still available.
+  let method_digest = MethodDigest::new(document.resolve_method(&method_fragment, None).unwrap()).unwrap();
+  assert_eq!(
+    public_view.key_id_storage().get_key_id(&method_digest).await.unwrap(),
+    storage.key_id_storage().get_key_id(&method_digest).await.unwrap()
+  );
+
+  // Signing requires the private key.
+  let result = document
+    .sign_bytes(&public_view, &method_fragment, b"test", &JwsSignatureOptions::new())
+    .await;
+  assert!(matches!(
+    result.unwrap_err(),
+    JwkStorageDocumentError::KeyStorageError(err) if matches!(err.kind(), KeyStorageErrorKind::PrivateKeyUnavailable)
+  ));
+
+  // So does generating new keys.
+  let result = document
+    .generate_method(
+      &public_view,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await;
+  assert!(matches!(
+    result.unwrap_err(),
+    JwkStorageDocumentError::KeyStorageError(_)
+  ));
+
+  // The original storage is unaffected.
+  assert!(document
+    .sign_bytes(&storage, &method_fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .is_ok());
+}
+
 #[tokio::test]
 async fn signing_bytes_detached_without_b64() {
   let (mut document, storage) = setup();
@@ -148,6 +287,98 @@ async fn signing_bytes_detached_without_b64() {
     .unwrap();
 }
 
+#[tokio::test]
+async fn boxed_jwk_storage() {
+  let (mut document, _) = setup();
+  // The key storage backend is only known at runtime.
+  let key_storage: Box<dyn JwkStorage> = Box::new(JwkMemStore::new());
+  let storage: Storage<Box<dyn JwkStorage>, KeyIdMemstore> = Storage::new(key_storage, KeyIdMemstore::new());
+
+  let method_fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let jws = document
+    .sign_bytes(&storage, &method_fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default()
+    )
+    .is_ok());
+
+  let method_id: DIDUrl = document.id().to_url().join(format!("#{method_fragment}")).unwrap();
+  document.purge_method(&storage, &method_id).await.unwrap();
+  assert!(document.resolve_method(method_fragment.as_str(), None).is_none());
+}
+
+#[tokio::test]
+async fn verify_jws_returns_method() {
+  let (mut document, storage) = setup();
+  let method_fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let payload = b"test";
+
+  let jws = document
+    .sign_bytes(&storage, &method_fragment, payload, &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  let verified: VerifiedJws<'_, '_> = verify_jws(
+    jws.as_str(),
+    None,
+    &document,
+    &EdDSAJwsVerifier::default(),
+    &JwsVerificationOptions::default(),
+  )
+  .unwrap();
+  assert_eq!(verified.decoded.claims.as_ref(), payload);
+  assert_eq!(verified.method.id().fragment(), Some(method_fragment.as_str()));
+
+  // Detached payloads must be passed separately, in their encoded form.
+  let options = JwsSignatureOptions::new().detached_payload(true);
+  let jws = document
+    .sign_bytes(&storage, &method_fragment, payload, &options)
+    .await
+    .unwrap();
+  let encoded_payload: String = encode_b64(payload);
+  let verified: VerifiedJws<'_, '_> = verify_jws(
+    jws.as_str(),
+    Some(encoded_payload.as_bytes()),
+    &document,
+    &EdDSAJwsVerifier::default(),
+    &JwsVerificationOptions::default(),
+  )
+  .unwrap();
+  assert_eq!(verified.decoded.claims.as_ref(), payload);
+  assert_eq!(verified.method.id().fragment(), Some(method_fragment.as_str()));
+  assert!(verify_jws(
+    jws.as_str(),
+    Some(encode_b64(b"other").as_bytes()),
+    &document,
+    &EdDSAJwsVerifier::default(),
+    &JwsVerificationOptions::default(),
+  )
+  .is_err());
+}
+
 #[tokio::test]
 async fn signing_credential() {
   let (mut document, storage) = setup();
@@ -224,6 +455,317 @@ async fn purging() {
   assert_eq!(storage.key_storage().count().await, 0);
 }
 
+#[tokio::test]
+async fn purging_orphans() {
+  let (mut document, storage) = setup();
+  for fragment in ["#kept", "#removed"] {
+    document
+      .generate_method(
+        &storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        Some(fragment),
+        MethodScope::VerificationMethod,
+      )
+      .await
+      .unwrap();
+  }
+  let removed_method_id: DIDUrl = document.resolve_method("#removed", None).unwrap().id().clone();
+  let removed_method: VerificationMethod = document.remove_method(&removed_method_id).unwrap();
+  let removed_method_digest: MethodDigest = MethodDigest::new(&removed_method).unwrap();
+
+  // Only the key of the removed method is purged.
+  let purged: Vec<MethodDigest> = storage.purge_orphans(&document).await.unwrap();
+  assert_eq!(purged, [removed_method_digest.clone()]);
+  assert!(storage
+    .key_id_storage()
+    .get_key_id(&removed_method_digest)
+    .await
+    .is_err());
+  assert_eq!(storage.key_id_storage().count().await, 1);
+  assert_eq!(storage.key_storage().count().await, 1);
+
+  // The key of the remaining method is still usable.
+  assert!(document
+    .sign_bytes(&storage, "#kept", b"test", &JwsSignatureOptions::new())
+    .await
+    .is_ok());
+  assert!(storage.purge_orphans(&document).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn new_with_storage() {
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let did: CoreDID = CoreDID::parse("did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr").unwrap();
+  let authentication = MethodScope::VerificationRelationship(MethodRelationship::Authentication);
+  let assertion_method = MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod);
+
+  let document: CoreDocument = CoreDocument::new_with_storage(
+    did.clone(),
+    &storage,
+    &[
+      MethodSpec::new(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, authentication).fragment("#auth"),
+      MethodSpec::new(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, assertion_method),
+    ],
+  )
+  .await
+  .unwrap();
+
+  assert_eq!(document.id(), &did);
+  assert!(document.resolve_method("#auth", Some(authentication)).is_some());
+  assert_eq!(document.methods(Some(assertion_method)).len(), 1);
+  assert_eq!(storage.key_id_storage().count().await, 2);
+  assert_eq!(storage.key_storage().count().await, 2);
+  assert!(document
+    .sign_bytes(&storage, "#auth", b"test", &JwsSignatureOptions::new())
+    .await
+    .is_ok());
+
+  // A conflicting fragment fails the assembly, the methods generated before are purged.
+  let storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let err: JwkStorageDocumentError = CoreDocument::new_with_storage(
+    did,
+    &storage,
+    &[
+      MethodSpec::new(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, authentication).fragment("#key"),
+      MethodSpec::new(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, assertion_method).fragment("#key"),
+    ],
+  )
+  .await
+  .unwrap_err();
+  assert!(matches!(err, JwkStorageDocumentError::FragmentAlreadyExists));
+  assert_eq!(storage.key_id_storage().count().await, 0);
+  assert_eq!(storage.key_storage().count().await, 0);
+}
+
+#[tokio::test]
+async fn method_fragments() {
+  let (mut document, storage) = setup();
+  let existing: Vec<String> = document.method_fragments();
+
+  let mut generated: Vec<String> = Vec::new();
+  for fragment in [Some("#key-1"), None] {
+    generated.push(
+      document
+        .generate_method(
+          &storage,
+          JwkMemStore::ED25519_KEY_TYPE,
+          JwsAlgorithm::EdDSA,
+          fragment,
+          MethodScope::VerificationMethod,
+        )
+        .await
+        .unwrap(),
+    );
+  }
+
+  let fragments: Vec<String> = document.method_fragments();
+  assert_eq!(fragments.len(), existing.len() + generated.len());
+  for fragment in existing.iter().chain(generated.iter()) {
+    assert!(fragments.contains(fragment));
+  }
+}
+
+#[tokio::test]
+async fn rotating() {
+  let (mut document, storage) = setup();
+  let method_fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("#key-1"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let method_id: DIDUrl = document.resolve_method(&method_fragment, None).unwrap().id().clone();
+  assert!(document
+    .attach_method_relationship(&method_id, MethodRelationship::AssertionMethod)
+    .unwrap());
+  let old_method: VerificationMethod = document.resolve_method(&method_fragment, None).unwrap().clone();
+
+  document
+    .rotate_method(
+      &storage,
+      &method_fragment,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+    )
+    .await
+    .unwrap();
+
+  // The fragment and relationships are stable, while the key material changed.
+  let method: &VerificationMethod = document
+    .resolve_method(&method_fragment, Some(MethodScope::assertion_method()))
+    .unwrap();
+  assert_eq!(method.id(), &method_id);
+  assert_ne!(method.data(), old_method.data());
+
+  // Only the new key is left in the storage.
+  assert_eq!(storage.key_id_storage().count().await, 1);
+  assert_eq!(storage.key_storage().count().await, 1);
+  let old_method_digest: MethodDigest = MethodDigest::new(&old_method).unwrap();
+  assert!(storage.key_id_storage().get_key_id(&old_method_digest).await.is_err());
+
+  let jws = document
+    .sign_bytes(&storage, &method_fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default()
+    )
+    .is_ok());
+
+  // Methods that are not backed by a JWK cannot be rotated.
+  assert!(matches!(
+    document
+      .rotate_method(&storage, "#root", JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+      .await
+      .unwrap_err(),
+    JwkStorageDocumentError::NotPublicKeyJwk
+  ));
+}
+
+#[tokio::test]
+async fn methods_with_scope() {
+  let (mut document, storage) = setup();
+  let assertion = MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod);
+  let authentication = MethodScope::authentication();
+
+  for (fragment, scope) in [("#assertion", assertion), ("#authentication", authentication)] {
+    document
+      .generate_method(
+        &storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        Some(fragment),
+        scope,
+      )
+      .await
+      .unwrap();
+  }
+  // An embedded method that is referenced from the authentication relationship.
+  let referenced_fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("#referenced"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let referenced_id: DIDUrl = document
+    .resolve_method(&referenced_fragment, None)
+    .unwrap()
+    .id()
+    .clone();
+  assert!(document
+    .attach_method_relationship(&referenced_id, MethodRelationship::Authentication)
+    .unwrap());
+
+  let fragments = |scope: MethodScope| -> Vec<String> {
+    document
+      .methods_with_scope(scope)
+      .into_iter()
+      .map(|method| method.id().fragment().unwrap().to_owned())
+      .collect()
+  };
+  assert_eq!(fragments(assertion), ["assertion"]);
+  assert_eq!(fragments(authentication), ["authentication", "referenced"]);
+  // The `#root` method of the mock document is not a JWK and is therefore excluded.
+  assert_eq!(fragments(MethodScope::VerificationMethod), ["referenced"]);
+  assert!(fragments(MethodScope::key_agreement()).is_empty());
+}
+
+#[tokio::test]
+async fn merge_methods() {
+  let (mut primary, storage) = setup();
+  primary
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("#shared"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+
+  let mut secondary: CoreDocument = CoreDocument::from_json(r#"{"id": "did:bar:secondary"}"#).unwrap();
+  for (fragment, scope) in [
+    ("#shared", MethodScope::VerificationMethod),
+    ("#referenced", MethodScope::VerificationMethod),
+    ("#embedded", MethodScope::key_agreement()),
+  ] {
+    secondary
+      .generate_method(
+        &storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        Some(fragment),
+        scope,
+      )
+      .await
+      .unwrap();
+  }
+  let referenced_id: DIDUrl = secondary.resolve_method("#referenced", None).unwrap().id().clone();
+  secondary
+    .attach_method_relationship(&referenced_id, MethodRelationship::AssertionMethod)
+    .unwrap();
+
+  let public_key = |document: &CoreDocument, fragment: &str| {
+    document
+      .resolve_method(fragment, None)
+      .unwrap()
+      .data()
+      .public_key_jwk()
+      .cloned()
+  };
+  let assert_merged = |document: &CoreDocument| {
+    let referenced = document.resolve_method("#referenced", None).unwrap();
+    assert_eq!(referenced.id().did(), primary.id());
+    assert_eq!(
+      document.methods_with_scope(MethodScope::assertion_method())[0].id(),
+      referenced.id()
+    );
+    assert_eq!(document.methods_with_scope(MethodScope::key_agreement()).len(), 1);
+  };
+
+  // Skip: the existing method is kept.
+  let mut document: CoreDocument = primary.clone();
+  document.merge_methods(&secondary, ConflictPolicy::Skip).unwrap();
+  assert_merged(&document);
+  assert_eq!(public_key(&document, "#shared"), public_key(&primary, "#shared"));
+
+  // Overwrite: the existing method is replaced.
+  let mut document: CoreDocument = primary.clone();
+  document.merge_methods(&secondary, ConflictPolicy::Overwrite).unwrap();
+  assert_merged(&document);
+  assert_eq!(public_key(&document, "#shared"), public_key(&secondary, "#shared"));
+
+  // Error: nothing is merged.
+  let mut document: CoreDocument = primary.clone();
+  assert!(matches!(
+    document.merge_methods(&secondary, ConflictPolicy::Error).unwrap_err(),
+    JwkStorageDocumentError::FragmentAlreadyExists
+  ));
+  assert_eq!(document, primary);
+
+  // The key of a merged method is still found in storage.
+  let mut document: CoreDocument = primary.clone();
+  document.merge_methods(&secondary, ConflictPolicy::Skip).unwrap();
+  assert!(document
+    .sign_bytes(&storage, "embedded", b"payload", &JwsSignatureOptions::default())
+    .await
+    .is_ok());
+}
+
 #[cfg(feature = "iota-document")]
 mod iota_document_tests {
   // Write a single test for the IotaDocument case just to check that it works
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
 
@@ -16,9 +17,10 @@ use crate::key_storage::JwkMemStore;
 use crate::storage::JwsSignatureOptions;
 
 use crate::storage::JwkDocumentExt;
+use crate::storage::JwkStorageDocumentError;
 use crate::Storage;
 
-type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+pub(super) type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
 
 const MOCK_DOCUMENT_JSON: &str = r#"
 {
@@ -33,7 +35,7 @@ const MOCK_DOCUMENT_JSON: &str = r#"
     ]
 }"#;
 
-async fn setup() -> (CoreDocument, MemStorage, String, Credential) {
+pub(super) async fn setup() -> (CoreDocument, MemStorage, String, Credential) {
   let mut mock_document = CoreDocument::from_json(MOCK_DOCUMENT_JSON).unwrap();
   let storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
 
@@ -186,3 +188,95 @@ async fn signing_credential_with_b64() {
     .await
     .is_err());
 }
+
+#[tokio::test]
+async fn signing_credential_can_be_validated() {
+  let (document, storage, kid, credential) = setup().await;
+
+  let jwt = document
+    .sign_credential(&credential, &storage, kid.as_ref(), &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+
+  let decoded = identity_credential::validator::CredentialValidator::new()
+    .validate::<_, Object>(
+      &jwt,
+      &document,
+      &CredentialValidationOptions::default(),
+      identity_credential::validator::FailFast::FirstError,
+    )
+    .unwrap();
+  assert_eq!(decoded.credential, credential);
+}
+
+#[tokio::test]
+async fn signing_credential_fails_with_missing_fragment_or_key() {
+  let (document, storage, _, credential) = setup().await;
+
+  // Missing fragment.
+  assert!(matches!(
+    document
+      .sign_credential(&credential, &storage, "missing", &JwsSignatureOptions::default())
+      .await
+      .unwrap_err(),
+    JwkStorageDocumentError::MethodNotFound
+  ));
+
+  // The method exists but its key is not in the given storage.
+  let (document, _, kid, credential) = setup().await;
+  let empty_storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  assert!(matches!(
+    document
+      .sign_credential(
+        &credential,
+        &empty_storage,
+        kid.as_ref(),
+        &JwsSignatureOptions::default()
+      )
+      .await
+      .unwrap_err(),
+    JwkStorageDocumentError::KeyIdStorageError(_)
+  ));
+}
+
+#[tokio::test]
+async fn create_credential_jwts() {
+  let (document, storage, kid, credential) = setup().await;
+  let credentials: Vec<Credential> = (0..3)
+    .map(|index| {
+      let mut credential: Credential = credential.clone();
+      credential.id = Some(Url::parse(format!("http://example.edu/credentials/{index}")).unwrap());
+      credential
+    })
+    .collect();
+
+  let jwts = document
+    .create_credential_jwts(&credentials, &storage, kid.as_ref(), &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+  assert_eq!(jwts.len(), credentials.len());
+
+  let validator = identity_credential::validator::CredentialValidator::new();
+  for (jwt, credential) in jwts.iter().zip(credentials.iter()) {
+    let decoded = validator
+      .validate::<_, Object>(
+        jwt,
+        &document,
+        &CredentialValidationOptions::default(),
+        identity_credential::validator::FailFast::FirstError,
+      )
+      .unwrap();
+    assert_eq!(&decoded.credential, credential);
+  }
+
+  // The options are checked before signing any credential.
+  assert!(document
+    .create_credential_jwts(
+      &credentials,
+      &storage,
+      kid.as_ref(),
+      &JwsSignatureOptions::default().detached_payload(true),
+    )
+    .await
+    .is_err());
+}
@@ -1,28 +1,48 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use identity_core::common::Context;
 use identity_core::common::Duration;
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
 use identity_credential::credential::Jwt;
 use identity_credential::credential::RevocationBitmapStatus;
 use identity_credential::credential::Status;
+use identity_credential::credential::StatusList2021Entry;
+use identity_credential::credential::StatusPurpose;
 use identity_credential::credential::Subject;
 use identity_credential::revocation::RevocationBitmap;
 use identity_credential::revocation::RevocationDocumentExt;
+use identity_credential::revocation::StatusList2021;
 use identity_credential::validator::CredentialValidationOptions;
 use identity_credential::validator::CredentialValidator;
 use identity_credential::validator::FailFast;
 use identity_credential::validator::StatusCheck;
 use identity_credential::validator::SubjectHolderRelationship;
 use identity_credential::validator::ValidationError;
+use identity_did::CoreDID;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
 use identity_document::service::Service;
 use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jose::error::Error as JoseError;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::EdDSAJwsVerifier;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use identity_verification::jws::VerificationInput;
+use identity_verification::jwu::decode_b64;
+use identity_verification::jwu::encode_b64;
 use once_cell::sync::Lazy;
 use proptest::proptest;
 
@@ -105,7 +125,7 @@ where
     credential,
     issuance_date,
     expiration_date,
-  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
 
   let jws = issuer_doc
     .sign_credential(
@@ -166,8 +186,282 @@ where
 
 #[tokio::test]
 async fn invalid_expiration_or_issuance_date() {
-  invalid_expiration_or_issuance_date_impl(test_utils::setup_coredocument(None, None).await).await;
-  invalid_expiration_or_issuance_date_impl(test_utils::setup_iotadocument(None, None).await).await;
+  invalid_expiration_or_issuance_date_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  invalid_expiration_or_issuance_date_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
+}
+
+/// A [`JwsVerifier`] counting how often it is invoked.
+struct SpyVerifier(Arc<AtomicUsize>);
+
+impl JwsVerifier for SpyVerifier {
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    self.0.fetch_add(1, Ordering::SeqCst);
+    EdDSAJwsVerifier::default().verify(input, public_key)
+  }
+}
+
+async fn expiration_before_signature_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    ..
+  } = setup;
+
+  let CredentialSetup {
+    credential,
+    issuance_date,
+    expiration_date,
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
+  let jws: Jwt = issuer_doc
+    .sign_credential(&credential, &storage, &method_fragment, &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+
+  let options = CredentialValidationOptions::default()
+    .latest_issuance_date(issuance_date)
+    .earliest_expiry_date(expiration_date.checked_add(Duration::seconds(1)).unwrap());
+  let validate = |options: &CredentialValidationOptions| {
+    let calls: Arc<AtomicUsize> = Arc::default();
+    let result = CredentialValidator::with_signature_verifier(SpyVerifier(calls.clone())).validate::<_, Object>(
+      &jws,
+      &issuer_doc,
+      options,
+      FailFast::AllErrors,
+    );
+    (
+      result.map_err(|err| err.validation_errors),
+      calls.load(Ordering::SeqCst),
+    )
+  };
+
+  // By default the signature is verified before the expiration date is checked.
+  let (result, calls) = validate(&options);
+  assert!(matches!(
+    result.unwrap_err().as_slice(),
+    [ValidationError::ExpirationDate]
+  ));
+  assert_eq!(calls, 1);
+
+  // An expired credential is rejected without invoking the verifier.
+  let (result, calls) = validate(&options.clone().check_expiration_before_signature(true));
+  assert!(matches!(
+    result.unwrap_err().as_slice(),
+    [ValidationError::ExpirationDate]
+  ));
+  assert_eq!(calls, 0);
+
+  // The signature of an unexpired credential is still verified.
+  let (result, calls) = validate(
+    &options
+      .earliest_expiry_date(expiration_date)
+      .check_expiration_before_signature(true),
+  );
+  assert!(result.is_ok());
+  assert_eq!(calls, 1);
+}
+
+#[tokio::test]
+async fn expiration_before_signature() {
+  expiration_before_signature_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  expiration_before_signature_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
+}
+
+async fn trusted_issuers_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    ..
+  } = setup;
+
+  let CredentialSetup {
+    credential,
+    issuance_date,
+    expiration_date,
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
+  let jws: Jwt = issuer_doc
+    .sign_credential(&credential, &storage, &method_fragment, &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+
+  let options = CredentialValidationOptions::default()
+    .latest_issuance_date(issuance_date)
+    .earliest_expiry_date(expiration_date);
+  let validate = |trusted_issuers: Vec<CoreDID>| {
+    CredentialValidator::new()
+      .validate::<_, Object>(
+        &jws,
+        &issuer_doc,
+        &options.clone().trusted_issuers(trusted_issuers),
+        FailFast::AllErrors,
+      )
+      .map_err(|err| err.validation_errors)
+  };
+
+  // Any issuer is trusted by default.
+  assert!(validate(Vec::new()).is_ok());
+
+  // The issuer is on the list.
+  let issuer: CoreDID = issuer_doc.as_ref().id().clone();
+  let subject: CoreDID = subject_doc.as_ref().id().clone();
+  assert!(validate(vec![subject.clone(), issuer]).is_ok());
+
+  // The issuer is not on the list.
+  let errors = validate(vec![subject]).unwrap_err();
+  assert!(matches!(errors.as_slice(), [ValidationError::UntrustedIssuer]));
+}
+
+#[tokio::test]
+async fn trusted_issuers() {
+  trusted_issuers_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  trusted_issuers_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
+}
+
+async fn leeway_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    ..
+  } = setup;
+
+  let validate = |jws: &Jwt, options: &CredentialValidationOptions| {
+    CredentialValidator::new()
+      .validate::<_, Object>(jws, &issuer_doc, options, FailFast::AllErrors)
+      .map_err(|err| err.validation_errors)
+  };
+  let sign = |credential: Credential| {
+    let issuer_doc = &issuer_doc;
+    let storage = &storage;
+    let method_fragment = &method_fragment;
+    async move {
+      issuer_doc
+        .sign_credential(&credential, storage, method_fragment, &JwsSignatureOptions::default())
+        .await
+        .unwrap()
+    }
+  };
+
+  // A credential that expired just now.
+  let now: Timestamp = Timestamp::now_utc();
+  let credential: Credential = test_utils::generate_credential(
+    &issuer_doc,
+    &[&subject_doc],
+    None,
+    Some(now.checked_sub(Duration::seconds(30)).unwrap()),
+    &[],
+  )
+  .credential;
+  let jws: Jwt = sign(credential).await;
+
+  let errors = validate(&jws, &CredentialValidationOptions::default()).unwrap_err();
+  assert!(matches!(errors.as_slice(), [ValidationError::ExpirationDate]));
+  assert!(validate(
+    &jws,
+    &CredentialValidationOptions::default().leeway(Duration::minutes(1))
+  )
+  .is_ok());
+
+  // A credential that will only be issued in a moment.
+  let credential: Credential = test_utils::generate_credential(
+    &issuer_doc,
+    &[&subject_doc],
+    Some(now.checked_add(Duration::seconds(30)).unwrap()),
+    Some(now.checked_add(Duration::days(1)).unwrap()),
+    &[],
+  )
+  .credential;
+  let jws: Jwt = sign(credential).await;
+
+  let errors = validate(&jws, &CredentialValidationOptions::default()).unwrap_err();
+  assert!(matches!(errors.as_slice(), [ValidationError::IssuanceDate]));
+  assert!(validate(
+    &jws,
+    &CredentialValidationOptions::default().leeway(Duration::minutes(1))
+  )
+  .is_ok());
+}
+
+#[tokio::test]
+async fn leeway() {
+  leeway_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  leeway_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
+}
+
+async fn pinned_now_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    ..
+  } = setup;
+
+  let issuance_date: Timestamp = Timestamp::parse("2010-01-01T00:00:00Z").unwrap();
+  let expiration_date: Timestamp = Timestamp::parse("2020-01-01T00:00:00Z").unwrap();
+  let credential: Credential = test_utils::generate_credential(
+    &issuer_doc,
+    &[&subject_doc],
+    Some(issuance_date),
+    Some(expiration_date),
+    &[],
+  )
+  .credential;
+  let jws: Jwt = issuer_doc
+    .sign_credential(&credential, &storage, &method_fragment, &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+
+  let validate = |options: &CredentialValidationOptions| {
+    CredentialValidator::new()
+      .validate::<_, Object>(&jws, &issuer_doc, options, FailFast::AllErrors)
+      .map_err(|err| err.validation_errors)
+  };
+
+  // The credential is expired according to the system clock.
+  let errors = validate(&CredentialValidationOptions::default()).unwrap_err();
+  assert!(matches!(errors.as_slice(), [ValidationError::ExpirationDate]));
+
+  // Pinning the current time within the validity period makes the credential valid.
+  let now: Timestamp = Timestamp::parse("2015-06-01T00:00:00Z").unwrap();
+  assert!(validate(&CredentialValidationOptions::default().now(now)).is_ok());
+
+  // Pinning the current time before the issuance date fails.
+  let now: Timestamp = Timestamp::parse("2005-06-01T00:00:00Z").unwrap();
+  let errors = validate(&CredentialValidationOptions::default().now(now)).unwrap_err();
+  assert!(matches!(errors.as_slice(), [ValidationError::IssuanceDate]));
+
+  // Explicit dates take precedence over the pinned time.
+  let now: Timestamp = Timestamp::parse("2015-06-01T00:00:00Z").unwrap();
+  let errors = validate(
+    &CredentialValidationOptions::default()
+      .now(now)
+      .earliest_expiry_date(Timestamp::parse("2021-01-01T00:00:00Z").unwrap()),
+  )
+  .unwrap_err();
+  assert!(matches!(errors.as_slice(), [ValidationError::ExpirationDate]));
+}
+
+#[tokio::test]
+async fn pinned_now() {
+  pinned_now_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  pinned_now_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
 }
 
 async fn full_validation_impl<T>(setup: Setup<T, T>)
@@ -187,7 +481,7 @@ where
     credential,
     issuance_date,
     expiration_date,
-  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
 
   let jwt: Jwt = issuer_doc
     .sign_credential(
@@ -211,8 +505,56 @@ where
 
 #[tokio::test]
 async fn full_validation() {
-  full_validation_impl(test_utils::setup_coredocument(None, None).await).await;
-  full_validation_impl(test_utils::setup_iotadocument(None, None).await).await;
+  full_validation_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  full_validation_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
+}
+
+async fn additional_contexts_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    subject_storage: _,
+    subject_method_fragment: _,
+  } = setup;
+
+  let custom_context: Url = Url::parse("https://example.edu/contexts/degree/v1").unwrap();
+  let CredentialSetup { credential, .. } =
+    test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[custom_context.clone()]);
+  let expected_contexts: Vec<Context> = vec![
+    Credential::<Object>::base_context().clone(),
+    Context::Url(custom_context),
+  ];
+  assert_eq!(credential.context.as_slice(), expected_contexts.as_slice());
+
+  let jwt: Jwt = issuer_doc
+    .sign_credential(
+      &credential,
+      &storage,
+      method_fragment.as_ref(),
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap();
+  let decoded = CredentialValidator::new()
+    .validate::<_, Object>(
+      &jwt,
+      &issuer_doc,
+      &CredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .unwrap();
+  assert_eq!(decoded.credential.context.as_slice(), expected_contexts.as_slice());
+}
+
+#[tokio::test]
+async fn additional_contexts() {
+  additional_contexts_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  additional_contexts_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
 }
 
 async fn matches_issuer_did_unrelated_issuer_impl<T>(setup: Setup<T, T>)
@@ -228,7 +570,8 @@ where
     subject_method_fragment: _,
   } = setup;
 
-  let CredentialSetup { credential, .. } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+  let CredentialSetup { credential, .. } =
+    test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
 
   let jwt: Jwt = issuer_doc
     .sign_credential(
@@ -267,8 +610,70 @@ where
 
 #[tokio::test]
 async fn matches_issuer_did_unrelated_issuer() {
-  matches_issuer_did_unrelated_issuer_impl(test_utils::setup_coredocument(None, None).await).await;
-  matches_issuer_did_unrelated_issuer_impl(test_utils::setup_iotadocument(None, None).await).await;
+  matches_issuer_did_unrelated_issuer_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  matches_issuer_did_unrelated_issuer_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
+}
+
+async fn validate_batch_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    ..
+  } = setup;
+
+  let CredentialSetup {
+    credential,
+    issuance_date,
+    expiration_date,
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
+  let mut other_credential: Credential = credential.clone();
+  other_credential.id = Some(Url::parse("https://example.edu/credentials/4242").unwrap());
+
+  let mut jwts: Vec<Jwt> = Vec::new();
+  for credential in [&credential, &other_credential] {
+    jwts.push(
+      issuer_doc
+        .sign_credential(
+          credential,
+          &storage,
+          method_fragment.as_ref(),
+          &JwsSignatureOptions::default(),
+        )
+        .await
+        .unwrap(),
+    );
+  }
+
+  // Replace the payload of the first credential with the one of the second, invalidating the signature.
+  let segments: Vec<&str> = jwts[0].as_str().split('.').collect();
+  let other_payload: &str = jwts[1].as_str().split('.').nth(1).unwrap();
+  let tampered: Jwt = Jwt::new(format!("{}.{}.{}", segments[0], other_payload, segments[2]));
+
+  let options = CredentialValidationOptions::default()
+    .latest_issuance_date(issuance_date.checked_add(Duration::days(14)).unwrap())
+    .earliest_expiry_date(expiration_date.checked_sub(Duration::hours(1)).unwrap());
+  let batch: Vec<Jwt> = vec![jwts[0].clone(), tampered, jwts[1].clone()];
+  let results =
+    CredentialValidator::new().validate_batch::<_, Object>(&batch, &issuer_doc, &options, FailFast::FirstError);
+
+  assert_eq!(results.len(), 3);
+  assert_eq!(results[0].as_ref().unwrap().credential, credential);
+  assert!(matches!(
+    results[1].as_ref().unwrap_err().validation_errors.as_slice(),
+    [ValidationError::Signature { .. }]
+  ));
+  assert_eq!(results[2].as_ref().unwrap().credential, other_credential);
+}
+
+#[tokio::test]
+async fn validate_batch() {
+  validate_batch_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  validate_batch_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
 }
 
 async fn verify_invalid_signature_impl<T>(setup: Setup<T, T>, other_setup: Setup<T, T>, fragment: &'static str)
@@ -291,7 +696,7 @@ where
     credential,
     issuance_date,
     expiration_date,
-  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
 
   // Sign the credential with the *other* issuer.
   let jwt: Jwt = other_issuer_doc
@@ -334,19 +739,87 @@ async fn verify_invalid_signature() {
   // Ensure the fragment is the same on both documents so we can produce the signature verification error.
   let fragment = "signing-key";
   verify_invalid_signature_impl(
-    test_utils::setup_coredocument(Some(fragment), None).await,
-    test_utils::setup_coredocument(Some(fragment), None).await,
+    test_utils::setup_coredocument_or_panic(Some(fragment), None).await,
+    test_utils::setup_coredocument_or_panic(Some(fragment), None).await,
     fragment,
   )
   .await;
   verify_invalid_signature_impl(
-    test_utils::setup_iotadocument(Some(fragment), None).await,
-    test_utils::setup_iotadocument(Some(fragment), None).await,
+    test_utils::setup_iotadocument_or_panic(Some(fragment), None).await,
+    test_utils::setup_iotadocument_or_panic(Some(fragment), None).await,
     fragment,
   )
   .await;
 }
 
+async fn accepted_algorithms_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    ..
+  } = setup;
+
+  let credential: Credential =
+    test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]).credential;
+  let jwt: Jwt = issuer_doc
+    .sign_credential(&credential, &storage, &method_fragment, &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+  // Replaces the `alg` of the protected header, keeping the payload and signature.
+  let with_alg = |alg: &str| -> Jwt {
+    let (header, rest) = jwt.as_str().split_once('.').unwrap();
+    let mut header: serde_json::Value = serde_json::from_slice(&decode_b64(header).unwrap()).unwrap();
+    header["alg"] = alg.into();
+    Jwt::new(format!("{}.{rest}", encode_b64(serde_json::to_vec(&header).unwrap())))
+  };
+  let verify = |jwt: &Jwt, options: &JwsVerificationOptions| {
+    let calls: Arc<AtomicUsize> = Arc::default();
+    let result = CredentialValidator::with_signature_verifier(SpyVerifier(calls.clone()))
+      .verify_signature::<_, Object>(jwt, &[&issuer_doc], options);
+    (result, calls.load(Ordering::SeqCst))
+  };
+  let is_unsupported_alg = |error: ValidationError| match error {
+    ValidationError::Signature {
+      source: JoseError::SignatureVerificationError(error),
+      ..
+    } => matches!(error.kind(), SignatureVerificationErrorKind::UnsupportedAlg),
+    _ => false,
+  };
+
+  // EdDSA is accepted by default.
+  let (result, calls) = verify(&jwt, &JwsVerificationOptions::default());
+  assert!(result.is_ok());
+  assert_eq!(calls, 1);
+
+  // Algorithms that are not accepted are rejected without invoking the verifier.
+  let options: JwsVerificationOptions = JwsVerificationOptions::default().accepted_algorithms([JwsAlgorithm::ES256]);
+  let (result, calls) = verify(&jwt, &options);
+  assert!(is_unsupported_alg(result.unwrap_err()));
+  assert_eq!(calls, 0);
+
+  let (result, calls) = verify(&with_alg("HS256"), &JwsVerificationOptions::default());
+  assert!(is_unsupported_alg(result.unwrap_err()));
+  assert_eq!(calls, 0);
+
+  // `none` is rejected, even if it is explicitly accepted.
+  let options: JwsVerificationOptions =
+    JwsVerificationOptions::default().accepted_algorithms([JwsAlgorithm::EdDSA, JwsAlgorithm::NONE]);
+  let (result, calls) = verify(&with_alg("none"), &options);
+  assert!(is_unsupported_alg(result.unwrap_err()));
+  assert_eq!(calls, 0);
+}
+
+#[tokio::test]
+async fn accepted_algorithms() {
+  accepted_algorithms_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  accepted_algorithms_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
+}
+
 async fn check_subject_holder_relationship_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
@@ -458,8 +931,8 @@ where
 
 #[tokio::test]
 async fn check_subject_holder_relationship() {
-  check_subject_holder_relationship_impl(test_utils::setup_coredocument(None, None).await).await;
-  check_subject_holder_relationship_impl(test_utils::setup_iotadocument(None, None).await).await;
+  check_subject_holder_relationship_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  check_subject_holder_relationship_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
 }
 
 fn check_status_impl<T, F>(setup: Setup<T, T>, insert_service: F)
@@ -473,7 +946,7 @@ where
     ..
   } = setup;
   let CredentialSetup { mut credential, .. } =
-    test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+    test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
 
   // 0: missing status always succeeds.
   for status_check in [StatusCheck::Strict, StatusCheck::SkipUnsupported, StatusCheck::SkipAll] {
@@ -549,13 +1022,77 @@ where
 #[tokio::test]
 async fn check_status() {
   check_status_impl(
-    test_utils::setup_coredocument(None, None).await,
+    test_utils::setup_coredocument_or_panic(None, None).await,
     |document: &mut CoreDocument, service: Service| {
       document.insert_service(service).unwrap();
     },
   );
 }
 
+async fn check_status_list_2021_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    ..
+  } = setup;
+  let CredentialSetup { mut credential, .. } =
+    test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
+  let list_url: Url = Url::parse("https://example.com/credentials/status/3").unwrap();
+
+  for purpose in [StatusPurpose::Revocation, StatusPurpose::Suspension] {
+    let mut status_list: StatusList2021 = StatusList2021::new(purpose);
+    status_list.set(42).unwrap();
+    let status_list_credential: Credential = CredentialBuilder::default()
+      .id(list_url.clone())
+      .issuer(Url::parse(issuer_doc.as_ref().id().as_str()).unwrap())
+      .type_(StatusList2021::CREDENTIAL_TYPE)
+      .subject(status_list.to_subject(list_url.join("#list").unwrap()).unwrap())
+      .build()
+      .unwrap();
+    let status_resolver = |url: &Url| -> Result<Credential, identity_credential::Error> {
+      assert_eq!(url, &list_url);
+      Ok(status_list_credential.clone())
+    };
+    let check = |credential: &Credential| {
+      CredentialValidator::check_status_with_resolver(credential, &[&issuer_doc], &status_resolver, StatusCheck::Strict)
+    };
+
+    // The status of index 42 is set, the one of index 43 is not.
+    credential.credential_status =
+      Some(StatusList2021Entry::new(list_url.join("#42").unwrap(), purpose, 42, list_url.clone()).into());
+    let error: ValidationError = check(&credential).unwrap_err();
+    match purpose {
+      StatusPurpose::Revocation => assert!(matches!(error, ValidationError::Revoked)),
+      StatusPurpose::Suspension => assert!(matches!(error, ValidationError::Suspended)),
+    }
+
+    credential.credential_status =
+      Some(StatusList2021Entry::new(list_url.join("#43").unwrap(), purpose, 43, list_url.clone()).into());
+    assert!(check(&credential).is_ok());
+
+    // The purpose of the entry must match the purpose of the list.
+    let other_purpose: StatusPurpose = match purpose {
+      StatusPurpose::Revocation => StatusPurpose::Suspension,
+      StatusPurpose::Suspension => StatusPurpose::Revocation,
+    };
+    credential.credential_status =
+      Some(StatusList2021Entry::new(list_url.join("#42").unwrap(), other_purpose, 42, list_url.clone()).into());
+    assert!(matches!(
+      check(&credential).unwrap_err(),
+      ValidationError::InvalidStatus(_)
+    ));
+  }
+}
+
+#[tokio::test]
+async fn check_status_list_2021() {
+  check_status_list_2021_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  check_status_list_2021_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
+}
+
 async fn full_validation_fail_fast_impl<T, U>(setup: Setup<T, U>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
@@ -574,7 +1111,7 @@ where
     credential,
     issuance_date,
     expiration_date,
-  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None, &[]);
 
   let jws = issuer_doc
     .sign_credential(
@@ -611,6 +1148,6 @@ where
 
 #[tokio::test]
 async fn full_validation_fail_fast() {
-  full_validation_fail_fast_impl(test_utils::setup_coredocument(None, None).await).await;
-  full_validation_fail_fast_impl(test_utils::setup_iotadocument(None, None).await).await;
+  full_validation_fail_fast_impl(test_utils::setup_coredocument_or_panic(None, None).await).await;
+  full_validation_fail_fast_impl(test_utils::setup_iotadocument_or_panic(None, None).await).await;
 }
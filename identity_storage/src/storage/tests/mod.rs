@@ -5,4 +5,6 @@ mod api;
 mod credential_jws;
 mod credential_validation;
 mod presentation_validation;
+#[cfg(feature = "sd-jwt")]
+mod sd_jwt;
 mod test_utils;
@@ -5,30 +5,55 @@ use identity_core::common::Duration;
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::common::Value;
 use identity_core::convert::FromJson;
+use identity_core::utils::Base;
+use identity_core::utils::BaseEncoding;
 use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
 use identity_credential::credential::Jwt;
+use identity_credential::credential::RevocationList2020Status;
+use identity_credential::credential::Schema;
+use identity_credential::credential::Subject;
+use identity_credential::presentation::Confirmation;
 use identity_credential::presentation::JwtPresentation;
 use identity_credential::presentation::JwtPresentationBuilder;
 use identity_credential::presentation::JwtPresentationOptions;
+use identity_credential::revocation::RevocationBitstring;
+use identity_credential::validator::CompoundJwtPresentationValidationError;
+use identity_credential::validator::CredentialErrorPolicy;
+use identity_credential::validator::CredentialValidationOptions;
 use identity_credential::validator::DecodedJwtPresentation;
 use identity_credential::validator::FailFast;
 use identity_credential::validator::JwtPresentationValidationOptions;
 use identity_credential::validator::JwtPresentationValidator;
 use identity_credential::validator::ValidationError;
 use identity_did::CoreDID;
+use identity_did::DIDUrl;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jws::CompactJwsEncoder;
+use identity_verification::jws::EdDSAJwsVerifier;
 use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsHeader;
 
 use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+use serde_json::json;
 
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
 use crate::storage::tests::test_utils::generate_credential;
-use crate::storage::tests::test_utils::setup_coredocument;
-use crate::storage::tests::test_utils::setup_iotadocument;
+use crate::storage::tests::test_utils::setup_coredocument_or_panic;
+use crate::storage::tests::test_utils::setup_iotadocument_or_panic;
 use crate::storage::tests::test_utils::Setup;
+use crate::verify_jwt_presentation;
 use crate::JwkDocumentExt;
 use crate::JwkMemStore;
+use crate::JwkStorageDocumentError;
 
 use crate::JwsSignatureOptions;
 
@@ -36,14 +61,14 @@ use super::test_utils::CredentialSetup;
 
 #[tokio::test]
 async fn test_valid_presentation() {
-  test_valid_presentation_impl(setup_coredocument(None, None).await).await;
-  test_valid_presentation_impl(setup_iotadocument(None, None).await).await;
+  test_valid_presentation_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_valid_presentation_impl(setup_iotadocument_or_panic(None, None).await).await;
 }
 async fn test_valid_presentation_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
 {
-  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
   let jws = sign_credential(&setup, &credential.credential).await;
 
   let presentation: JwtPresentation =
@@ -95,17 +120,17 @@ where
 
 #[tokio::test]
 async fn test_extract_dids() {
-  test_extract_dids_impl(setup_coredocument(None, None).await).await;
-  test_extract_dids_impl(setup_iotadocument(None, None).await).await;
+  test_extract_dids_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_extract_dids_impl(setup_iotadocument_or_panic(None, None).await).await;
 }
 async fn test_extract_dids_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
 {
-  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
 
   let issuer_2 = CoreDocument::from_json(r#"{"id": "did:test:123"}"#).unwrap();
-  let credential_2: CredentialSetup = generate_credential(&issuer_2, &[&setup.subject_doc], None, None);
+  let credential_2: CredentialSetup = generate_credential(&issuer_2, &[&setup.subject_doc], None, None, &[]);
   let jws = sign_credential(&setup, &credential.credential).await;
   let jws_2 = sign_credential(&setup, &credential_2.credential).await;
 
@@ -141,6 +166,9 @@ where
     setup.issuer_doc.as_ref().id().to_url()
   );
   assert_eq!(issuers.get(1).unwrap().to_url(), issuer_2.as_ref().id().to_url());
+
+  let holder: CoreDID = JwtPresentationValidator::extract_holder(&presentation_jwt).unwrap();
+  assert_eq!(holder.to_url(), setup.subject_doc.as_ref().id().to_url());
 }
 
 // > Create a VP signed by a verification method with `subject_method_fragment`.
@@ -148,14 +176,14 @@ where
 // > Validation fails due to invalid signature since key material changed.
 #[tokio::test]
 async fn test_invalid_signature() {
-  test_invalid_signature_impl(setup_coredocument(None, None).await).await;
-  test_invalid_signature_impl(setup_iotadocument(None, None).await).await;
+  test_invalid_signature_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_invalid_signature_impl(setup_iotadocument_or_panic(None, None).await).await;
 }
 async fn test_invalid_signature_impl<T>(mut setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
 {
-  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
   let jws = sign_credential(&setup, &credential.credential).await;
 
   let presentation: JwtPresentation =
@@ -231,14 +259,14 @@ where
 
 #[tokio::test]
 async fn expiration_date() {
-  expiration_date_impl(setup_coredocument(None, None).await).await;
-  expiration_date_impl(setup_iotadocument(None, None).await).await;
+  expiration_date_impl(setup_coredocument_or_panic(None, None).await).await;
+  expiration_date_impl(setup_iotadocument_or_panic(None, None).await).await;
 }
 async fn expiration_date_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument> + Clone,
 {
-  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
   let jws = sign_credential(&setup, &credential.credential).await;
 
   let presentation: JwtPresentation =
@@ -302,15 +330,15 @@ where
 
 #[tokio::test]
 async fn issuance_date() {
-  issuance_date_impl(setup_coredocument(None, None).await).await;
-  issuance_date_impl(setup_iotadocument(None, None).await).await;
+  issuance_date_impl(setup_coredocument_or_panic(None, None).await).await;
+  issuance_date_impl(setup_iotadocument_or_panic(None, None).await).await;
 }
 
 async fn issuance_date_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument> + Clone,
 {
-  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
   let jws = sign_credential(&setup, &credential.credential).await;
 
   let presentation: JwtPresentation =
@@ -375,15 +403,15 @@ where
 
 #[tokio::test]
 async fn presentation_jws_error() {
-  presentation_jws_error_impl(setup_coredocument(None, None).await).await;
-  presentation_jws_error_impl(setup_iotadocument(None, None).await).await;
+  presentation_jws_error_impl(setup_coredocument_or_panic(None, None).await).await;
+  presentation_jws_error_impl(setup_iotadocument_or_panic(None, None).await).await;
 }
 
 async fn presentation_jws_error_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument> + Clone,
 {
-  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
   let jws = sign_credential(&setup, &credential.credential).await;
 
   let presentation: JwtPresentation =
@@ -429,6 +457,786 @@ where
   ));
 }
 
+// > Issue a credential with a `RevocationList2020Status` pointing to index 42.
+// > Validate a presentation of it against a revocation list in which index 42 is set.
+// > Validation fails since the credential is revoked.
+#[tokio::test]
+async fn test_revocation_list_2020_status() {
+  test_revocation_list_2020_status_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_revocation_list_2020_status_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_revocation_list_2020_status_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument> + Clone,
+{
+  // A 16KB GZIP-compressed, base64url-encoded bitstring in which only index 42 is set.
+  const ENCODED_LIST: &str = "H4sIAAAAAAACA-3BMQEAAAjAoEWxf0pt4QPUmQAAAAAAAAAAAAAAAAAAAIBHCzOa7TAAQAAA";
+  let list_url: Url = Url::parse("https://example.com/credentials/status/3").unwrap();
+
+  let revocation_list: Credential = CredentialBuilder::default()
+    .id(list_url.clone())
+    .issuer(Url::parse(setup.issuer_doc.as_ref().id().as_str()).unwrap())
+    .type_("RevocationList2020Credential")
+    .subject(
+      Subject::from_json_value(json!({
+        "id": "https://example.com/status/3#list",
+        "type": RevocationBitstring::TYPE,
+        "encodedList": ENCODED_LIST,
+      }))
+      .unwrap(),
+    )
+    .build()
+    .unwrap();
+  let status_resolver = |url: &Url| -> Result<Credential, identity_credential::Error> {
+    assert_eq!(url, &list_url);
+    Ok(revocation_list.clone())
+  };
+
+  for (index, revoked) in [(42, true), (43, false)] {
+    let expiration_date: Timestamp = Timestamp::now_utc().checked_add(Duration::days(1)).unwrap();
+    let mut credential: CredentialSetup = generate_credential(
+      &setup.issuer_doc,
+      &[&setup.subject_doc],
+      None,
+      Some(expiration_date),
+      &[],
+    );
+    credential.credential.credential_status =
+      Some(RevocationList2020Status::new(list_url.join(format!("#{index}")).unwrap(), index, list_url.clone()).into());
+    let jws = sign_credential(&setup, &credential.credential).await;
+
+    let presentation: JwtPresentation =
+      JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+        .credential(jws)
+        .build()
+        .unwrap();
+
+    let presentation_jwt = setup
+      .subject_doc
+      .sign_presentation(
+        &presentation,
+        &setup.subject_storage,
+        &setup.subject_method_fragment,
+        &JwsSignatureOptions::default(),
+        &JwtPresentationOptions::default(),
+      )
+      .await
+      .unwrap();
+
+    let result = JwtPresentationValidator::new().validate_with_status_resolver::<_, _, Object, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &[setup.issuer_doc.clone()],
+      &JwtPresentationValidationOptions::default(),
+      &status_resolver,
+      FailFast::FirstError,
+    );
+
+    if revoked {
+      let error = result.unwrap_err();
+      let credential_error = error.credential_errors.get(&0).unwrap();
+      assert!(matches!(
+        credential_error.validation_errors.as_slice(),
+        [ValidationError::Revoked]
+      ));
+    } else {
+      assert!(result.is_ok());
+    }
+  }
+}
+
+#[tokio::test]
+async fn test_credential_schema() {
+  test_credential_schema_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_credential_schema_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_credential_schema_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument> + Clone,
+{
+  let schema_url: Url = Url::parse("https://example.edu/schemas/degree.json").unwrap();
+  let schema_resolver = |url: &Url| -> Result<Value, std::io::Error> {
+    assert_eq!(url.as_str(), "https://example.edu/schemas/degree.json");
+    Ok(json!({
+      "type": "object",
+      "required": ["id", "degree"],
+      "properties": {
+        "GPA": { "type": "number" }
+      }
+    }))
+  };
+
+  let mut credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
+  credential.credential.credential_schema =
+    Schema::new(schema_url.clone(), "JsonSchemaValidator2018".to_owned()).into();
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  let presentation: JwtPresentation =
+    JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws)
+      .build()
+      .unwrap();
+
+  let presentation_jwt = setup
+    .subject_doc
+    .sign_presentation(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let options: JwtPresentationValidationOptions = JwtPresentationValidationOptions::default()
+    .shared_validation_options(CredentialValidationOptions::default().check_credential_schema(true));
+
+  // The `GPA` of the generated credential is a string.
+  let error = JwtPresentationValidator::new()
+    .validate_with_schema_resolver::<_, _, Object, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      std::slice::from_ref(&setup.issuer_doc),
+      &options,
+      &schema_resolver,
+      FailFast::FirstError,
+    )
+    .unwrap_err();
+  let credential_error = error.credential_errors.get(&0).unwrap();
+  assert!(matches!(
+    credential_error.validation_errors.as_slice(),
+    [ValidationError::SubjectSchema { schema, .. }] if schema == &schema_url
+  ));
+
+  // Without a resolver the schema cannot be fetched.
+  let error = JwtPresentationValidator::new()
+    .validate::<_, _, Object, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      std::slice::from_ref(&setup.issuer_doc),
+      &options,
+      FailFast::FirstError,
+    )
+    .unwrap_err();
+  let credential_error = error.credential_errors.get(&0).unwrap();
+  assert!(matches!(
+    credential_error.validation_errors.as_slice(),
+    [ValidationError::SchemaResolution(_)]
+  ));
+}
+
+#[tokio::test]
+async fn test_verify_jwt_presentation() {
+  test_verify_jwt_presentation_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_verify_jwt_presentation_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_verify_jwt_presentation_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  let presentation: JwtPresentation =
+    JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws)
+      .build()
+      .unwrap();
+
+  let presentation_jwt = setup
+    .subject_doc
+    .sign_presentation(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let verified_presentation: JwtPresentation = verify_jwt_presentation(
+    &presentation_jwt,
+    &setup.subject_doc,
+    EdDSAJwsVerifier::default(),
+    &JwtPresentationValidationOptions::default(),
+  )
+  .unwrap();
+  assert_eq!(verified_presentation.holder, presentation.holder);
+  assert_eq!(
+    verified_presentation.verifiable_credential,
+    presentation.verifiable_credential
+  );
+
+  // Verification against a document that did not sign the presentation fails.
+  let error = verify_jwt_presentation::<_, _, Object>(
+    &presentation_jwt,
+    &setup.issuer_doc,
+    EdDSAJwsVerifier::default(),
+    &JwtPresentationValidationOptions::default(),
+  )
+  .unwrap_err();
+  assert!(matches!(
+    error,
+    JwkStorageDocumentError::PresentationVerificationError(ValidationError::PresentationJwsError(_))
+  ));
+}
+
+#[tokio::test]
+async fn test_presentation_holder_binding() {
+  test_presentation_holder_binding_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_presentation_holder_binding_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_presentation_holder_binding_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  let holder: Url = setup.subject_doc.as_ref().id().to_url().into();
+  let kid: Url = holder.join(format!("#{}", setup.subject_method_fragment)).unwrap();
+  let presentation: JwtPresentation = JwtPresentationBuilder::new(holder.clone(), Object::new())
+    .credential(jws.clone())
+    .holder_binding(kid.clone())
+    .build()
+    .unwrap();
+
+  let presentation_jwt = setup
+    .subject_doc
+    .sign_presentation(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  // The `cnf` claim is part of the JWT claims set.
+  let payload: &str = presentation_jwt.as_str().split('.').nth(1).unwrap();
+  let claims: Object = Object::from_json_slice(&BaseEncoding::decode(payload, Base::Base64Url).unwrap()).unwrap();
+  assert_eq!(claims.get("cnf").unwrap(), &json!({ "kid": kid.as_str() }));
+
+  let verified_presentation: JwtPresentation = verify_jwt_presentation(
+    &presentation_jwt,
+    &setup.subject_doc,
+    EdDSAJwsVerifier::default(),
+    &JwtPresentationValidationOptions::default(),
+  )
+  .unwrap();
+  assert_eq!(verified_presentation.holder_binding, Some(Confirmation::new(kid)));
+
+  // Signing fails if the referenced key does not exist in the holder's document.
+  let presentation: JwtPresentation = JwtPresentationBuilder::new(holder.clone(), Object::new())
+    .credential(jws)
+    .holder_binding(holder.join("#does-not-exist").unwrap())
+    .build()
+    .unwrap();
+  let error = setup
+    .subject_doc
+    .sign_presentation(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(error, JwkStorageDocumentError::MethodNotFound));
+
+  // Validation fails if the referenced key does not exist in the holder's document, even though the presentation is
+  // signed by the holder.
+  let claims: Object =
+    Object::from_json(&presentation.serialize_jwt(&JwtPresentationOptions::default()).unwrap()).unwrap();
+  let presentation_jwt: Jwt = sign_claims(&setup, &claims).await;
+
+  let error = verify_jwt_presentation::<_, _, Object>(
+    &presentation_jwt,
+    &setup.subject_doc,
+    EdDSAJwsVerifier::default(),
+    &JwtPresentationValidationOptions::default(),
+  )
+  .unwrap_err();
+  assert!(matches!(
+    error,
+    JwkStorageDocumentError::PresentationVerificationError(ValidationError::PresentationStructure(
+      identity_credential::Error::InvalidHolderBinding(_)
+    ))
+  ));
+
+  let error: CompoundJwtPresentationValidationError =
+    JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+      .validate::<_, _, Object, Object>(
+        &presentation_jwt,
+        &setup.subject_doc,
+        &[&setup.issuer_doc],
+        &JwtPresentationValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .unwrap_err();
+  assert!(matches!(
+    error.presentation_validation_errors.as_slice(),
+    [ValidationError::PresentationStructure(
+      identity_credential::Error::InvalidHolderBinding(_)
+    )]
+  ));
+}
+
+#[tokio::test]
+async fn test_presentation_audience() {
+  test_presentation_audience_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_presentation_audience_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_presentation_audience_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
+  let jws = sign_credential(&setup, &credential.credential).await;
+  let presentation: JwtPresentation =
+    JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws)
+      .build()
+      .unwrap();
+
+  let verifier: Url = Url::parse("did:test:verifier").unwrap();
+  let validate = |presentation_jwt: &Jwt| {
+    JwtPresentationValidator::new().validate::<_, _, Object, Object>(
+      presentation_jwt,
+      &setup.subject_doc,
+      &[&setup.issuer_doc],
+      &JwtPresentationValidationOptions::default().expected_audience(verifier.clone()),
+      FailFast::FirstError,
+    )
+  };
+  let assert_audience_error = |presentation_jwt: &Jwt| {
+    let error = validate(presentation_jwt).unwrap_err();
+    assert!(matches!(
+      error.presentation_validation_errors.as_slice(),
+      [ValidationError::Audience]
+    ));
+  };
+
+  // Matching audience.
+  let presentation_jwt: Jwt = sign_presentation_with_audience(&setup, &presentation, Some(verifier.clone())).await;
+  assert_eq!(validate(&presentation_jwt).unwrap().aud, Some(verifier.clone()));
+
+  // Mismatched audience.
+  let presentation_jwt: Jwt =
+    sign_presentation_with_audience(&setup, &presentation, Some(Url::parse("did:test:other").unwrap())).await;
+  assert_audience_error(&presentation_jwt);
+  // The audience is not checked unless expected.
+  assert!(JwtPresentationValidator::new()
+    .validate::<_, _, Object, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &[&setup.issuer_doc],
+      &JwtPresentationValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .is_ok());
+
+  // Missing audience.
+  let presentation_jwt: Jwt = sign_presentation_with_audience(&setup, &presentation, None).await;
+  assert_audience_error(&presentation_jwt);
+
+  // Array audience, re-signing the claims of a presentation with an `aud` array.
+  let payload: &str = presentation_jwt.as_str().split('.').nth(1).unwrap();
+  let mut claims: Object = Object::from_json_slice(&BaseEncoding::decode(payload, Base::Base64Url).unwrap()).unwrap();
+  claims.insert("aud".to_owned(), json!(["did:test:other", verifier.as_str()]));
+  let presentation_jwt: Jwt = sign_claims(&setup, &claims).await;
+  assert_eq!(
+    validate(&presentation_jwt).unwrap().aud,
+    Some(Url::parse("did:test:other").unwrap())
+  );
+
+  claims.insert("aud".to_owned(), json!(["did:test:other", "did:test:another"]));
+  let presentation_jwt: Jwt = sign_claims(&setup, &claims).await;
+  assert_audience_error(&presentation_jwt);
+}
+
+#[tokio::test]
+async fn test_presentation_challenge() {
+  test_presentation_challenge_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_presentation_challenge_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_presentation_challenge_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
+  let jws = sign_credential(&setup, &credential.credential).await;
+  let presentation: JwtPresentation =
+    JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws)
+      .build()
+      .unwrap();
+
+  let validate = |presentation_jwt: &Jwt, challenge: &str| {
+    JwtPresentationValidator::new().validate::<_, _, Object, Object>(
+      presentation_jwt,
+      &setup.subject_doc,
+      &[&setup.issuer_doc],
+      &JwtPresentationValidationOptions::default().challenge(challenge),
+      FailFast::FirstError,
+    )
+  };
+  let is_challenge_error = |result: Result<DecodedJwtPresentation, CompoundJwtPresentationValidationError>| {
+    matches!(
+      result.unwrap_err().presentation_validation_errors.as_slice(),
+      [ValidationError::Challenge]
+    )
+  };
+
+  // Correct challenge.
+  let signature_options = JwsSignatureOptions::default().nonce("challenge-1".to_owned());
+  let presentation_jwt: Jwt = setup
+    .subject_doc
+    .sign_presentation(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &signature_options,
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+  assert!(validate(&presentation_jwt, "challenge-1").is_ok());
+
+  // Wrong challenge.
+  assert!(is_challenge_error(validate(&presentation_jwt, "challenge-2")));
+
+  // Missing challenge.
+  let presentation_jwt: Jwt = sign_presentation_with_audience(&setup, &presentation, None).await;
+  assert!(is_challenge_error(validate(&presentation_jwt, "challenge-1")));
+}
+
+#[tokio::test]
+async fn test_max_credentials() {
+  test_max_credentials_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_max_credentials_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_max_credentials_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
+  let jws = sign_credential(&setup, &credential.credential).await;
+  let presentation: JwtPresentation =
+    JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws.clone())
+      .credential(jws)
+      .build()
+      .unwrap();
+  let presentation_jwt: Jwt = sign_presentation_with_audience(&setup, &presentation, None).await;
+
+  let validate = |holder: &T, max_credentials: usize| {
+    JwtPresentationValidator::new().validate::<_, _, Object, Object>(
+      &presentation_jwt,
+      holder,
+      &[&setup.issuer_doc],
+      &JwtPresentationValidationOptions::default().max_credentials(max_credentials),
+      FailFast::FirstError,
+    )
+  };
+
+  assert!(validate(&setup.subject_doc, 2).is_ok());
+  assert!(matches!(
+    validate(&setup.subject_doc, 1)
+      .unwrap_err()
+      .presentation_validation_errors
+      .as_slice(),
+    [ValidationError::TooManyCredentials(1)]
+  ));
+  // The count is checked before the signature, which cannot be verified with the issuer's document.
+  assert!(matches!(
+    validate(&setup.issuer_doc, 1)
+      .unwrap_err()
+      .presentation_validation_errors
+      .as_slice(),
+    [ValidationError::TooManyCredentials(1)]
+  ));
+  assert!(matches!(
+    validate(&setup.issuer_doc, 2)
+      .unwrap_err()
+      .presentation_validation_errors
+      .as_slice(),
+    [ValidationError::PresentationJwsError(_)]
+  ));
+}
+
+#[tokio::test]
+async fn test_credential_error_policy() {
+  test_credential_error_policy_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_credential_error_policy_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_credential_error_policy_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  // An expired credential and a credential issued in the future.
+  let expired: CredentialSetup = generate_credential(
+    &setup.issuer_doc,
+    &[&setup.subject_doc],
+    Some(Timestamp::parse("2020-01-01T00:00:00Z").unwrap()),
+    Some(Timestamp::parse("2021-01-01T00:00:00Z").unwrap()),
+    &[],
+  );
+  let not_yet_issued: CredentialSetup = generate_credential(
+    &setup.issuer_doc,
+    &[&setup.subject_doc],
+    Some(Timestamp::now_utc().checked_add(Duration::hours(10)).unwrap()),
+    Some(Timestamp::now_utc().checked_add(Duration::hours(20)).unwrap()),
+    &[],
+  );
+  let presentation: JwtPresentation =
+    JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(sign_credential(&setup, &expired.credential).await)
+      .credential(sign_credential(&setup, &not_yet_issued.credential).await)
+      .build()
+      .unwrap();
+  let presentation_jwt: Jwt = sign_presentation_with_audience(&setup, &presentation, None).await;
+
+  let validate = |policy: CredentialErrorPolicy| {
+    JwtPresentationValidator::new()
+      .validate::<_, _, Object, Object>(
+        &presentation_jwt,
+        &setup.subject_doc,
+        &[&setup.issuer_doc],
+        &JwtPresentationValidationOptions::default().credential_error_policy(policy),
+        FailFast::FirstError,
+      )
+      .unwrap_err()
+  };
+
+  // By default only the first invalid credential is reported when failing fast.
+  let error: CompoundJwtPresentationValidationError = validate(CredentialErrorPolicy::FollowFailFast);
+  assert!(error.presentation_validation_errors.is_empty());
+  assert_eq!(error.credential_errors.len(), 1);
+
+  // Both invalid credentials are reported when accumulating.
+  for policy in [
+    CredentialErrorPolicy::AccumulateFirstErrors,
+    CredentialErrorPolicy::AccumulateAllErrors,
+  ] {
+    let error: CompoundJwtPresentationValidationError = validate(policy);
+    assert!(error.presentation_validation_errors.is_empty());
+    assert_eq!(error.credential_errors.len(), 2);
+    assert!(matches!(
+      error.credential_errors[&0].validation_errors.as_slice(),
+      [ValidationError::ExpirationDate]
+    ));
+    assert!(matches!(
+      error.credential_errors[&1].validation_errors.as_slice(),
+      [ValidationError::IssuanceDate]
+    ));
+  }
+}
+
+#[tokio::test]
+async fn test_check_all_subjects_are_holder() {
+  test_check_all_subjects_are_holder_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_check_all_subjects_are_holder_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_check_all_subjects_are_holder_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let held: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None, &[]);
+  let foreign: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.issuer_doc], None, None, &[]);
+  let held_jws: Jwt = sign_credential(&setup, &held.credential).await;
+  let foreign_jws: Jwt = sign_credential(&setup, &foreign.credential).await;
+
+  let presentation = |credentials: &[&Jwt]| -> JwtPresentation {
+    credentials
+      .iter()
+      .fold(
+        JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new()),
+        |builder, credential| builder.credential((*credential).clone()),
+      )
+      .build()
+      .unwrap()
+  };
+
+  // A credential issued as a JWT has exactly one subject, the holder must be the subject of every credential.
+  assert!(JwtPresentationValidator::check_all_subjects_are_holder(&presentation(&[&held_jws])).is_ok());
+  assert!(matches!(
+    JwtPresentationValidator::check_all_subjects_are_holder(&presentation(&[&held_jws, &foreign_jws])).unwrap_err(),
+    ValidationError::SubjectHolderRelationship { .. }
+  ));
+}
+
+#[tokio::test]
+async fn test_verify_presentation_signature() {
+  test_verify_presentation_signature_impl(setup_coredocument_or_panic(None, None).await).await;
+  test_verify_presentation_signature_impl(setup_iotadocument_or_panic(None, None).await).await;
+}
+async fn test_verify_presentation_signature_impl<T>(mut setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  // The holder has two authentication methods in addition to the assertion method of the setup.
+  for fragment in ["auth-1", "auth-2"] {
+    setup
+      .subject_doc
+      .generate_method(
+        &setup.subject_storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        Some(fragment),
+        MethodScope::authentication(),
+      )
+      .await
+      .unwrap();
+  }
+
+  let presentation: JwtPresentation =
+    JwtPresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .build()
+      .unwrap();
+  let validator: JwtPresentationValidator = JwtPresentationValidator::new();
+
+  // The second authentication method signed, which is the method reported as verifying.
+  let presentation_jwt: Jwt = setup
+    .subject_doc
+    .sign_presentation(
+      &presentation,
+      &setup.subject_storage,
+      "auth-2",
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+  let (verified, method_id): (JwtPresentation, DIDUrl) = validator
+    .verify_presentation_signature(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwsVerificationOptions::default(),
+    )
+    .unwrap();
+  assert_eq!(verified.holder, presentation.holder);
+  assert_eq!(method_id.fragment(), Some("auth-2"));
+
+  // The method referenced by the `kid` is tried first, the others are tried if it does not verify the signature.
+  let claims: String = presentation.serialize_jwt(&JwtPresentationOptions::default()).unwrap();
+  let auth_1: DIDUrl = setup.subject_doc.as_ref().id().to_url().join("#auth-1").unwrap();
+  for kid in [Some(&auth_1), None] {
+    let presentation_jwt: Jwt = sign_with_kid(&setup, "auth-2", kid, &claims).await;
+    let (_, method_id): (JwtPresentation, DIDUrl) = validator
+      .verify_presentation_signature(
+        &presentation_jwt,
+        &setup.subject_doc,
+        &JwsVerificationOptions::default(),
+      )
+      .unwrap();
+    assert_eq!(method_id.fragment(), Some("auth-2"));
+  }
+
+  // A signature by a method that is not authentication-scoped is rejected.
+  let presentation_jwt: Jwt = setup
+    .subject_doc
+    .sign_presentation(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+  assert!(matches!(
+    validator
+      .verify_presentation_signature::<_, Object>(
+        &presentation_jwt,
+        &setup.subject_doc,
+        &JwsVerificationOptions::default()
+      )
+      .unwrap_err(),
+    ValidationError::PresentationJwsError(identity_document::Error::JwsVerificationError(_))
+  ));
+}
+
+async fn sign_presentation_with_audience<T>(
+  setup: &Setup<T, T>,
+  presentation: &JwtPresentation,
+  audience: Option<Url>,
+) -> Jwt
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let presentation_options = JwtPresentationOptions {
+    audience,
+    ..Default::default()
+  };
+  setup
+    .subject_doc
+    .sign_presentation(
+      presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &presentation_options,
+    )
+    .await
+    .unwrap()
+}
+
+/// Signs arbitrary presentation `claims` with the subject's key.
+async fn sign_claims<T>(setup: &Setup<T, T>, claims: &Object) -> Jwt
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let jws = setup
+    .subject_doc
+    .sign_bytes(
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &serde_json::to_vec(claims).unwrap(),
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap();
+  Jwt::new(jws.into())
+}
+
+/// Signs `claims` with the key of the subject's method identified by `fragment`, but references `kid` instead of that
+/// method in the protected header.
+async fn sign_with_kid<T>(setup: &Setup<T, T>, fragment: &str, kid: Option<&DIDUrl>, claims: &str) -> Jwt
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let method: &VerificationMethod = setup.subject_doc.as_ref().resolve_method(fragment, None).unwrap();
+  let key_id: KeyId = setup
+    .subject_storage
+    .key_id_storage()
+    .get_key_id(&MethodDigest::new(method).unwrap())
+    .await
+    .unwrap();
+
+  let mut header: JwsHeader = JwsHeader::new();
+  header.set_alg(JwsAlgorithm::EdDSA);
+  if let Some(kid) = kid {
+    header.set_kid(kid.to_string());
+  }
+  let encoder: CompactJwsEncoder<'_> = CompactJwsEncoder::new(claims.as_bytes(), &header).unwrap();
+  let signature: Vec<u8> = setup
+    .subject_storage
+    .key_storage()
+    .sign(
+      &key_id,
+      encoder.signing_input(),
+      method.data().try_public_key_jwk().unwrap(),
+    )
+    .await
+    .unwrap();
+  Jwt::new(encoder.into_jws(&signature))
+}
+
 async fn sign_credential<T>(setup: &Setup<T, T>, credential: &Credential) -> Jwt
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
@@ -0,0 +1,160 @@
+// Copyright 2020-2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jose::jws::DecodedJws;
+use identity_verification::jose::jws::EdDSAJwsVerifier;
+use identity_verification::jose::jwu;
+use serde_json::json;
+
+use crate::storage::tests::credential_jws::setup;
+use crate::storage::verify_sd_jwt;
+use crate::storage::Disclosure;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwkStorageDocumentError;
+use crate::storage::JwsSignatureOptions;
+use crate::storage::SdJwt;
+
+#[tokio::test]
+async fn create_sd_jwt() {
+  let (document, storage, kid, credential) = setup().await;
+
+  let sd_jwt: SdJwt = document
+    .create_sd_jwt(
+      &credential,
+      &storage,
+      kid.as_ref(),
+      &["/degree/type", "/degree/name"],
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let decoded: DecodedJws<'_> = document
+    .verify_jws(
+      sd_jwt.jwt().as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default(),
+    )
+    .unwrap();
+  let claims: Value = serde_json::from_slice(&decoded.claims).unwrap();
+  assert_eq!(claims["_sd_alg"], "sha-256");
+
+  // The concealed claims are replaced by the digests of their disclosures.
+  let degree: &Value = &claims["vc"]["credentialSubject"]["degree"];
+  assert!(degree.get("type").is_none());
+  assert!(degree.get("name").is_none());
+  let mut digests: Vec<String> = sd_jwt.disclosures().iter().map(Disclosure::digest).collect();
+  digests.sort();
+  assert_eq!(degree["_sd"], json!(digests));
+
+  // Each disclosure encodes one of the concealed claims.
+  for disclosure in sd_jwt.disclosures() {
+    let decoded: Value = serde_json::from_slice(&jwu::decode_b64(disclosure.as_str()).unwrap()).unwrap();
+    assert_eq!(
+      decoded,
+      json!([disclosure.salt(), disclosure.claim_name(), disclosure.claim_value()])
+    );
+    assert_eq!(
+      credential.subject_value(&format!("/degree/{}", disclosure.claim_name())),
+      Some(disclosure.claim_value())
+    );
+  }
+  assert_eq!(
+    sd_jwt.to_string(),
+    format!(
+      "{}~{}~{}~",
+      sd_jwt.jwt().as_str(),
+      sd_jwt.disclosures()[0],
+      sd_jwt.disclosures()[1]
+    )
+  );
+
+  // INVALID: the pointer does not identify a claim of the subject.
+  let err = document
+    .create_sd_jwt(
+      &credential,
+      &storage,
+      kid.as_ref(),
+      &["/degree/grade"],
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(err, JwkStorageDocumentError::UndisclosableClaim(_)));
+}
+
+#[tokio::test]
+async fn verify_sd_jwt_round_trip() {
+  let (document, storage, kid, credential) = setup().await;
+  let sd_jwt: SdJwt = document
+    .create_sd_jwt(
+      &credential,
+      &storage,
+      kid.as_ref(),
+      &["/degree", "/degree/name"],
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap();
+  let verify = |sd_jwt: &SdJwt| {
+    verify_sd_jwt(
+      sd_jwt,
+      &document,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default(),
+    )
+  };
+  let disclosure = |claim_name: &str| -> Disclosure {
+    sd_jwt
+      .disclosures()
+      .iter()
+      .find(|disclosure| disclosure.claim_name() == claim_name)
+      .cloned()
+      .unwrap()
+  };
+
+  // All claims disclosed, the SD-JWT survives the combined format.
+  let presented: SdJwt = sd_jwt.to_string().parse().unwrap();
+  let claims: Object = verify(&presented).unwrap();
+  assert!(claims.get("_sd_alg").is_none());
+  assert_eq!(
+    claims["vc"]["credentialSubject"]["degree"],
+    json!({
+      "type": "BachelorDegree",
+      "name": "Bachelor of Science in Mechanical Engineering"
+    })
+  );
+
+  // Only the degree type is disclosed.
+  let presented: SdJwt = SdJwt::new(sd_jwt.jwt().clone(), vec![disclosure("degree")]);
+  let claims: Object = verify(&presented).unwrap();
+  let degree: &Value = &claims["vc"]["credentialSubject"]["degree"];
+  assert_eq!(degree["type"], "BachelorDegree");
+  assert!(degree.get("name").is_none());
+  assert!(degree.get("_sd").is_none());
+
+  // No claims disclosed.
+  let presented: SdJwt = SdJwt::new(sd_jwt.jwt().clone(), Vec::new());
+  let claims: Object = verify(&presented).unwrap();
+  assert!(claims["vc"]["credentialSubject"].get("degree").is_none());
+
+  // INVALID: a nested disclosure without its parent is not referenced by any digest.
+  let presented: SdJwt = SdJwt::new(sd_jwt.jwt().clone(), vec![disclosure("name")]);
+  assert!(matches!(
+    verify(&presented).unwrap_err(),
+    JwkStorageDocumentError::SdJwtVerificationError(..)
+  ));
+
+  // INVALID: a disclosure that was not issued with the SD-JWT.
+  let forged: Disclosure = Disclosure::new("salt".to_owned(), "GPA".to_owned(), json!("4.0"));
+  let presented: SdJwt = SdJwt::new(sd_jwt.jwt().clone(), vec![disclosure("degree"), forged]);
+  assert!(verify(&presented).is_err());
+
+  // INVALID: the same disclosure twice.
+  let presented: SdJwt = SdJwt::new(sd_jwt.jwt().clone(), vec![disclosure("degree"), disclosure("degree")]);
+  assert!(verify(&presented).is_err());
+}
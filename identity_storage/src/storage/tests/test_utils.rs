@@ -16,10 +16,12 @@ use serde_json::json;
 
 use crate::JwkDocumentExt;
 use crate::JwkMemStore;
+use crate::JwkStorage;
+use crate::JwkStorageDocumentError;
 use crate::KeyIdMemstore;
+use crate::KeyIdStorage;
 use crate::Storage;
-
-type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+use crate::StorageResult;
 
 const ISSUER_DOCUMENT_JSON: &str = r#"
 {
@@ -53,62 +55,113 @@ const ISSUER_IOTA_DOCUMENT_JSON: &str = r#"
   }
 }"#;
 
-pub(super) struct Setup<T: JwkDocumentExt, U: JwkDocumentExt> {
+pub(super) struct Setup<T: JwkDocumentExt, U: JwkDocumentExt, K = JwkMemStore, I = KeyIdMemstore> {
   pub issuer_doc: T,
   pub subject_doc: U,
-  pub issuer_storage: MemStorage,
+  pub issuer_storage: Storage<K, I>,
   pub issuer_method_fragment: String,
-  pub subject_storage: MemStorage,
+  pub subject_storage: Storage<K, I>,
   pub subject_method_fragment: String,
 }
 
+/// Errors that may occur while setting up the documents and storages of a test.
+#[derive(Debug, thiserror::Error)]
+pub(super) enum SetupError {
+  #[error("failed to generate the issuer method")]
+  IssuerMethodGeneration(#[source] JwkStorageDocumentError),
+  #[error("failed to generate the subject method")]
+  SubjectMethodGeneration(#[source] JwkStorageDocumentError),
+}
+
 pub(super) async fn setup_iotadocument(
   issuer_fragment: Option<&'static str>,
   subject_fragment: Option<&'static str>,
-) -> Setup<IotaDocument, IotaDocument> {
-  let mut issuer_doc = IotaDocument::from_json(ISSUER_IOTA_DOCUMENT_JSON).unwrap();
-  let mut subject_doc = IotaDocument::from_json(SUBJECT_IOTA_DOCUMENT_JSON).unwrap();
-  let issuer_storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
-  let subject_storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
-
-  let issuer_method_fragment: String = generate_method(&issuer_storage, &mut issuer_doc, issuer_fragment).await;
-  let subject_method_fragment: String = generate_method(&subject_storage, &mut subject_doc, subject_fragment).await;
+) -> Result<Setup<IotaDocument, IotaDocument>, SetupError> {
+  setup_with_storage(
+    IotaDocument::from_json(ISSUER_IOTA_DOCUMENT_JSON).unwrap(),
+    IotaDocument::from_json(SUBJECT_IOTA_DOCUMENT_JSON).unwrap(),
+    Storage::new(JwkMemStore::new(), KeyIdMemstore::new()),
+    Storage::new(JwkMemStore::new(), KeyIdMemstore::new()),
+    issuer_fragment,
+    subject_fragment,
+  )
+  .await
+}
 
-  Setup {
-    issuer_doc,
-    subject_doc,
-    issuer_storage,
-    subject_storage,
-    issuer_method_fragment,
-    subject_method_fragment,
-  }
+pub(super) async fn setup_iotadocument_or_panic(
+  issuer_fragment: Option<&'static str>,
+  subject_fragment: Option<&'static str>,
+) -> Setup<IotaDocument, IotaDocument> {
+  setup_iotadocument(issuer_fragment, subject_fragment)
+    .await
+    .unwrap_or_else(|err| panic!("test setup failed: {err}: {:?}", std::error::Error::source(&err)))
 }
 
 pub(super) async fn setup_coredocument(
   issuer_fragment: Option<&'static str>,
   subject_fragment: Option<&'static str>,
+) -> Result<Setup<CoreDocument, CoreDocument>, SetupError> {
+  setup_with_storage(
+    CoreDocument::from_json(ISSUER_DOCUMENT_JSON).unwrap(),
+    CoreDocument::from_json(SUBJECT_DOCUMENT_JSON).unwrap(),
+    Storage::new(JwkMemStore::new(), KeyIdMemstore::new()),
+    Storage::new(JwkMemStore::new(), KeyIdMemstore::new()),
+    issuer_fragment,
+    subject_fragment,
+  )
+  .await
+}
+
+pub(super) async fn setup_coredocument_or_panic(
+  issuer_fragment: Option<&'static str>,
+  subject_fragment: Option<&'static str>,
 ) -> Setup<CoreDocument, CoreDocument> {
-  let mut issuer_doc = CoreDocument::from_json(ISSUER_DOCUMENT_JSON).unwrap();
-  let mut subject_doc = CoreDocument::from_json(SUBJECT_DOCUMENT_JSON).unwrap();
-  let issuer_storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
-  let subject_storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  setup_coredocument(issuer_fragment, subject_fragment)
+    .await
+    .unwrap_or_else(|err| panic!("test setup failed: {err}: {:?}", std::error::Error::source(&err)))
+}
 
-  let issuer_method_fragment: String = generate_method(&issuer_storage, &mut issuer_doc, issuer_fragment).await;
-  let subject_method_fragment: String = generate_method(&subject_storage, &mut subject_doc, subject_fragment).await;
+/// Generates a method in each of the given documents, backed by the given storages.
+pub(super) async fn setup_with_storage<T, U, K, I>(
+  mut issuer_doc: T,
+  mut subject_doc: U,
+  issuer_storage: Storage<K, I>,
+  subject_storage: Storage<K, I>,
+  issuer_fragment: Option<&'static str>,
+  subject_fragment: Option<&'static str>,
+) -> Result<Setup<T, U, K, I>, SetupError>
+where
+  T: JwkDocumentExt,
+  U: JwkDocumentExt,
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  let issuer_method_fragment: String = generate_method(&issuer_storage, &mut issuer_doc, issuer_fragment)
+    .await
+    .map_err(SetupError::IssuerMethodGeneration)?;
+  let subject_method_fragment: String = generate_method(&subject_storage, &mut subject_doc, subject_fragment)
+    .await
+    .map_err(SetupError::SubjectMethodGeneration)?;
 
-  Setup {
+  Ok(Setup {
     issuer_doc,
     subject_doc,
     issuer_storage,
     subject_storage,
     issuer_method_fragment,
     subject_method_fragment,
-  }
+  })
 }
 
-async fn generate_method<T>(storage: &MemStorage, document: &mut T, fragment: Option<&'static str>) -> String
+async fn generate_method<T, K, I>(
+  storage: &Storage<K, I>,
+  document: &mut T,
+  fragment: Option<&'static str>,
+) -> StorageResult<String>
 where
   T: JwkDocumentExt,
+  K: JwkStorage,
+  I: KeyIdStorage,
 {
   document
     .generate_method(
@@ -119,7 +172,6 @@ where
       MethodScope::assertion_method(),
     )
     .await
-    .unwrap()
 }
 
 pub(super) struct CredentialSetup {
@@ -128,14 +180,18 @@ pub(super) struct CredentialSetup {
   pub expiration_date: Timestamp,
 }
 
+/// Generates a credential issued by `issuer` to `subjects`.
+///
+/// The `contexts` are appended to the `@context` set after the base context, in the given order.
 pub(super) fn generate_credential<T: AsRef<CoreDocument>, U: AsRef<CoreDocument>>(
   issuer: T,
   subjects: &[&U],
   issuance_date: Option<Timestamp>,
   expiration_date: Option<Timestamp>,
+  contexts: &[Url],
 ) -> CredentialSetup {
   let issuance_date = issuance_date.unwrap_or_else(|| Timestamp::parse("2020-01-01T00:00:00Z").unwrap());
-  let expiration_date = expiration_date.unwrap_or_else(|| Timestamp::parse("2024-01-01T00:00:00Z").unwrap());
+  let expiration_date = expiration_date.unwrap_or_else(|| Timestamp::parse("2124-01-01T00:00:00Z").unwrap());
 
   let credential_subjects: Vec<Subject> = subjects
     .iter()
@@ -154,7 +210,11 @@ pub(super) fn generate_credential<T: AsRef<CoreDocument>, U: AsRef<CoreDocument>
     .collect();
 
   // Build credential using subject above and issuer.
-  let credential: Credential = CredentialBuilder::default()
+  let mut builder: CredentialBuilder = CredentialBuilder::default();
+  for context in contexts {
+    builder = builder.context(context.clone());
+  }
+  let credential: Credential = builder
     .id(Url::parse("https://example.edu/credentials/3732").unwrap())
     .issuer(Url::parse(issuer.as_ref().id().as_str()).unwrap())
     .type_("UniversityDegreeCredential")
@@ -170,3 +230,61 @@ pub(super) fn generate_credential<T: AsRef<CoreDocument>, U: AsRef<CoreDocument>
     expiration_date,
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use identity_core::convert::FromJson;
+  use identity_document::document::CoreDocument;
+
+  use super::setup_with_storage;
+  use super::SetupError;
+  use super::ISSUER_DOCUMENT_JSON;
+  use super::SUBJECT_DOCUMENT_JSON;
+  use crate::key_id_storage::KeyIdStorage;
+  use crate::key_id_storage::KeyIdStorageError;
+  use crate::key_id_storage::KeyIdStorageErrorKind;
+  use crate::key_id_storage::KeyIdStorageResult;
+  use crate::key_id_storage::MethodDigest;
+  use crate::key_storage::KeyId;
+  use crate::JwkMemStore;
+  use crate::JwkStorageDocumentError;
+  use crate::Storage;
+
+  /// A [`KeyIdStorage`] that is always unavailable.
+  struct FailingKeyIdStorage;
+
+  #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync-storage", async_trait)]
+  impl KeyIdStorage for FailingKeyIdStorage {
+    async fn insert_key_id(&self, _method_digest: MethodDigest, _key_id: KeyId) -> KeyIdStorageResult<()> {
+      Err(KeyIdStorageError::new(KeyIdStorageErrorKind::Unavailable))
+    }
+
+    async fn get_key_id(&self, _method_digest: &MethodDigest) -> KeyIdStorageResult<KeyId> {
+      Err(KeyIdStorageError::new(KeyIdStorageErrorKind::Unavailable))
+    }
+
+    async fn delete_key_id(&self, _method_digest: &MethodDigest) -> KeyIdStorageResult<()> {
+      Err(KeyIdStorageError::new(KeyIdStorageErrorKind::Unavailable))
+    }
+  }
+
+  #[tokio::test]
+  async fn setup_reports_storage_failure() {
+    let result = setup_with_storage(
+      CoreDocument::from_json(ISSUER_DOCUMENT_JSON).unwrap(),
+      CoreDocument::from_json(SUBJECT_DOCUMENT_JSON).unwrap(),
+      Storage::new(JwkMemStore::new(), FailingKeyIdStorage),
+      Storage::new(JwkMemStore::new(), FailingKeyIdStorage),
+      None,
+      None,
+    )
+    .await;
+
+    let Err(SetupError::IssuerMethodGeneration(JwkStorageDocumentError::KeyIdStorageError(err))) = result else {
+      panic!("expected the issuer method generation to fail");
+    };
+    assert!(matches!(err.kind(), KeyIdStorageErrorKind::Unavailable));
+  }
+}
@@ -31,4 +31,6 @@ pub enum Error {
   PrivateKeyMaterialExposed,
   #[error("verification material format is not publicKeyJwk")]
   NotPublicKeyJwk,
+  #[error("verification material is not a secp256k1 public key")]
+  NotSecp256k1Key,
 }
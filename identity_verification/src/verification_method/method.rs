@@ -6,6 +6,7 @@ use core::fmt::Formatter;
 use std::borrow::Cow;
 
 use identity_jose::jwk::Jwk;
+use identity_jose::jws::JwsAlgorithm;
 use serde::de;
 use serde::Deserialize;
 use serde::Serialize;
@@ -26,6 +27,9 @@ use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_did::DID;
 
+/// The URN prefix of a JWK Thumbprint URI using SHA-256, see [RFC 9278](https://www.rfc-editor.org/rfc/rfc9278).
+const JWK_THUMBPRINT_SHA256_URI_PREFIX: &str = "urn:ietf:params:oauth:jwk-thumbprint:sha-256:";
+
 /// A DID Document Verification Method.
 ///
 /// [Specification](https://www.w3.org/TR/did-core/#verification-method-properties)
@@ -148,6 +152,62 @@ impl VerificationMethod {
     MethodRef::Embed(self)
   }
 
+  /// Returns the [JWK Thumbprint URI](https://www.rfc-editor.org/rfc/rfc9278) of the public key material of the
+  /// method, with SHA-256 as the hash function, e.g.
+  /// `urn:ietf:params:oauth:jwk-thumbprint:sha-256:kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k`.
+  ///
+  /// # Errors
+  /// [`Error::NotPublicKeyJwk`] if the method data is not a JWK.
+  pub fn thumbprint_uri(&self) -> Result<String> {
+    let jwk: &Jwk = self.data.try_public_key_jwk()?;
+    Ok(format!(
+      "{JWK_THUMBPRINT_SHA256_URI_PREFIX}{}",
+      jwk.thumbprint_sha256_b64()
+    ))
+  }
+
+  /// Returns the [`JwsAlgorithm`] the key of the method is intended to be used with, as declared by the `alg`
+  /// parameter of its JWK, e.g. for methods created with `JwkDocumentExt::generate_method`.
+  ///
+  /// Returns `None` if the method data is not a JWK, or the JWK does not declare a known algorithm.
+  pub fn jws_algorithm(&self) -> Option<JwsAlgorithm> {
+    self.data.public_key_jwk()?.alg()?.parse().ok()
+  }
+
+  /// Derives the 20-byte EVM (e.g. Ethereum) address of the secp256k1 public key of the method, i.e. the last 20
+  /// bytes of the keccak256 hash of the uncompressed public key.
+  ///
+  /// # Errors
+  /// [`Error::NotPublicKeyJwk`] if the method data is not a JWK and [`Error::NotSecp256k1Key`] if the JWK is not a
+  /// valid secp256k1 public key.
+  #[cfg(feature = "evm")]
+  pub fn evm_address(&self) -> Result<[u8; 20]> {
+    use identity_jose::jwk::EcCurve;
+    use identity_jose::jwk::JwkParamsEc;
+    use sha3::Digest;
+    use sha3::Keccak256;
+
+    let jwk: &Jwk = self.data.try_public_key_jwk()?;
+    let params: &JwkParamsEc = jwk.try_ec_params().map_err(|_| Error::NotSecp256k1Key)?;
+    if params.try_ec_curve().ok() != Some(EcCurve::Secp256K1) {
+      return Err(Error::NotSecp256k1Key);
+    }
+
+    let decode_coordinate = |coordinate: &str| -> Result<Vec<u8>> {
+      identity_jose::jwu::decode_b64(coordinate)
+        .ok()
+        .filter(|coordinate| coordinate.len() == 32)
+        .ok_or(Error::NotSecp256k1Key)
+    };
+    let x: Vec<u8> = decode_coordinate(&params.x)?;
+    let y: Vec<u8> = decode_coordinate(&params.y)?;
+
+    let hash = Keccak256::new().chain_update(x).chain_update(y).finalize();
+    let mut address: [u8; 20] = [0; 20];
+    address.copy_from_slice(&hash[12..]);
+    Ok(address)
+  }
+
   /// Maps the [`VerificationMethod`] by applying a function `f` to
   /// the [`CoreDID`] components of id and controller. Useful when working with DID methods where the identifier
   /// is not known before publishing.
@@ -271,3 +331,54 @@ impl KeyComparable for VerificationMethod {
     self.id()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  #[test]
+  fn test_thumbprint_uri() {
+    // Test vector from RFC 8037, appendix A.3.
+    let jwk: Jwk =
+      Jwk::from_json(r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#).unwrap();
+    let did: CoreDID = "did:example:123".parse().unwrap();
+    let method: VerificationMethod = VerificationMethod::new_from_jwk(did.clone(), jwk, Some("key-1")).unwrap();
+    assert_eq!(
+      method.thumbprint_uri().unwrap(),
+      "urn:ietf:params:oauth:jwk-thumbprint:sha-256:kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k"
+    );
+
+    let method: VerificationMethod =
+      VerificationMethod::new(did, KeyType::Ed25519, &[0; 32].to_vec().into(), "key-2").unwrap();
+    assert!(matches!(method.thumbprint_uri(), Err(Error::NotPublicKeyJwk)));
+  }
+
+  #[cfg(feature = "evm")]
+  #[test]
+  fn test_evm_address() {
+    // The public key of the secret key `1`, i.e. the generator point of secp256k1.
+    let jwk: Jwk = Jwk::from_json(
+      r#"{
+        "kty":"EC",
+        "crv":"secp256k1",
+        "x":"eb5mfvncu6xVoGKVzocLBwKb_NstzijZWfKBWxb4F5g",
+        "y":"SDradyajxGVdpPv8DhEIqP0XtEimhVQZnEfQj_sQ1Lg"
+      }"#,
+    )
+    .unwrap();
+    let did: CoreDID = "did:example:123".parse().unwrap();
+    let method: VerificationMethod = VerificationMethod::new_from_jwk(did.clone(), jwk, Some("key-1")).unwrap();
+    let expected: [u8; 20] = [
+      0x7e, 0x5f, 0x45, 0x52, 0x09, 0x1a, 0x69, 0x12, 0x5d, 0x5d, 0xfc, 0xb7, 0xb8, 0xc2, 0x65, 0x90, 0x29, 0x39, 0x5b,
+      0xdf,
+    ];
+    assert_eq!(method.evm_address().unwrap(), expected);
+
+    let jwk: Jwk =
+      Jwk::from_json(r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#).unwrap();
+    let method: VerificationMethod = VerificationMethod::new_from_jwk(did, jwk, Some("key-2")).unwrap();
+    assert!(matches!(method.evm_address(), Err(Error::NotSecp256k1Key)));
+  }
+}